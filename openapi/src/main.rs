@@ -15,7 +15,11 @@ fn main() {
     let id_renames = mappings::id_renames();
     let object_mappings = mappings::object_mappings();
     let field_mappings = mappings::field_mappings();
+    let version_optional_fields = mappings::version_optional_fields();
+    let open_enums = mappings::open_enums();
+    let default_structs = mappings::default_structs();
     let feature_groups = metadata::feature_groups();
+    let feature_group_enums = metadata::feature_group_enums();
 
     // Compute additional metadata from spec.
     let mut objects = BTreeSet::new();
@@ -81,6 +85,9 @@ fn main() {
         id_mappings,
         object_mappings,
         field_mappings,
+        version_optional_fields,
+        open_enums,
+        default_structs,
     };
 
     // Generate placeholders
@@ -95,15 +102,48 @@ fn main() {
             let struct_type = meta.schema_to_rust_type(schema);
             out.push_str(&format!("#[cfg(not(feature = \"{}\"))]\n", feature));
             out.push_str("#[derive(Clone, Debug, Deserialize, Serialize)]\n");
+            out.push_str("#[non_exhaustive]\n");
             out.push_str(&format!("pub struct {} {{\n", struct_type));
-            out.push_str(&format!("\tpub id: {},\n", id_type));
+            let optional_id = meta.is_version_optional(schema, "id");
+            if optional_id {
+                out.push_str("\t#[serde(default)]\n");
+                out.push_str(&format!("\tpub id: Option<{}>,\n", id_type));
+            } else {
+                out.push_str(&format!("\tpub id: {},\n", id_type));
+            }
             out.push_str("}\n\n");
             out.push_str(&format!("#[cfg(not(feature = \"{}\"))]\n", feature));
             out.push_str(&format!("impl Object for {} {{\n", struct_type));
             out.push_str(&format!("\ttype Id = {};\n", id_type));
-            out.push_str("\tfn id(&self) -> Self::Id { self.id.clone() }\n");
+            if optional_id {
+                out.push_str(&format!(
+                    "\tfn id(&self) -> Self::Id {{ self.id.clone().unwrap_or_else({}::none) }}\n",
+                    id_type
+                ));
+            } else {
+                out.push_str("\tfn id(&self) -> Self::Id { self.id.clone() }\n");
+            }
             out.push_str(&format!("\tfn object(&self) -> &'static str {{ \"{}\" }}\n", schema));
             out.push_str("}\n");
+            for (_, field) in feature_group_enums.iter().filter(|(s, _)| s == schema) {
+                let enum_name = meta.schema_to_rust_type(&meta.schema_field(schema, field));
+                let variants = meta.spec["components"]["schemas"][*schema]["properties"][*field]
+                    ["enum"]
+                    .as_array()
+                    .unwrap();
+                out.push('\n');
+                out.push_str(&format!("#[cfg(not(feature = \"{}\"))]\n", feature));
+                out.push_str(
+                    "#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]\n",
+                );
+                out.push_str("#[serde(rename_all = \"snake_case\")]\n");
+                out.push_str(&format!("pub enum {} {{\n", enum_name));
+                for variant in variants {
+                    let wire_name = variant.as_str().unwrap();
+                    out.push_str(&format!("\t{},\n", meta.schema_to_rust_type(wire_name)));
+                }
+                out.push_str("}\n");
+            }
             fs::write("openapi/out/placeholders.rs", out.as_bytes()).unwrap();
         }
     }
@@ -137,6 +177,12 @@ struct Metadata<'a> {
     object_mappings: mappings::ObjectMap,
     /// An override for the rust-type of a particular object/field pair.
     field_mappings: mappings::FieldMap,
+    /// Required fields that some API versions leave out.
+    version_optional_fields: BTreeSet<mappings::FieldSpec>,
+    /// Response enums that keep unknown values in an `Other(String)` variant.
+    open_enums: BTreeSet<&'static str>,
+    /// Param structs that derive `Default`.
+    default_structs: BTreeSet<&'static str>,
     /// A one to many map of _objects_ to requests which should be
     /// implemented for that object.
     ///
@@ -168,6 +214,11 @@ impl<'a> Metadata<'a> {
         self.field_mappings.get(&(schema.as_str(), field)).copied()
     }
 
+    fn is_version_optional(&self, schema: &str, field: &str) -> bool {
+        let schema = schema.replace('.', "_");
+        self.version_optional_fields.contains(&(schema.as_str(), field))
+    }
+
     fn schema_field(&self, parent: &str, field: &str) -> String {
         let parent_type = self.schema_to_rust_type(parent);
         format!("{}_{}", parent_type, field).to_snake_case()
@@ -184,6 +235,8 @@ struct Generated {
     use_params: BTreeSet<&'static str>,
     /// The resources that must be imported in this file.
     use_resources: BTreeSet<String>,
+    /// The std collections that must be imported in this file.
+    use_collections: BTreeSet<&'static str>,
     /// Extra (simple) enums that were / will be generated in this file.
     inferred_enums: BTreeMap<String, InferredEnum>,
     /// Extra (complex) enums that were / will be generated in this file.
@@ -314,6 +367,7 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
         out.push_str(").\n");
     }
     out.push_str("#[derive(Clone, Debug, Deserialize, Serialize)]\n");
+    out.push_str("#[non_exhaustive]\n");
     out.push_str("pub struct ");
     out.push_str(&struct_name);
     out.push_str(" {\n");
@@ -325,9 +379,17 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
         if id_type == "InvoiceId" {
             out.push_str("    #[serde(default = \"InvoiceId::none\")]");
         }
-        out.push_str("    pub id: ");
-        out.push_str(&id_type);
-        out.push_str(",\n");
+        if meta.is_version_optional(object, "id") {
+            out.push_str("    #[serde(default)]\n");
+            out.push_str("    #[serde(skip_serializing_if = \"Option::is_none\")]\n");
+            out.push_str("    pub id: Option<");
+            out.push_str(&id_type);
+            out.push_str(">,\n");
+        } else {
+            out.push_str("    pub id: ");
+            out.push_str(&id_type);
+            out.push_str(",\n");
+        }
     }
     let mut did_emit_deleted = false;
     for (key, field) in fields {
@@ -355,7 +417,7 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
             deleted_schema["properties"][&key].is_null()
         } else {
             false
-        };
+        } || meta.is_version_optional(object, &key);
         out.push('\n');
         out.push_str(&gen_field(
             &mut state,
@@ -387,7 +449,13 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
     if let Some(id_type) = &id_type {
         out.push_str(&id_type);
         out.push_str(";\n");
-        out.push_str("    fn id(&self) -> Self::Id {\n        self.id.clone()\n    }\n");
+        if meta.is_version_optional(object, "id") {
+            out.push_str("    fn id(&self) -> Self::Id {\n        self.id.clone().unwrap_or_else(");
+            out.push_str(&id_type);
+            out.push_str("::none)\n    }\n");
+        } else {
+            out.push_str("    fn id(&self) -> Self::Id {\n        self.id.clone()\n    }\n");
+        }
     } else {
         out.push_str("();\n");
         out.push_str("    fn id(&self) -> Self::Id {}\n");
@@ -406,7 +474,7 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
     {
         let struct_name = meta.schema_to_rust_type(&schema_name);
         out.push('\n');
-        out.push_str("#[derive(Clone, Debug, Deserialize, Serialize)]\n");
+        out.push_str(&struct_derive(meta, &struct_name));
         out.push_str("pub struct ");
         out.push_str(&struct_name);
         out.push_str(" {\n");
@@ -418,7 +486,8 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
             let required = meta.spec["components"]["schemas"][&schema_name]["required"]
                 .as_array()
                 .map(|arr| arr.iter().filter_map(|x| x.as_str()).any(|x| x == key))
-                .unwrap_or(false);
+                .unwrap_or(false)
+                && !meta.is_version_optional(&schema_name, &key);
             out.push('\n');
             out.push_str(&gen_field(&mut state, meta, &schema_name, &key, &field, required, false));
         }
@@ -475,7 +544,12 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
             let required = param["required"].as_bool() == Some(true);
             match param_name {
                 // TODO: Handle these unusual params
-                "bank_account" | "card" | "destination" | "usage" => continue,
+                "bank_account" | "card" | "usage" => continue,
+                "destination"
+                    if meta.field_to_rust_type(params_schema.as_str(), param_name).is_none() =>
+                {
+                    continue
+                }
 
                 "product" => {
                     print_doc(&mut out);
@@ -855,7 +929,7 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
                 }
             };
             out.push('\n');
-            out.push_str("#[derive(Clone, Debug, Deserialize, Serialize)]\n");
+            out.push_str(&struct_derive(meta, &struct_name.to_camel_case()));
             out.push_str("pub struct ");
             out.push_str(&struct_name.to_camel_case());
             out.push_str(" {\n");
@@ -912,74 +986,101 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
     for (enum_name, enum_) in state.inferred_enums.clone() {
         println!("enum {} {{ ... }}", enum_name);
 
+        let variants: Vec<(String, &str)> = enum_
+            .options
+            .iter()
+            .filter(|wire_name| !wire_name.trim().is_empty())
+            .map(|wire_name| {
+                let variant_name = match wire_name.as_str() {
+                    "*" => "All".to_string(),
+                    n => {
+                        if n.chars().next().unwrap().is_digit(10) {
+                            format!("V{}", n.to_string().replace('-', "_"))
+                        } else {
+                            meta.schema_to_rust_type(wire_name)
+                        }
+                    }
+                };
+                if variant_name.trim().is_empty() {
+                    panic!("unhandled enum variant: {:?}", wire_name)
+                }
+                (variant_name, wire_name.as_str())
+            })
+            .collect();
+        let open = meta.open_enums.contains(enum_name.as_str());
+
         out.push('\n');
         out.push_str(&format!(
             "/// An enum representing the possible values of an `{}`'s `{}` field.\n",
             enum_.parent, enum_.field
         ));
-        out.push_str("#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]\n");
-        out.push_str("#[serde(rename_all = \"snake_case\")]\n");
+        if open {
+            out.push_str("#[derive(Clone, Debug, Eq, PartialEq, Hash)]\n");
+        } else {
+            out.push_str("#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]\n");
+            out.push_str("#[serde(rename_all = \"snake_case\")]\n");
+        }
         out.push_str("pub enum ");
         out.push_str(&enum_name);
         out.push_str(" {\n");
-        for wire_name in &enum_.options {
-            if wire_name.trim().is_empty() {
-                continue;
-            }
-            let variant_name = match wire_name.as_str() {
-                "*" => "All".to_string(),
-                n => {
-                    if n.chars().next().unwrap().is_digit(10) {
-                        format!("V{}", n.to_string().replace('-', "_"))
-                    } else {
-                        meta.schema_to_rust_type(wire_name)
-                    }
-                }
-            };
-            if variant_name.trim().is_empty() {
-                panic!("unhandled enum variant: {:?}", wire_name)
-            }
-            if &variant_name.to_snake_case() != wire_name {
+        for (variant_name, wire_name) in &variants {
+            if !open && &variant_name.to_snake_case() != wire_name {
                 out.push_str("    #[serde(rename = \"");
                 out.push_str(wire_name);
                 out.push_str("\")]\n");
             }
             out.push_str("    ");
-            out.push_str(&variant_name);
+            out.push_str(variant_name);
             out.push_str(",\n");
         }
+        if open {
+            out.push_str("\n    /// A value not yet supported by the library.\n");
+            out.push_str("    ///\n");
+            out.push_str("    /// Holds the raw value returned by Stripe.\n");
+            out.push_str("    Other(String),\n");
+        }
         out.push_str("}\n");
         out.push('\n');
         out.push_str("impl ");
         out.push_str(&enum_name);
         out.push_str(" {\n");
-        out.push_str("    pub fn as_str(self) -> &'static str {\n");
+        if open {
+            out.push_str("    pub fn as_str(&self) -> &str {\n");
+        } else {
+            out.push_str("    pub fn as_str(self) -> &'static str {\n");
+        }
         out.push_str("        match self {\n");
-        for wire_name in &enum_.options {
-            if wire_name.trim().is_empty() {
-                continue;
-            }
-            let variant_name = match wire_name.as_str() {
-                "*" => "All".to_string(),
-                n => {
-                    if n.chars().next().unwrap().is_digit(10) {
-                        format!("V{}", n.to_string().replace('-', "_"))
-                    } else {
-                        meta.schema_to_rust_type(wire_name)
-                    }
-                }
-            };
+        for (variant_name, wire_name) in &variants {
             out.push_str("            ");
             out.push_str(&enum_name);
             out.push_str("::");
-            out.push_str(&variant_name);
+            out.push_str(variant_name);
             out.push_str(" => ");
             out.push_str(&format!("{:?}", wire_name));
             out.push_str(",\n");
         }
+        if open {
+            out.push_str(&format!("            {}::Other(value) => value.as_str(),\n", enum_name));
+        }
         out.push_str("        }\n");
         out.push_str("    }\n");
         out.push_str("}\n");
+        if open {
+            out.push('\n');
+            out.push_str(&format!("impl From<String> for {} {{\n", enum_name));
+            out.push_str("    fn from(value: String) -> Self {\n");
+            out.push_str("        match value.as_str() {\n");
+            for (variant_name, wire_name) in &variants {
+                out.push_str(&format!(
+                    "            {:?} => {}::{},\n",
+                    wire_name, enum_name, variant_name
+                ));
+            }
+            out.push_str(&format!("            _ => {}::Other(value),\n", enum_name));
+            out.push_str("        }\n");
+            out.push_str("    }\n");
+            out.push_str("}\n");
+        }
         out.push('\n');
         out.push_str("impl AsRef<str> for ");
         out.push_str(&enum_name);
@@ -996,6 +1097,29 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
         out.push_str("        self.as_str().fmt(f)\n");
         out.push_str("    }\n");
         out.push_str("}\n");
+        if open {
+            out.push('\n');
+            out.push_str(&format!("impl serde::Serialize for {} {{\n", enum_name));
+            out.push_str("    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>\n");
+            out.push_str("    where\n");
+            out.push_str("        S: serde::ser::Serializer,\n");
+            out.push_str("    {\n");
+            out.push_str("        self.as_str().serialize(serializer)\n");
+            out.push_str("    }\n");
+            out.push_str("}\n");
+            out.push('\n');
+            out.push_str(&format!("impl<'de> serde::Deserialize<'de> for {} {{\n", enum_name));
+            out.push_str("    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>\n");
+            out.push_str("    where\n");
+            out.push_str("        D: serde::de::Deserializer<'de>,\n");
+            out.push_str("    {\n");
+            out.push_str(
+                "        let s: String = serde::Deserialize::deserialize(deserializer)?;\n",
+            );
+            out.push_str(&format!("        Ok({}::from(s))\n", enum_name));
+            out.push_str("    }\n");
+            out.push_str("}\n");
+        }
     }
 
     let mut prelude = String::new();
@@ -1054,12 +1178,30 @@ fn gen_impl_object(meta: &Metadata, object: &str) -> String {
         prelude.push_str("};\n");
     }
     prelude.push_str("use serde_derive::{Deserialize, Serialize};\n");
+    if state.use_collections.len() > 0 {
+        prelude.push_str("use std::collections::{");
+        for (n, type_) in state.use_collections.iter().enumerate() {
+            if n > 0 {
+                prelude.push_str(", ");
+            }
+            prelude.push_str(&type_);
+        }
+        prelude.push_str("};\n");
+    }
     prelude.push('\n');
 
     // Done
     prelude + &out
 }
 
+fn struct_derive(meta: &Metadata, struct_name: &str) -> &'static str {
+    if meta.default_structs.contains(struct_name) {
+        "#[derive(Clone, Debug, Default, Deserialize, Serialize)]\n"
+    } else {
+        "#[derive(Clone, Debug, Deserialize, Serialize)]\n"
+    }
+}
+
 fn gen_field(
     state: &mut Generated,
     meta: &Metadata,
@@ -1084,8 +1226,11 @@ fn gen_field(
     }
     let rust_type =
         gen_field_rust_type(state, meta, object, &field_name, &field, required, default);
-    if !required {
-        if rust_type == "bool" || rust_type == "Metadata" || rust_type.starts_with("List<") {
+    // Collections may be missing on older API versions, so they default to empty.
+    if rust_type == "Metadata" || rust_type.starts_with("Vec<") {
+        out.push_str("    #[serde(default)]\n");
+    } else if !required {
+        if rust_type == "bool" || rust_type.starts_with("List<") {
             out.push_str("    #[serde(default)]\n");
         } else if rust_type.starts_with("Option<") {
             out.push_str("    #[serde(skip_serializing_if = \"Option::is_none\")]\n");
@@ -1114,6 +1259,12 @@ fn gen_field_rust_type(
             "Metadata" => {
                 state.use_params.insert("Metadata");
             }
+            "Secret" => {
+                state.use_params.insert("Secret");
+            }
+            path if path.ends_with("Id") && path != "TaxId" => {
+                state.use_ids.insert(path.into());
+            }
             _ => {
                 state.use_resources.insert(use_path.into());
             }
@@ -1197,6 +1348,19 @@ fn gen_field_rust_type(
 
                 // N.B. return immediately; we use `Default` for list rather than `Option`
                 return format!("List<{}>", element_type);
+            } else if field["additionalProperties"].is_object() {
+                // Maps like `currency_options` are keyed by a three-letter currency code.
+                let key_type = if field_name.ends_with("currency_options") {
+                    state.use_resources.insert("Currency".into());
+                    "Currency"
+                } else {
+                    "String"
+                };
+                let element = &field["additionalProperties"];
+                let element_type =
+                    gen_field_rust_type(state, meta, object, field_name, element, true, false);
+                state.use_collections.insert("HashMap");
+                format!("HashMap<{}, {}>", key_type, element_type)
             } else {
                 let struct_schema = meta.schema_field(object, field_name);
                 let struct_name = meta.schema_to_rust_type(&struct_schema);
@@ -1345,7 +1509,7 @@ fn gen_impl_requests(
                     out.push_str("<'_>) -> Response<List<");
                     out.push_str(&rust_struct);
                     out.push_str(">> {\n");
-                    out.push_str("        client.get_query(\"/");
+                    out.push_str("        List::get_with_params(client, \"/");
                     out.push_str(&segments.join("/"));
                     out.push_str("\", &params)\n");
                     out.push_str("    }\n");
@@ -1464,7 +1628,13 @@ fn gen_impl_requests(
                 out.push_str("    }\n");
                 methods.push(out);
             } else if segments.len() == 2 {
-                if !doc_comment.contains("Update") && !doc_comment.contains("update") {
+                // N.B. the invoice update is documented as "Draft invoices are fully editable"
+                // and the dispute update as "you can submit evidence"
+                let contains_update = doc_comment.contains("Update")
+                    || doc_comment.contains("update")
+                    || doc_comment.contains("editable")
+                    || doc_comment.contains("submit evidence");
+                if !contains_update {
                     continue; // skip requests which don't appear to be `update` for now
                 }
 
@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 pub fn id_renames() -> BTreeMap<&'static str, &'static str> {
     [
@@ -43,7 +43,27 @@ pub fn object_mappings() -> ObjectMap {
         ("issuing_authorization_merchant_data", "merchant_data"),
         ("issuing_authorization_wallet_provider", "wallet_provider"),
         ("item", "checkout_session_item"),
+        ("portal_flows_flow", "portal_flow_data"),
+        ("portal_flow_data_type", "portal_flow_type"),
+        ("portal_flows_flow_after_completion", "portal_flow_after_completion"),
+        ("portal_flows_after_completion_hosted_confirmation", "portal_flow_after_completion_hosted_confirmation"),
+        ("portal_flows_after_completion_redirect", "portal_flow_after_completion_redirect"),
+        ("portal_flows_flow_subscription_cancel", "portal_flow_subscription_cancel"),
+        ("portal_flows_flow_subscription_update", "portal_flow_subscription_update"),
+        ("portal_flows_flow_subscription_update_confirm", "portal_flow_subscription_update_confirm"),
+        ("portal_flows_subscription_update_confirm_item", "portal_flow_subscription_update_confirm_item"),
+        ("payment_pages_checkout_session_customer_details", "checkout_session_customer_details"),
+        ("payment_pages_checkout_session_custom_fields", "checkout_session_custom_field"),
+        ("payment_pages_checkout_session_custom_fields_dropdown", "custom_field_dropdown"),
+        ("payment_pages_checkout_session_custom_fields_option", "custom_field_dropdown_option"),
+        ("payment_pages_checkout_session_custom_fields_label", "custom_field_label"),
+        ("payment_pages_checkout_session_custom_fields_numeric", "custom_field_numeric"),
+        ("payment_pages_checkout_session_custom_fields_text", "custom_field_text"),
+        ("checkout_session_custom_field_type", "custom_field_type"),
+        ("payment_pages_checkout_session_custom_text", "checkout_session_custom_text"),
+        ("payment_pages_checkout_session_custom_text_position", "custom_text_position"),
         ("invoice_collection_method", "collection_method"),
+        ("automatic_tax", "invoice_automatic_tax"),
         ("invoices_resource_invoice_tax_id_type", "tax_id_type"),
         ("invoice_tax_amount", "tax_amount"),
         ("invoiceitem", "invoice_item"),
@@ -56,16 +76,21 @@ pub fn object_mappings() -> ObjectMap {
         ("line_item", "invoice_line_item"),
         ("payment_method_card", "card_details"),
         ("payment_method_card_present", "card_present"),
+        ("networks", "payment_method_card_networks"),
         ("payment_method_card_wallet", "wallet_details"),
         ("payment_method_card_wallet_amex_express_checkout", "wallet_amex_express_checkout"),
         ("payment_method_card_wallet_apple_pay", "wallet_apple_pay"),
         ("payment_method_card_wallet_google_pay", "wallet_google_pay"),
+        ("payment_method_card_wallet_link", "wallet_link"),
         ("payment_method_card_wallet_masterpass", "wallet_masterpass"),
         ("payment_method_card_wallet_samsung_pay", "wallet_samsung_pay"),
         ("payment_method_card_wallet_visa_checkout", "wallet_visa_checkout"),
         ("payment_method_card_wallet_type", "wallet_type"),
         ("payment_pages_payment_page_resources_shipping_address_collection", "shipping_address_collection"),
         ("tax_id_data_type", "tax_id_type"),
+        ("terminal_reader_reader_resource_reader_action", "terminal_reader_action"),
+        ("terminal_reader_reader_resource_process_payment_intent_action", "terminal_reader_process_payment_intent_action"),
+        ("terminal_reader_reader_resource_process_setup_intent_action", "terminal_reader_process_setup_intent_action"),
 
         // Config for `account` params
         ("create_account_company", "company_params"),
@@ -104,6 +129,11 @@ pub fn object_mappings() -> ObjectMap {
         ("update_customer_tax_info", "tax_info_params"),
         ("tax_info_params_type", "tax_info_type"),
 
+        // Config for `invoice` params
+        ("create_invoice_from_invoice", "from_invoice"),
+        ("create_invoice_rendering_amount_tax_display", "invoice_rendering_amount_tax_display"),
+        ("create_invoice_rendering_pdf_page_size", "invoice_rendering_pdf_page_size"),
+
         // Config for `invoiceitem` params
         ("create_invoiceitem", "create_invoice_item"),
         ("update_invoiceitem", "update_invoice_item"),
@@ -115,6 +145,22 @@ pub fn object_mappings() -> ObjectMap {
         ("create_order_items", "order_item_params"),
         ("order_items_params_type", "order_item_type"),
 
+        // Config for `price` params
+        ("currency_option_tax_behavior", "price_tax_behavior"),
+        ("create_price_tax_behavior", "price_tax_behavior"),
+        ("update_price_tax_behavior", "price_tax_behavior"),
+        ("update_price_currency_options", "create_price_currency_options"),
+        ("create_price_currency_options_tax_behavior", "price_tax_behavior"),
+        ("create_price_currency_options_tiers", "create_price_tiers"),
+
+        // Config for `product` params
+        ("create_product_features", "product_feature"),
+        ("update_product_features", "product_feature"),
+
+        // Config for `setup_intent` params
+        ("setup_intent_payment_method_options_acss_debit_verification_method", "setup_intent_payment_method_options_verification_method"),
+        ("setup_intent_payment_method_options_us_bank_account_verification_method", "setup_intent_payment_method_options_verification_method"),
+
         // Config for `source` params
         ("create_source_mandate", "source_mandate_params"),
         ("update_source_mandate", "source_mandate_params"),
@@ -140,6 +186,20 @@ pub fn object_mappings() -> ObjectMap {
         ("create_subscription_pending_invoice_item_interval_interval", "plan_interval"),
         ("update_subscription_pending_invoice_item_interval_interval", "plan_interval"),
         ("subscription_item_price_data_recurring_interval", "plan_interval"),
+        ("create_subscription_payment_settings", "subscription_payment_settings_params"),
+        ("update_subscription_payment_settings", "subscription_payment_settings_params"),
+        ("subscriptions_resource_payment_settings_payment_method_types", "subscription_payment_method_type"),
+        ("subscription_payment_settings_params_payment_method_types", "subscription_payment_method_type"),
+        ("subscriptions_resource_payment_settings_save_default_payment_method", "subscription_save_default_payment_method"),
+        ("subscription_payment_settings_params_save_default_payment_method", "subscription_save_default_payment_method"),
+        ("subscriptions_resource_payment_method_options", "subscription_payment_method_options"),
+        ("invoice_payment_method_options_acss_debit", "subscription_payment_method_options_acss_debit"),
+        ("invoice_payment_method_options_bancontact", "subscription_payment_method_options_bancontact"),
+        ("invoice_payment_method_options_us_bank_account", "subscription_payment_method_options_us_bank_account"),
+        ("subscriptions_trials_resource_trial_settings", "subscription_trial_settings"),
+        ("subscriptions_trials_resource_end_behavior", "subscription_trial_settings_end_behavior"),
+        ("subscription_payment_method_options_acss_debit_verification_method", "subscription_payment_method_options_verification_method"),
+        ("subscription_payment_method_options_us_bank_account_verification_method", "subscription_payment_method_options_verification_method"),
         ("create_subscription_item_price_data", "subscription_item_price_data"),
         ("update_subscription_item_price_data", "subscription_item_price_data"),
         ("create_subscription_items_price_data", "subscription_item_price_data"),
@@ -207,12 +267,37 @@ pub fn field_mappings() -> FieldMap {
             ("AccountHolderType", "Option<AccountHolderType>"),
         ),
         (("bank_account", "status"), ("BankAccountStatus", "Option<BankAccountStatus>")),
+        (("billing_portal_session", "customer"), ("CustomerId", "CustomerId")),
+        (("coupon_applies_to", "products"), ("ProductId", "Vec<ProductId>")),
+        (
+            ("payment_method_us_bank_account", "account_holder_type"),
+            ("AccountHolderType", "Option<AccountHolderType>"),
+        ),
         (("fee", "type"), ("FeeType", "FeeType")),
         (("charge", "source"), ("PaymentSource", "Option<PaymentSource>")),
+        (("issuing_card", "cvc"), ("Secret", "Option<Secret>")),
+        (("issuing_card", "number"), ("Secret", "Option<Secret>")),
+        (("payment_intent", "client_secret"), ("Secret", "Option<Secret>")),
+        (("setup_intent", "client_secret"), ("Secret", "Option<Secret>")),
+        (("source", "client_secret"), ("Secret", "Secret")),
+        (("webhook_endpoint", "secret"), ("Secret", "Option<Secret>")),
+        (("create_payout", "destination"), ("ExternalAccountId", "Option<ExternalAccountId>")),
         (
             ("charge_fraud_details", "stripe_report"),
             ("FraudDetailsReport", "Option<FraudDetailsReport>"),
         ),
+        (("discount", "checkout_session"), ("CheckoutSessionId", "Option<CheckoutSessionId>")),
+        (("discount", "invoice"), ("InvoiceId", "Option<InvoiceId>")),
+        (("discount", "promotion_code"), ("PromotionCode", "Option<Expandable<PromotionCode>>")),
+        (("discount", "subscription"), ("SubscriptionId", "Option<SubscriptionId>")),
+        (
+            ("payment_pages_checkout_session_customer_details", "tax_exempt"),
+            ("CustomerTaxExempt", "Option<CustomerTaxExempt>"),
+        ),
+        (
+            ("payment_pages_checkout_session_customer_details", "tax_ids"),
+            ("TaxIdData", "Vec<TaxIdData>"),
+        ),
         (("customer", "default_source"), ("PaymentSource", "Option<Expandable<PaymentSource>>")),
         (("customer", "sources"), ("PaymentSource", "List<PaymentSource>")),
         (("invoice", "billing"), ("", "Option<CollectionMethod>")),
@@ -303,6 +388,7 @@ pub fn field_mappings() -> FieldMap {
         ),
         (("file", "purpose"), ("", "FilePurpose")),
         (("order", "status"), ("", "OrderStatus")),
+        (("payout", "destination"), ("ExternalAccount", "Option<Expandable<ExternalAccount>>")),
         (("person", "dob"), ("Dob", "Option<Dob>")),
         (("recipient", "type"), ("", "Option<RecipientType>")),
         (("review", "reason"), ("ReviewReason", "ReviewReason")),
@@ -311,6 +397,22 @@ pub fn field_mappings() -> FieldMap {
             ("subscription", "default_source"),
             ("PaymentSource", "Option<Expandable<PaymentSource>>"),
         ),
+        (
+            ("subscription_pending_invoice_item_interval", "interval"),
+            ("PlanInterval", "PlanInterval"),
+        ),
+        (
+            ("create_subscription_pending_invoice_item_interval", "interval"),
+            ("PlanInterval", "PlanInterval"),
+        ),
+        (
+            ("update_subscription_pending_invoice_item_interval", "interval"),
+            ("PlanInterval", "PlanInterval"),
+        ),
+        (
+            ("subscription_item_price_data_recurring", "interval"),
+            ("PlanInterval", "PlanInterval"),
+        ),
         (("source", "flow"), ("", "SourceFlow")),
         (("source", "status"), ("SourceStatus", "SourceStatus")),
         (("source", "usage"), ("SourceUsage", "Option<SourceUsage>")),
@@ -354,6 +456,10 @@ pub fn field_mappings() -> FieldMap {
             ("subscription_schedule_default_settings_params", "collection_method"),
             ("CollectionMethod", "Option<CollectionMethod>"),
         ),
+        (
+            ("subscription_schedules_resource_default_settings", "collection_method"),
+            ("CollectionMethod", "Option<CollectionMethod>"),
+        ),
         (("token", "type"), ("TokenType", "TokenType")),
         (("transfer", "source_type"), ("", "Option<TransferSourceType>")),
         (("transfer_schedule", "weekly_anchor"), ("Weekday", "Option<Weekday>")),
@@ -380,11 +486,17 @@ pub fn field_mappings() -> FieldMap {
         // (("person_verification_params", "additional_document"), ("VerificationDocumentParams", "Option<VerificationDocumentParams>")),
 
         // Config for `charge` params
-        (("create_charge", "shipping"), ("Shipping", "Option<Shipping>")),
+        (("create_charge", "shipping"), ("ChargeShippingParams", "Option<ChargeShippingParams>")),
         (("create_charge", "source"), ("ChargeSourceParams", "Option<ChargeSourceParams>")),
-        (("update_charge", "shipping"), ("Shipping", "Option<Shipping>")),
+        (("update_charge", "shipping"), ("ChargeShippingParams", "Option<ChargeShippingParams>")),
         (("fraud_details_params", "user_report"), ("FraudDetailsReport", "FraudDetailsReport")),
 
+        // Config for `coupon` params
+        (("create_coupon", "applies_to"), ("", "Option<CouponAppliesTo>")),
+
+        // Config for `dispute` params
+        (("update_dispute", "evidence"), ("", "Option<DisputeEvidence>")),
+
         // Config for `customer` params
         (("create_customer", "address"), ("Address", "Option<Address>")),
         (("update_customer", "address"), ("Address", "Option<Address>")),
@@ -410,6 +522,16 @@ pub fn field_mappings() -> FieldMap {
         (("list_invoices", "billing"), ("", "Option<CollectionMethod>")),
         (("create_invoice", "billing"), ("", "Option<CollectionMethod>")),
         (("create_invoice", "custom_fields"), ("CustomField", "Option<Vec<CustomField>>")),
+        (("update_invoice", "custom_fields"), ("CustomField", "Option<Vec<CustomField>>")),
+        (("from_invoice", "invoice"), ("InvoiceId", "InvoiceId")),
+        (
+            ("create_invoice_payment_settings", "payment_method_options"),
+            ("SubscriptionPaymentMethodOptions", "Option<SubscriptionPaymentMethodOptions>"),
+        ),
+        (
+            ("create_invoice_payment_settings", "payment_method_types"),
+            ("SubscriptionPaymentMethodType", "Option<Vec<SubscriptionPaymentMethodType>>"),
+        ),
 
         // Config for `invoiceitem` params
         (("create_invoice_item", "period"), ("Period", "Option<Period>")),
@@ -423,14 +545,79 @@ pub fn field_mappings() -> FieldMap {
         // Config for `payment_intent` params
         (("payment_intent", "source"), ("PaymentSource", "Option<Expandable<PaymentSource>>")),
         (("payment_intent_next_action", "use_stripe_sdk"), ("", "Option<serde_json::Value>")),
-        (("create_payment_intent", "shipping"), ("ShippingParams", "Option<ShippingParams>")),
+        (("create_payment_intent", "shipping"), ("ChargeShippingParams", "Option<ChargeShippingParams>")),
         (
             ("create_payment_intent", "off_session"),
             ("Option<PaymentIntentOffSession>", "Option<PaymentIntentOffSession>"),
         ),
-        (("update_payment_intent", "shipping"), ("ShippingParams", "Option<ShippingParams>")),
+        (("update_payment_intent", "shipping"), ("ChargeShippingParams", "Option<ChargeShippingParams>")),
         (("create_setup_intent", "usage"), ("", "Option<SetupIntentUsage>")),
         (("setup_intent_next_action", "use_stripe_sdk"), ("", "Option<serde_json::Value>")),
+        (
+            ("setup_intent_next_action", "verify_with_microdeposits"),
+            (
+                "PaymentIntentNextActionVerifyWithMicrodeposits",
+                "Option<PaymentIntentNextActionVerifyWithMicrodeposits>",
+            ),
+        ),
+        (
+            ("create_setup_intent_payment_method_options", "acss_debit"),
+            ("SetupIntentPaymentMethodOptionsAcssDebit", "Option<SetupIntentPaymentMethodOptionsAcssDebit>"),
+        ),
+        (
+            ("create_setup_intent_payment_method_options", "sepa_debit"),
+            ("SetupIntentPaymentMethodOptionsSepaDebit", "Option<SetupIntentPaymentMethodOptionsSepaDebit>"),
+        ),
+        (
+            ("create_setup_intent_payment_method_options", "us_bank_account"),
+            ("SetupIntentPaymentMethodOptionsUsBankAccount", "Option<SetupIntentPaymentMethodOptionsUsBankAccount>"),
+        ),
+        (
+            ("create_setup_intent_payment_method_options_card", "mandate_options"),
+            (
+                "SetupIntentPaymentMethodOptionsCardMandateOptions",
+                "Option<SetupIntentPaymentMethodOptionsCardMandateOptions>",
+            ),
+        ),
+        (
+            ("update_setup_intent_payment_method_options", "acss_debit"),
+            ("SetupIntentPaymentMethodOptionsAcssDebit", "Option<SetupIntentPaymentMethodOptionsAcssDebit>"),
+        ),
+        (
+            ("update_setup_intent_payment_method_options", "sepa_debit"),
+            ("SetupIntentPaymentMethodOptionsSepaDebit", "Option<SetupIntentPaymentMethodOptionsSepaDebit>"),
+        ),
+        (
+            ("update_setup_intent_payment_method_options", "us_bank_account"),
+            ("SetupIntentPaymentMethodOptionsUsBankAccount", "Option<SetupIntentPaymentMethodOptionsUsBankAccount>"),
+        ),
+        (
+            ("update_setup_intent_payment_method_options_card", "mandate_options"),
+            (
+                "SetupIntentPaymentMethodOptionsCardMandateOptions",
+                "Option<SetupIntentPaymentMethodOptionsCardMandateOptions>",
+            ),
+        ),
+
+        // Config for `payment_method` params
+        (
+            ("create_payment_method_us_bank_account", "account_holder_type"),
+            ("AccountHolderType", "Option<AccountHolderType>"),
+        ),
+        (
+            ("create_payment_method_us_bank_account", "account_type"),
+            ("", "Option<PaymentMethodUsBankAccountAccountType>"),
+        ),
+
+        // Config for `product` params
+        (("product", "features"), ("", "Vec<ProductFeature>")),
+        (("create_product", "tax_code"), ("TaxCodeId", "Option<TaxCodeId>")),
+        (("update_product", "tax_code"), ("TaxCodeId", "Option<TaxCodeId>")),
+        (("update_product", "default_price"), ("PriceId", "Option<PriceId>")),
+        (
+            ("create_product_default_price_data", "tax_behavior"),
+            ("PriceTaxBehavior", "Option<PriceTaxBehavior>"),
+        ),
 
         // Config for `sku` params
         (("list_skus", "attributes"), ("Metadata", "Option<Metadata>")),
@@ -496,6 +683,18 @@ pub fn field_mappings() -> FieldMap {
         ),
         (("create_subscription", "trial_end"), ("Scheduled", "Option<Scheduled>")),
         (("update_subscription", "trial_end"), ("Scheduled", "Option<Scheduled>")),
+        (
+            ("subscription_payment_settings_params", "payment_method_options"),
+            ("SubscriptionPaymentMethodOptions", "Option<SubscriptionPaymentMethodOptions>"),
+        ),
+        (
+            ("create_subscription", "trial_settings"),
+            ("SubscriptionTrialSettings", "Option<SubscriptionTrialSettings>"),
+        ),
+        (
+            ("update_subscription", "trial_settings"),
+            ("SubscriptionTrialSettings", "Option<SubscriptionTrialSettings>"),
+        ),
 
         // Config for `subscription_schedule` params
         (("create_subscription_schedule", "collection_method"), ("CollectionMethod", "Option<CollectionMethod>")),
@@ -586,3 +785,119 @@ pub fn field_mappings() -> FieldMap {
     .copied()
     .collect()
 }
+
+/// Fields the spec marks as required but that some API versions leave out.
+///
+/// These are generated as `Option` so that the responses still deserialize.
+#[rustfmt::skip]
+pub fn version_optional_fields() -> BTreeSet<FieldSpec> {
+    [
+        // Discounts have no id before 2020-08-27, and `start` isn't reliably sent either.
+        ("discount", "id"),
+        ("discount", "start"),
+        // Only sent since 2020-08-27.
+        ("invoice", "automatic_tax"),
+        // Moved to the subscription items in 2025-03-31.basil.
+        ("subscription", "current_period_end"),
+        ("subscription", "current_period_start"),
+    ]
+    .iter()
+    .copied()
+    .collect()
+}
+
+/// Enums on responses that Stripe adds values to, which are generated with an
+/// `Other(String)` variant holding values that aren't known yet.
+#[rustfmt::skip]
+pub fn open_enums() -> BTreeSet<&'static str> {
+    [
+        "BalanceTransactionReportingCategory",
+        "BalanceTransactionType",
+        "FundingInstructionsBankTransferFinancialAddressSupportedNetworks",
+        "FundingInstructionsBankTransferFinancialAddressType",
+        "PaymentIntentNextActionDisplayBankTransferInstructionsType",
+        "PayoutFailureCode",
+        "PayoutStatus",
+        "RefundFailureReason",
+        "RefundStatus",
+        "SubscriptionPaymentMethodType",
+        "TaxIdType",
+        "TerminalReaderActionType",
+        "TerminalReaderDeviceType",
+    ]
+    .iter()
+    .copied()
+    .collect()
+}
+
+/// Structs used in request params that derive `Default`, so that callers
+/// can fill in only the fields they need.
+#[rustfmt::skip]
+pub fn default_structs() -> BTreeSet<&'static str> {
+    [
+        // Config for `billing_portal_session` params
+        "PortalFlowAfterCompletionHostedConfirmation",
+        "PortalFlowAfterCompletionRedirect",
+        "PortalFlowSubscriptionCancel",
+        "PortalFlowSubscriptionUpdate",
+        "PortalFlowSubscriptionUpdateConfirm",
+        "PortalFlowSubscriptionUpdateConfirmItem",
+
+        // Config for `checkout_session` params
+        "CheckoutSessionCustomText",
+
+        // Config for `coupon` params
+        "CouponAppliesTo",
+        "CouponCurrencyOption",
+        "CreateCouponCurrencyOptions",
+        "UpdateCouponCurrencyOptions",
+
+        // Config for `dispute` params
+        "DisputeEvidence",
+
+        // Config for `invoice` params
+        "CreateInvoiceAutomaticTax",
+        "CreateInvoiceDiscounts",
+        "CreateInvoicePaymentSettings",
+        "CreateInvoiceRendering",
+        "CreateInvoiceRenderingPdf",
+        "UpdateInvoiceAutomaticTax",
+        "UpdateInvoiceDiscounts",
+
+        // Config for `invoiceitem` params
+        "CreateInvoiceItemDiscounts",
+        "UpdateInvoiceItemDiscounts",
+
+        // Config for `payment_method` params
+        "CreatePaymentMethodUsBankAccount",
+
+        // Config for `price` params
+        "CreatePriceCurrencyOptions",
+
+        // Config for `product` params
+        "ProductFeature",
+
+        // Config for `setup_intent` params
+        "CreateSetupIntentPaymentMethodOptions",
+        "CreateSetupIntentPaymentMethodOptionsCard",
+        "LinkedAccountOptionsUsBankAccount",
+        "SetupIntentPaymentMethodOptionsAcssDebit",
+        "SetupIntentPaymentMethodOptionsMandateOptionsAcssDebit",
+        "SetupIntentPaymentMethodOptionsMandateOptionsSepaDebit",
+        "SetupIntentPaymentMethodOptionsSepaDebit",
+        "SetupIntentPaymentMethodOptionsUsBankAccount",
+        "UpdateSetupIntentPaymentMethodOptions",
+        "UpdateSetupIntentPaymentMethodOptionsCard",
+
+        // Config for `subscription` params
+        "SubscriptionPaymentMethodOptions",
+        "SubscriptionPaymentMethodOptionsAcssDebit",
+        "SubscriptionPaymentMethodOptionsBancontact",
+        "SubscriptionPaymentMethodOptionsCard",
+        "SubscriptionPaymentMethodOptionsUsBankAccount",
+        "SubscriptionPaymentSettingsParams",
+    ]
+    .iter()
+    .copied()
+    .collect()
+}
@@ -38,6 +38,7 @@ pub fn feature_groups() -> BTreeMap<&'static str, &'static str> {
 		("invoiceitem", "billing"),
         ("line_item", "billing"),
 		("plan", "billing"),
+		("price", "billing"),
 		("subscription", "billing"),
 		("subscription_item", "billing"),
 		("subscription_schedule", "billing"),
@@ -83,3 +84,13 @@ pub fn feature_groups() -> BTreeMap<&'static str, &'static str> {
 	.copied()
 	.collect()
 }
+
+/// Enums on feature-gated objects that are also used by params which are
+/// always available, so they need a placeholder when the feature is disabled.
+#[rustfmt::skip]
+pub fn feature_group_enums() -> Vec<(&'static str, &'static str)> {
+    vec![
+        // Used by `CreateProductDefaultPriceData`
+        ("price", "tax_behavior"),
+    ]
+}
@@ -1,15 +1,17 @@
 use std::future::Future;
 use std::pin::Pin;
+use std::time::Duration;
 
 use futures_util::future;
 use http::header::{HeaderMap, HeaderName, HeaderValue};
 use http::request::Builder as RequestBuilder;
+use http::StatusCode;
 use hyper::client::connect::dns::GaiResolver;
 use hyper::client::HttpConnector;
 use serde::de::DeserializeOwned;
 
-use crate::error::{Error, ErrorResponse, RequestError};
-use crate::params::{AppInfo, Headers};
+use crate::error::{Error, ErrorResponse, ErrorType, RequestError};
+use crate::params::{to_form_body, AppInfo, Headers};
 use crate::resources::ApiVersion;
 
 #[cfg(feature = "rustls-tls")]
@@ -33,6 +35,10 @@ fn new_connector() -> hyper_tls::HttpsConnector<HttpConnector<GaiResolver>> {
 
 type HttpClient = hyper::Client<HttpsConnector<hyper::client::HttpConnector>, hyper::Body>;
 
+fn new_http_client() -> HttpClient {
+    hyper::Client::builder().pool_max_idle_per_host(0).build(new_connector())
+}
+
 pub type Response<T> = Pin<Box<dyn Future<Output = Result<T, Error>> + Send>>;
 
 #[allow(dead_code)]
@@ -47,15 +53,31 @@ pub(crate) fn err<T: Send + 'static>(err: Error) -> Response<T> {
     Box::pin(future::ready(Err(err)))
 }
 
+#[cfg(not(feature = "blocking"))]
+#[inline(always)]
+pub(crate) fn map<T, U, F>(response: Response<T>, f: F) -> Response<U>
+where
+    T: Send + 'static,
+    U: Send + 'static,
+    F: FnOnce(T) -> U + Send + 'static,
+{
+    Box::pin(async move { response.await.map(f) })
+}
+
 #[derive(Clone)]
 pub struct Client {
     host: String,
     client: HttpClient,
     secret_key: String,
     headers: Headers,
+    extra_headers: HeaderMap,
     app_info: Option<AppInfo>,
 }
 
+/// Headers which are always set by the client and can't be replaced by `with_extra_headers`.
+const PROTECTED_HEADERS: &[&str] =
+    &["authorization", "idempotency-key", "stripe-account", "stripe-version"];
+
 impl Client {
     /// Creates a new client pointed to `https://api.stripe.com/`
     pub fn new(secret_key: impl Into<String>) -> Client {
@@ -66,8 +88,7 @@ impl Client {
     pub fn from_url(scheme_host: impl Into<String>, secret_key: impl Into<String>) -> Client {
         let url = scheme_host.into();
         let host = if url.ends_with('/') { format!("{}v1", url) } else { format!("{}/v1", url) };
-        let https = new_connector();
-        let client = hyper::Client::builder().pool_max_idle_per_host(0).build(https);
+        let client = new_http_client();
         let mut headers = Headers::default();
         // TODO: Automatically determine the latest supported api version in codegen?
         headers.stripe_version = Some(ApiVersion::V2019_09_09);
@@ -76,6 +97,7 @@ impl Client {
             client,
             secret_key: secret_key.into(),
             headers,
+            extra_headers: HeaderMap::new(),
             app_info: Some(AppInfo::default()),
         }
     }
@@ -90,10 +112,55 @@ impl Client {
         client
     }
 
+    /// Clones a new client with a connection pool of its own.
+    ///
+    /// Connections are bound to the runtime which opened them, so a client
+    /// which moves to another runtime must not share its pool.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn with_new_pool(&self) -> Client {
+        let mut client = self.clone();
+        client.client = new_http_client();
+        client
+    }
+
     pub fn set_app_info(&mut self, name: String, version: Option<String>, url: Option<String>) {
         self.app_info = Some(AppInfo { name, url, version });
     }
 
+    /// Clones a new client which identifies itself with the given app info.
+    ///
+    /// See `app_info_from_env!` to fill it in from your crate's `Cargo.toml`.
+    pub fn with_app_info(&self, app_info: AppInfo) -> Client {
+        let mut client = self.clone();
+        client.app_info = Some(app_info);
+        client
+    }
+
+    /// Clones a new client which sends requests with the given Stripe-Version header.
+    ///
+    /// This is useful for trying out a newer API version on a subset of requests
+    /// while the rest of the application stays pinned to the client's version.
+    /// Pass the same client to `List::next` or `List::get_all` so that every page of a list
+    /// is fetched with the same version.
+    pub fn with_stripe_version(&self, version: ApiVersion) -> Client {
+        let mut client = self.clone();
+        client.headers.stripe_version = Some(version);
+        client
+    }
+
+    /// Clones a new client which adds the given headers to every request.
+    ///
+    /// This is useful when requests to Stripe go through a proxy or gateway which expects headers of its own.
+    /// The extra headers are merged in after the Stripe headers, replacing any header of the same name,
+    /// except for `Authorization`, `Idempotency-Key`, `Stripe-Account` and `Stripe-Version` which are ignored.
+    /// Pass the same client to `List::next` or `List::get_all` so that every page of a list
+    /// is fetched with the extra headers.
+    pub fn with_extra_headers(&self, extra_headers: HeaderMap) -> Client {
+        let mut client = self.clone();
+        client.extra_headers = extra_headers;
+        client
+    }
+
     /// Sets a value for the Stripe-Account header
     ///
     /// This is recommended if you are acting as only one Account for the lifetime of the client.
@@ -162,6 +229,9 @@ impl Client {
     }
 
     /// Make a `POST` http request with urlencoded body
+    ///
+    /// The body is encoded with [`to_form_body`](crate::to_form_body), so sequences are sent as
+    /// indexed fields (`items[0][price]=...`) which is the form Stripe expects.
     pub fn post_form<T: DeserializeOwned + Send + 'static, F: serde::Serialize>(
         &self,
         path: &str,
@@ -171,8 +241,8 @@ impl Client {
         let mut req = RequestBuilder::new()
             .method("POST")
             .uri(url)
-            .body(match serde_qs::to_string(&form) {
-                Err(err) => return Box::pin(future::ready(Err(Error::serialize(err)))),
+            .body(match to_form_body(&form) {
+                Err(err) => return Box::pin(future::ready(Err(err))),
                 Ok(body) => hyper::Body::from(body),
             })
             .unwrap();
@@ -189,7 +259,7 @@ impl Client {
     }
 
     fn url_with_params<P: serde::Serialize>(&self, path: &str, params: P) -> Result<String, Error> {
-        let params = serde_qs::to_string(&params).map_err(Error::serialize)?;
+        let params = to_form_body(&params)?;
         Ok(format!("{}/{}?{}", self.host, &path[1..], params))
     }
 
@@ -233,6 +303,15 @@ impl Client {
                 HeaderValue::from_str(user_agent.as_str()).unwrap(),
             );
         };
+        for name in self.extra_headers.keys() {
+            if PROTECTED_HEADERS.contains(&name.as_str()) {
+                continue;
+            }
+            headers.remove(name);
+            for value in self.extra_headers.get_all(name) {
+                headers.append(name.clone(), value.clone());
+            }
+        }
         headers
     }
 }
@@ -243,31 +322,170 @@ fn send<T: DeserializeOwned + Send + 'static>(
 ) -> Response<T> {
     let client = client.clone(); // N.B. Client is send sync;  cloned clients share the same pool.
     Box::pin(async move {
-        let response = client.request(request).await?;
-        let status = response.status();
-        let bytes = hyper::body::to_bytes(response.into_body()).await?;
-        if !status.is_success() {
-            let mut err = serde_json::from_slice(&bytes).unwrap_or_else(|err| {
-                let mut req = ErrorResponse { error: RequestError::default() };
-                req.error.message = Some(format!("failed to deserialize error: {}", err));
-                req
-            });
-            err.error.http_status = status.as_u16();
-            Err(Error::from(err.error))?;
+        let (parts, body) = client.request(request).await?.into_parts();
+        let bytes = hyper::body::to_bytes(body).await?;
+        if !parts.status.is_success() {
+            Err(response_error(parts.status, &parts.headers, &bytes))?;
         }
         serde_json::from_slice(&bytes).map_err(Error::deserialize)
     })
 }
 
+/// Builds the error for an unsuccessful response from its status, headers and body.
+fn response_error(status: StatusCode, headers: &HeaderMap, body: &[u8]) -> Error {
+    let header = |name: &str| Some(headers.get(name)?.to_str().ok()?.trim().to_owned());
+    let mut err = serde_json::from_slice(body).unwrap_or_else(|err| {
+        let mut req = ErrorResponse { error: RequestError::default() };
+        req.error.message = Some(format!("failed to deserialize error: {}", err));
+        req
+    });
+    err.error.http_status = status.as_u16();
+    if err.error.error_type == ErrorType::Idempotency {
+        return Error::IdempotencyMismatch {
+            error: Box::new(err.error),
+            request_id: header("request-id"),
+            original_request_id: header("original-request"),
+        };
+    }
+    err.error.retry_after =
+        header("retry-after").and_then(|value| value.parse().ok()).map(Duration::from_secs);
+    Error::from(err.error)
+}
+
 /// Formats a plugin's 'App Info' into a string that can be added to the end of an User-Agent string.
 ///
 /// This formatting matches that of other libraries, and if changed then it should be changed everywhere.
 fn format_app_info(info: &AppInfo) -> String {
-    let formatted: String = match (&info.version, &info.url) {
-        (Some(a), Some(b)) => format!("{}/{} ({})", &info.name, a, b),
-        (Some(a), None) => format!("{}/{}", &info.name, a),
-        (None, Some(b)) => format!("{}/{}", &info.name, b),
-        _ => info.name.to_string(),
+    let name = sanitize_app_info(&info.name);
+    let version = info.version.as_deref().map(sanitize_app_info);
+    let url = info.url.as_deref().map(sanitize_app_info);
+    let formatted: String = match (version, url) {
+        (Some(a), Some(b)) => format!("{}/{} ({})", name, a, b),
+        (Some(a), None) => format!("{}/{}", name, a),
+        (None, Some(b)) => format!("{} ({})", name, b),
+        _ => name,
     };
     formatted
 }
+
+/// Makes an app info component safe to put in a header value.
+///
+/// Control characters (including newlines) are dropped and non-ASCII characters are percent-escaped,
+/// since `HeaderValue` only accepts visible ASCII.
+fn sanitize_app_info(value: &str) -> String {
+    let mut sanitized = String::with_capacity(value.len());
+    for ch in value.trim().chars() {
+        if ch.is_ascii_control() {
+            continue;
+        } else if ch.is_ascii() {
+            sanitized.push(ch);
+        } else {
+            let mut buf = [0; 4];
+            for byte in ch.encode_utf8(&mut buf).bytes() {
+                sanitized.push_str(&format!("%{:02X}", byte));
+            }
+        }
+    }
+    sanitized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_stripe_version() {
+        let client = Client::new("sk_test_123");
+        let versioned = client.with_stripe_version(ApiVersion::V2020_08_27);
+        assert_eq!(client.headers()["stripe-version"], "2019-09-09");
+        assert_eq!(versioned.headers()["stripe-version"], "2020-08-27");
+    }
+
+    #[test]
+    fn with_extra_headers() {
+        let mut extra = HeaderMap::new();
+        extra.insert("x-gateway-auth", HeaderValue::from_static("secret"));
+        extra.append("x-trace", HeaderValue::from_static("a"));
+        extra.append("x-trace", HeaderValue::from_static("b"));
+        extra.insert("authorization", HeaderValue::from_static("Bearer sk_other"));
+        extra.insert("stripe-version", HeaderValue::from_static("2024-06-20"));
+
+        let client = Client::new("sk_test_123").with_extra_headers(extra);
+        let headers = client.headers();
+        assert_eq!(headers["x-gateway-auth"], "secret");
+        assert_eq!(headers.get_all("x-trace").iter().collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!(headers["authorization"], "Bearer sk_test_123");
+        assert_eq!(headers["stripe-version"], "2019-09-09");
+
+        let versioned = client.with_stripe_version(ApiVersion::V2020_08_27);
+        assert_eq!(versioned.headers()["x-gateway-auth"], "secret");
+    }
+
+    #[test]
+    fn user_agent_app_info() {
+        let user_agent = |info: AppInfo| {
+            let client = Client::new("sk_test_123").with_app_info(info);
+            client.headers()["user-agent"].to_str().unwrap().to_string()
+        };
+        let base = format!("Stripe/v3 RustBindings/{}", env!("CARGO_PKG_VERSION"));
+
+        let info = AppInfo {
+            name: "MyPlugin".to_string(),
+            url: Some("https://myplugin.example".to_string()),
+            version: Some("1.2.3".to_string()),
+        };
+        assert_eq!(user_agent(info), format!("{} MyPlugin/1.2.3 (https://myplugin.example)", base));
+
+        let info = AppInfo {
+            name: "MyPlugin".to_string(),
+            url: Some("https://myplugin.example".to_string()),
+            version: None,
+        };
+        assert_eq!(user_agent(info), format!("{} MyPlugin (https://myplugin.example)", base));
+
+        assert_eq!(user_agent(AppInfo::default()), base);
+    }
+
+    #[test]
+    fn user_agent_hostile_app_info() {
+        let info = AppInfo {
+            name: "Café Zürich\r\nX-Injected: 1".to_string(),
+            url: Some("https://例え.jp\n".to_string()),
+            version: Some("\t1.0\u{7f}".to_string()),
+        };
+        let client = Client::new("sk_test_123").with_app_info(info);
+        let headers = client.headers();
+        let user_agent = headers["user-agent"].to_str().unwrap();
+        assert!(user_agent
+            .ends_with(" Caf%C3%A9 Z%C3%BCrichX-Injected: 1/1.0 (https://%E4%BE%8B%E3%81%88.jp)"));
+        assert!(!headers.contains_key("x-injected"));
+    }
+
+    #[test]
+    fn response_error() {
+        let mut headers = HeaderMap::new();
+        headers.insert("request-id", HeaderValue::from_static("req_2"));
+        headers.insert("original-request", HeaderValue::from_static("req_1"));
+        headers.insert("retry-after", HeaderValue::from_static("2"));
+
+        let body = br#"{"error": {"type": "idempotency_error", "message": "Keys for idempotent requests can only be used with the same parameters they were first used with."}}"#;
+        match super::response_error(StatusCode::BAD_REQUEST, &headers, body) {
+            Error::IdempotencyMismatch { error, request_id, original_request_id } => {
+                assert_eq!(error.http_status, 400);
+                assert!(error.message.unwrap().starts_with("Keys for idempotent requests"));
+                assert_eq!(request_id.as_deref(), Some("req_2"));
+                assert_eq!(original_request_id.as_deref(), Some("req_1"));
+            }
+            other => panic!("expected an idempotency mismatch; was {:?}", other),
+        }
+
+        let body = br#"{"error": {"type": "rate_limit_error"}}"#;
+        match super::response_error(StatusCode::TOO_MANY_REQUESTS, &headers, body) {
+            Error::Stripe(err) => {
+                assert_eq!(err.http_status, 429);
+                assert_eq!(err.retry_after, Some(Duration::from_secs(2)));
+            }
+            other => panic!("expected a stripe error; was {:?}", other),
+        }
+    }
+}
@@ -1,9 +1,13 @@
 use crate::client::r#async::Client as AsyncClient;
 use crate::error::Error;
-use crate::params::Headers;
+use crate::params::{AppInfo, Headers};
+use crate::resources::ApiVersion;
+use http::header::HeaderMap;
 use serde::de::DeserializeOwned;
+use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::runtime::RuntimeFlavor;
 
 /// The delay after which the blocking `Client` will assume the request has failed.
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
@@ -20,10 +24,50 @@ pub(crate) fn err<T>(err: crate::Error) -> Response<T> {
     Err(err)
 }
 
+#[inline(always)]
+pub(crate) fn map<T, U, F>(response: Response<T>, f: F) -> Response<U>
+where
+    F: FnOnce(T) -> U,
+{
+    response.map(f)
+}
+
 #[derive(Clone)]
 pub struct Client {
     inner: AsyncClient,
-    runtime: Arc<tokio::runtime::Runtime>,
+    runtime: Arc<Runtime>,
+}
+
+/// The runtime driving the requests of a blocking `Client`.
+enum Runtime {
+    /// A runtime owned by the client.
+    ///
+    /// It is shut down in the background when dropped, so that a client can also be dropped
+    /// from within an async context (where dropping a `tokio::runtime::Runtime` panics).
+    Owned(Option<tokio::runtime::Runtime>),
+    /// A runtime provided with `Client::with_runtime_handle`.
+    Handle(tokio::runtime::Handle),
+}
+
+impl Runtime {
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        match self {
+            Runtime::Owned(runtime) => {
+                runtime.as_ref().expect("runtime is only taken when dropped").block_on(future)
+            }
+            Runtime::Handle(handle) => handle.block_on(future),
+        }
+    }
+}
+
+impl Drop for Runtime {
+    fn drop(&mut self) {
+        if let Runtime::Owned(runtime) = self {
+            if let Some(runtime) = runtime.take() {
+                runtime.shutdown_background();
+            }
+        }
+    }
 }
 
 impl Client {
@@ -43,7 +87,18 @@ impl Client {
             .enable_time() // use separate `io/time` instead of `all` to ensure `tokio/time` is enabled
             .build()
             .unwrap();
-        Client { inner, runtime: Arc::new(runtime) }
+        Client { inner, runtime: Arc::new(Runtime::Owned(Some(runtime))) }
+    }
+
+    /// Clones a new client which drives its requests on the runtime of `handle`,
+    /// instead of on a runtime of its own.
+    ///
+    /// This avoids starting a second runtime in applications which already run tokio.
+    /// The handle should belong to a multi-thread runtime: a current-thread runtime can't
+    /// make progress on the request while its only thread is blocked waiting for it.
+    /// The new client opens its own connections rather than sharing those of `self`.
+    pub fn with_runtime_handle(&self, handle: tokio::runtime::Handle) -> Client {
+        Client { inner: self.inner.with_new_pool(), runtime: Arc::new(Runtime::Handle(handle)) }
     }
 
     /// Clones a new client with different headers.
@@ -58,6 +113,33 @@ impl Client {
         self.inner.set_app_info(name, version, url);
     }
 
+    /// Clones a new client which identifies itself with the given app info.
+    ///
+    /// See `app_info_from_env!` to fill it in from your crate's `Cargo.toml`.
+    pub fn with_app_info(&self, app_info: AppInfo) -> Client {
+        Client { inner: self.inner.with_app_info(app_info), runtime: self.runtime.clone() }
+    }
+
+    /// Clones a new client which sends requests with the given Stripe-Version header.
+    ///
+    /// This is useful for trying out a newer API version on a subset of requests
+    /// while the rest of the application stays pinned to the client's version.
+    /// Pass the same client to `List::next` or `List::get_all` so that every page of a list
+    /// is fetched with the same version.
+    pub fn with_stripe_version(&self, version: ApiVersion) -> Client {
+        Client { inner: self.inner.with_stripe_version(version), runtime: self.runtime.clone() }
+    }
+
+    /// Clones a new client which adds the given headers to every request.
+    ///
+    /// See the async `Client::with_extra_headers` for which headers can't be replaced.
+    pub fn with_extra_headers(&self, extra_headers: HeaderMap) -> Client {
+        Client {
+            inner: self.inner.with_extra_headers(extra_headers),
+            runtime: self.runtime.clone(),
+        }
+    }
+
     /// Sets a value for the Stripe-Account header
     ///
     /// This is recommended if you are acting as only one Account for the lifetime of the client.
@@ -112,13 +194,31 @@ impl Client {
         &self,
         request: super::r#async::Response<T>,
     ) -> Response<T> {
-        match self.runtime.block_on(async {
+        let runtime = self.runtime.clone();
+        let send = move || match runtime.block_on(async {
             // N.B. The `tokio::time::timeout` must be called from within a running async
             //      context or else it will panic (it registers with the thread-local timer).
             tokio::time::timeout(DEFAULT_TIMEOUT, request).await
         }) {
             Ok(finished) => finished,
             Err(_) => Err(Error::timeout()),
+        };
+
+        // N.B. Blocking on a runtime from a thread which is already driving one panics,
+        //      e.g. when the blocking client is used from inside an async application.
+        let current = match tokio::runtime::Handle::try_current() {
+            Ok(current) => current,
+            Err(_) => return send(),
+        };
+        match current.runtime_flavor() {
+            // Let the runtime move its other tasks off this worker thread while it waits.
+            RuntimeFlavor::MultiThread => tokio::task::block_in_place(send),
+            // The only worker thread can't be handed over, so wait on the blocking pool instead.
+            _ => {
+                let (tx, rx) = std::sync::mpsc::channel();
+                current.spawn_blocking(move || tx.send(send()));
+                rx.recv().unwrap_or(Err(Error::Unexpected("blocking request was dropped")))
+            }
         }
     }
 }
@@ -1,13 +1,31 @@
 use crate::params::to_snakecase;
 use serde_derive::{Deserialize, Serialize};
 use std::num::ParseIntError;
+use std::time::Duration;
 
 /// An error encountered when communicating with the Stripe API.
 #[derive(Debug)]
 pub enum Error {
     /// An error reported by Stripe in the response body.
     Stripe(RequestError),
-    /// An http or networking error communicating with the Stripe server.
+    /// An `Idempotency-Key` was reused for a request with different parameters than the original.
+    ///
+    /// Retrying won't help, as it means two different requests were sent with the same key.
+    IdempotencyMismatch {
+        /// The error Stripe reported, with its message, http status and code.
+        error: Box<RequestError>,
+        /// The `Request-Id` of the rejected request.
+        request_id: Option<String>,
+        /// The `Request-Id` of the request which first used the key, if Stripe reported it.
+        original_request_id: Option<String>,
+    },
+    /// No response was received before the request timed out.
+    Timeout,
+    /// The request could not be sent, or the connection failed before a response was received.
+    ///
+    /// Stripe may or may not have received the request.
+    NetworkFailure { message: String },
+    /// An http error communicating with the Stripe server.
     Http(HttpError),
     /// An error reading the response body.
     Io(std::io::Error),
@@ -24,7 +42,65 @@ pub enum Error {
 impl Error {
     #[allow(dead_code)]
     pub(crate) fn timeout() -> Error {
-        Error::Http(HttpError::Timeout)
+        Error::Timeout
+    }
+
+    /// Classifies an IO error on the connection to Stripe, or returns `None`
+    /// if it doesn't indicate a network failure.
+    fn network_failure(err: &std::io::Error) -> Option<Error> {
+        use std::io::ErrorKind;
+
+        match err.kind() {
+            ErrorKind::TimedOut => Some(Error::Timeout),
+            ErrorKind::ConnectionRefused
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::NotConnected
+            | ErrorKind::AddrNotAvailable
+            | ErrorKind::BrokenPipe => Some(Error::NetworkFailure { message: err.to_string() }),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if no response was received before the request timed out.
+    ///
+    /// This is `Error::Timeout`, or a `504 Gateway Timeout` returned in front of the Stripe API.
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            Error::Timeout | Error::Http(HttpError::Timeout) => true,
+            Error::Stripe(err) => err.http_status == 504,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if Stripe rejected the request because too many requests were sent.
+    ///
+    /// This is a `rate_limit_error`, or a `429 Too Many Requests` without an error body;
+    /// `RequestError::retry_after` says how long to wait before retrying.
+    pub fn is_rate_limited(&self) -> bool {
+        match self {
+            Error::Stripe(err) => err.error_type == ErrorType::RateLimit || err.http_status == 429,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if sending the same request again may succeed.
+    ///
+    /// This is the case for timeouts (see `Error::is_timeout`), `Error::NetworkFailure`,
+    /// rate limiting (`rate_limit_error` or `429`) and server errors (`api_error` or `5xx`),
+    /// but never for `Error::IdempotencyMismatch`.
+    /// Stripe may have processed the original request, so retried `POST` requests should carry an idempotency key.
+    pub fn is_retryable(&self) -> bool {
+        if self.is_timeout() {
+            return true;
+        }
+        match self {
+            Error::NetworkFailure { .. } => true,
+            Error::Stripe(err) => {
+                self.is_rate_limited() || err.error_type == ErrorType::Api || err.http_status >= 500
+            }
+            _ => false,
+        }
     }
 
     pub(crate) fn serialize<T>(err: T) -> Error
@@ -48,6 +124,16 @@ impl std::fmt::Display for Error {
         f.write_str(std::error::Error::description(self))?;
         match *self {
             Error::Stripe(ref err) => write!(f, ": {}", err),
+            Error::IdempotencyMismatch { ref request_id, ref original_request_id, .. } => {
+                let request_id = request_id.as_deref().unwrap_or("unknown");
+                write!(f, ": request {}", request_id)?;
+                match original_request_id {
+                    Some(original) => write!(f, " reused the key of {}", original),
+                    None => Ok(()),
+                }
+            }
+            Error::Timeout => Ok(()),
+            Error::NetworkFailure { ref message } => write!(f, ": {}", message),
             Error::Http(ref err) => write!(f, ": {}", err),
             Error::Io(ref err) => write!(f, ": {}", err),
             Error::Serialize(ref err) => write!(f, ": {}", err),
@@ -62,6 +148,9 @@ impl std::error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::Stripe(_) => "error reported by stripe",
+            Error::IdempotencyMismatch { .. } => "idempotency key reused with different parameters",
+            Error::Timeout => "request timed out",
+            Error::NetworkFailure { .. } => "network failure communicating with stripe",
             Error::Http(_) => "error communicating with stripe",
             Error::Io(_) => "error reading response from stripe",
             Error::Serialize(_) => "error serializing a request",
@@ -74,6 +163,9 @@ impl std::error::Error for Error {
     fn cause(&self) -> Option<&dyn std::error::Error> {
         match *self {
             Error::Stripe(ref err) => Some(err),
+            Error::IdempotencyMismatch { ref error, .. } => Some(&**error),
+            Error::Timeout => None,
+            Error::NetworkFailure { .. } => None,
             Error::Http(ref err) => Some(err),
             Error::Io(ref err) => Some(err),
             Error::Serialize(ref err) => Some(&**err),
@@ -92,13 +184,23 @@ impl From<RequestError> for Error {
 
 impl From<hyper::Error> for Error {
     fn from(err: hyper::Error) -> Error {
-        Error::Http(HttpError::Stream(err))
+        if err.is_timeout() {
+            Error::Timeout
+        } else if err.is_connect() || err.is_closed() || err.is_incomplete_message() {
+            Error::NetworkFailure { message: err.to_string() }
+        } else {
+            let io_err = std::error::Error::source(&err).and_then(|source| source.downcast_ref());
+            match io_err.and_then(Error::network_failure) {
+                Some(network_err) => network_err,
+                None => Error::Http(HttpError::Stream(err)),
+            }
+        }
     }
 }
 
 impl From<std::io::Error> for Error {
     fn from(err: std::io::Error) -> Error {
-        Error::Io(err)
+        Error::network_failure(&err).unwrap_or(Error::Io(err))
     }
 }
 
@@ -107,6 +209,8 @@ pub enum HttpError {
     /// An error handling HTTP streams.
     Stream(hyper::Error),
     /// The request timed out.
+    ///
+    /// Timeouts are now reported as `Error::Timeout`.
     Timeout,
 }
 
@@ -138,25 +242,57 @@ impl std::error::Error for HttpError {
 }
 
 /// The list of possible values for a RequestError's type.
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum ErrorType {
-    #[serde(skip_deserializing)]
+    /// The response did not include a type.
     Unknown,
 
-    #[serde(rename = "api_error")]
     Api,
-    #[serde(rename = "api_connection_error")]
     Connection,
-    #[serde(rename = "authentication_error")]
     Authentication,
-    #[serde(rename = "card_error")]
     Card,
-    #[serde(rename = "invalid_request_error")]
+    Idempotency,
     InvalidRequest,
-    #[serde(rename = "rate_limit_error")]
     RateLimit,
-    #[serde(rename = "validation_error")]
     Validation,
+
+    /// A type not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
+}
+
+impl ErrorType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ErrorType::Unknown => "unknown_error",
+            ErrorType::Api => "api_error",
+            ErrorType::Connection => "api_connection_error",
+            ErrorType::Authentication => "authentication_error",
+            ErrorType::Card => "card_error",
+            ErrorType::Idempotency => "idempotency_error",
+            ErrorType::InvalidRequest => "invalid_request_error",
+            ErrorType::RateLimit => "rate_limit_error",
+            ErrorType::Validation => "validation_error",
+            ErrorType::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for ErrorType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "api_error" => ErrorType::Api,
+            "api_connection_error" => ErrorType::Connection,
+            "authentication_error" => ErrorType::Authentication,
+            "card_error" => ErrorType::Card,
+            "idempotency_error" => ErrorType::Idempotency,
+            "invalid_request_error" => ErrorType::InvalidRequest,
+            "rate_limit_error" => ErrorType::RateLimit,
+            "validation_error" => ErrorType::Validation,
+            _ => ErrorType::Other(value),
+        }
+    }
 }
 
 impl Default for ErrorType {
@@ -165,9 +301,25 @@ impl Default for ErrorType {
     }
 }
 
+impl AsRef<str> for ErrorType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
 impl std::fmt::Display for ErrorType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", to_snakecase(&format!("{:?}Error", self)))
+        self.as_str().fmt(f)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ErrorType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(ErrorType::from(s))
     }
 }
 
@@ -272,7 +424,7 @@ pub struct RequestError {
     pub http_status: u16,
 
     /// The type of error returned.
-    #[serde(rename = "type")]
+    #[serde(rename = "type", default)]
     pub error_type: ErrorType,
 
     /// A human-readable message providing more details about the error.
@@ -289,6 +441,11 @@ pub struct RequestError {
 
     /// The ID of the failed charge, if applicable.
     pub charge: Option<String>,
+
+    /// For rate limited requests, how long Stripe asked to wait before retrying,
+    /// from the `Retry-After` header of the response.
+    #[serde(skip_deserializing)]
+    pub retry_after: Option<Duration>,
 }
 
 impl std::fmt::Display for RequestError {
@@ -365,3 +522,79 @@ impl std::error::Error for WebhookError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stripe_error(http_status: u16) -> Error {
+        Error::Stripe(RequestError { http_status, ..Default::default() })
+    }
+
+    #[test]
+    fn is_timeout() {
+        assert!(Error::timeout().is_timeout());
+        assert!(Error::from(std::io::Error::from(std::io::ErrorKind::TimedOut)).is_timeout());
+        assert!(stripe_error(504).is_timeout());
+        assert!(!stripe_error(500).is_timeout());
+        assert!(!Error::Unexpected("").is_timeout());
+    }
+
+    #[test]
+    fn is_retryable() {
+        assert!(Error::timeout().is_retryable());
+        let reset = Error::from(std::io::Error::from(std::io::ErrorKind::ConnectionReset));
+        assert!(matches!(reset, Error::NetworkFailure { .. }), "{:?}", reset);
+        assert!(reset.is_retryable());
+        let invalid = Error::from(std::io::Error::from(std::io::ErrorKind::InvalidData));
+        assert!(matches!(invalid, Error::Io(_)), "{:?}", invalid);
+        assert!(!invalid.is_retryable());
+        assert!(stripe_error(429).is_retryable());
+        assert!(stripe_error(500).is_retryable());
+        assert!(stripe_error(503).is_retryable());
+        assert!(!stripe_error(400).is_retryable());
+        assert!(!stripe_error(402).is_retryable());
+        assert!(!Error::Unsupported("").is_retryable());
+        let mismatch = Error::IdempotencyMismatch {
+            error: Box::new(RequestError { http_status: 400, ..Default::default() }),
+            request_id: Some("req_2".into()),
+            original_request_id: Some("req_1".into()),
+        };
+        assert!(!mismatch.is_retryable());
+        assert_eq!(
+            mismatch.to_string(),
+            "idempotency key reused with different parameters: request req_2 reused the key of req_1"
+        );
+    }
+
+    #[test]
+    fn error_types() {
+        let fixtures = [
+            // (type, http status, expected type, retryable, rate limited)
+            ("api_error", 500, ErrorType::Api, true, false),
+            ("authentication_error", 401, ErrorType::Authentication, false, false),
+            ("card_error", 402, ErrorType::Card, false, false),
+            ("idempotency_error", 400, ErrorType::Idempotency, false, false),
+            ("invalid_request_error", 400, ErrorType::InvalidRequest, false, false),
+            ("rate_limit_error", 429, ErrorType::RateLimit, true, true),
+            ("some_future_error", 400, ErrorType::Other("some_future_error".into()), false, false),
+        ];
+        for (type_, http_status, expected, retryable, rate_limited) in fixtures {
+            let body = serde_json::json!({
+                "error": { "type": type_, "message": "Something went wrong." }
+            });
+            let mut response: ErrorResponse = serde_json::from_value(body).unwrap();
+            assert_eq!(response.error.error_type, expected, "{}", type_);
+            response.error.http_status = http_status;
+            let err = Error::from(response.error);
+            assert_eq!(err.is_retryable(), retryable, "{}", type_);
+            assert_eq!(err.is_rate_limited(), rate_limited, "{}", type_);
+        }
+
+        // A rate limited response without an error body is still recognized by its status.
+        let err = stripe_error(429);
+        assert!(err.is_rate_limited() && err.is_retryable());
+        let response: ErrorResponse = serde_json::from_str(r#"{"error": {}}"#).unwrap();
+        assert_eq!(response.error.error_type, ErrorType::Unknown);
+    }
+}
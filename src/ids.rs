@@ -428,6 +428,109 @@ macro_rules! def_id {
             }
         }
 
+        $(
+            impl From<$($variant_type)*> for $enum_name {
+                fn from(id: $($variant_type)*) -> Self {
+                    $enum_name::$variant_name(id)
+                }
+            }
+        )*
+    };
+    (#[other] enum $enum_name:ident { $( $variant_name:ident($($variant_type:tt)*) ),* $(,)* }) => {
+        #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        pub enum $enum_name {
+            $( $variant_name($($variant_type)*), )*
+            /// An id of a type not yet supported by the library.
+            Other(String),
+        }
+
+        impl $enum_name {
+            pub fn as_str(&self) -> &str {
+                match *self {
+                    $( $enum_name::$variant_name(ref id) => id.as_str(), )*
+                    $enum_name::Other(ref id) => id.as_str(),
+                }
+            }
+        }
+
+        impl PartialEq<str> for $enum_name {
+            fn eq(&self, other: &str) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        impl PartialEq<&str> for $enum_name {
+            fn eq(&self, other: &&str) -> bool {
+                self.as_str() == *other
+            }
+        }
+
+        impl PartialEq<String> for $enum_name {
+            fn eq(&self, other: &String) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        impl AsRef<str> for $enum_name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl crate::params::AsCursor for $enum_name {}
+
+        impl std::ops::Deref for $enum_name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl std::fmt::Display for $enum_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                match *self {
+                    $( $enum_name::$variant_name(ref id) => id.fmt(f), )*
+                    $enum_name::Other(ref id) => id.fmt(f),
+                }
+            }
+        }
+
+        impl std::str::FromStr for $enum_name {
+            type Err = ParseIdError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let prefix = match s.find('_') {
+                    Some(i) => &s[0..=i],
+                    None => return Ok($enum_name::Other(s.into())),
+                };
+
+                match prefix {
+                    $(_ if $($variant_type)*::is_valid_prefix(prefix) => {
+                        Ok($enum_name::$variant_name(s.parse()?))
+                    })*
+                    _ => Ok($enum_name::Other(s.into())),
+                }
+            }
+        }
+
+        impl serde::Serialize for $enum_name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where S: serde::ser::Serializer
+            {
+                self.as_str().serialize(serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $enum_name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where D: serde::de::Deserializer<'de>
+            {
+                let s: String = serde::Deserialize::deserialize(deserializer)?;
+                s.parse::<Self>().map_err(::serde::de::Error::custom)
+            }
+        }
+
         $(
             impl From<$($variant_type)*> for $enum_name {
                 fn from(id: $($variant_type)*) -> Self {
@@ -464,6 +567,8 @@ def_id!(ApplicationFeeRefundId, "fr_");
 def_id!(BalanceTransactionId, "txn_");
 def_id!(BankAccountId, "ba_");
 def_id!(BankTokenId, "btok_");
+def_id!(BillingPortalSessionId, "bps_");
+def_id!(BitcoinReceiverId, "btcrcv_");
 def_id!(
     #[optional]
     enum BalanceTransactionSourceId {
@@ -487,10 +592,21 @@ def_id!(CheckoutSessionId, "cs_");
 def_id!(CheckoutSessionItemId: String); // TODO: Figure out what prefix this id has
 def_id!(CouponId: String); // N.B. A coupon id can be user-provided so can be any arbitrary string
 def_id!(CustomerId, "cus_");
+def_id!(DiscountId, "di_");
 def_id!(DisputeId, "dp_" | "du_");
 def_id!(EventId, "evt_");
+def_id!(
+    enum ExternalAccountId {
+        BankAccount(BankAccountId),
+        Card(CardId),
+    }
+);
 def_id!(FileId, "file_");
 def_id!(FileLinkId, "link_");
+def_id!(FinancialConnectionsAccountId, "fca_");
+def_id!(FinancialConnectionsAccountOwnerId, "fcaown_");
+def_id!(FinancialConnectionsAccountOwnershipId, "fcaowns_");
+def_id!(FinancialConnectionsTransactionId, "fctxn_");
 def_id!(InvoiceId, "in_", { _ });
 def_id!(InvoiceItemId, "ii_");
 def_id!(
@@ -503,34 +619,36 @@ def_id!(IssuingAuthorizationId, "iauth_");
 def_id!(IssuingCardId, "ic_");
 def_id!(IssuingCardholderId, "ich_");
 def_id!(IssuingDisputeId, "idp_");
+def_id!(IssuingPhysicalBundleId, "ics_");
 def_id!(IssuingTransactionId, "ipi_");
 def_id!(OrderId, "or_");
 def_id!(OrderReturnId, "orret_");
 def_id!(MandateId: String); // TODO: Figure out what prefix this id has
 def_id!(PaymentIntentId, "pi_");
+def_id!(PaymentLinkId, "plink_");
 def_id!(PaymentMethodId, "pm" | "card_");
+def_id!(PaymentMethodDomainId, "pmd_");
 def_id!(
+    #[other]
     enum PaymentSourceId {
         Account(AccountId),
         AlipayAccount(AlipayAccountId),
         BankAccount(BankAccountId),
+        BitcoinReceiver(BitcoinReceiverId),
         Card(CardId),
         Source(SourceId),
     }
 );
 def_id!(PayoutId, "po_");
-def_id!(
-    enum PayoutDestinationId {
-        BankAccount(BankAccountId),
-        Card(CardId),
-    }
-);
 def_id!(PersonId, "person_");
 def_id!(PlanId: String); // N.B. A plan id can be user-provided so can be any arbitrary string
 def_id!(PriceId: String); // TODO: Figure out what prefix this id has
 def_id!(ProductId: String); // N.B. A product id can be user-provided so can be any arbitrary string
+def_id!(PromotionCodeId, "promo_");
+def_id!(QuoteId, "qt_");
 def_id!(RecipientId: String); // FIXME: This doesn't seem to be documented yet
 def_id!(RefundId, "re_" | "pyr_");
+def_id!(ReportTypeId: String); // N.B. Report type ids are named like "balance.summary.1" and have no prefix
 def_id!(ReviewId, "prv_");
 def_id!(ScheduledQueryRunId, "sqr_");
 def_id!(SetupIntentId, "seti_");
@@ -540,8 +658,12 @@ def_id!(SubscriptionId, "sub_");
 def_id!(SubscriptionItemId, "si_");
 def_id!(SubscriptionLineId, "sli_");
 def_id!(SubscriptionScheduleId, "sub_sched_");
+def_id!(TaxCalculationId, "taxcalc_");
+def_id!(TaxCalculationLineItemId, "tax_li_");
+def_id!(TaxCodeId, "txcd_");
 def_id!(TaxIdId, "txi_");
 def_id!(TaxRateId, "txr_");
+def_id!(TerminalReaderId, "tmr_");
 def_id!(
     enum TokenId {
         Card(CardTokenId),
@@ -555,6 +677,18 @@ def_id!(UsageRecordId, "mbur_");
 def_id!(UsageRecordSummaryId, "sis_");
 def_id!(WebhookEndpointId, "we_");
 
+impl DiscountId {
+    pub(crate) fn none() -> Self {
+        Self("".into())
+    }
+
+    /// A DiscountId may have a `None` representation when received
+    /// from Stripe on API versions before 2020-08-27.
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
 impl InvoiceId {
     pub(crate) fn none() -> Self {
         Self("".into())
@@ -93,14 +93,17 @@ mod resources;
 // See https://github.com/rust-lang/rust/issues/44265
 pub use crate::error::{Error, ErrorCode, ErrorType, RequestError, WebhookError};
 pub use crate::ids::*;
+#[cfg(feature = "blocking")]
+pub use crate::params::ListPages;
 pub use crate::params::{
-    Expandable, Headers, IdOrCreate, List, Metadata, Object, RangeBounds, RangeQuery, Timestamp,
+    to_form_body, AppInfo, Deleted, Expandable, Headers, IdOrCreate, List, Metadata, Object,
+    RangeBounds, RangeQuery, Secret, Timestamp,
 };
 pub use crate::resources::*;
 
 #[cfg(feature = "blocking")]
 mod config {
-    pub(crate) use crate::client::blocking::{err, ok};
+    pub(crate) use crate::client::blocking::{err, map, ok};
     pub type Client = crate::client::blocking::Client;
 
     /// An alias for `Result`.
@@ -122,7 +125,7 @@ mod config {
 
 #[cfg(not(feature = "blocking"))]
 mod config {
-    pub(crate) use crate::client::r#async::{err, ok};
+    pub(crate) use crate::client::r#async::{err, map, ok};
     pub type Client = crate::client::r#async::Client;
     pub type Response<T> = crate::client::r#async::Response<T>;
 }
@@ -1,20 +1,42 @@
-use crate::config::{err, ok, Client, Response};
+use crate::config::{err, map, ok, Client, Response};
 use crate::error::Error;
 use crate::resources::ApiVersion;
 use serde::de::DeserializeOwned;
 use serde_derive::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 #[cfg(not(feature = "blocking"))]
 use futures_util::stream::TryStream;
 
-#[derive(Clone, Default)]
+/// Identifies a plugin or application built on top of this library.
+///
+/// The info is appended to the User-Agent of every request, which helps Stripe support identify the caller.
+#[derive(Clone, Debug, Default)]
 pub struct AppInfo {
     pub name: String,
     pub url: Option<String>,
     pub version: Option<String>,
 }
 
+/// Builds an `AppInfo` from the name and version in the calling crate's `Cargo.toml`.
+///
+/// This has to be a macro, since a function in this crate would report its own package instead.
+///
+/// ```no_run
+/// let client = stripe::Client::new("sk_test_123").with_app_info(stripe::app_info_from_env!());
+/// ```
+#[macro_export]
+macro_rules! app_info_from_env {
+    () => {
+        $crate::AppInfo {
+            name: env!("CARGO_PKG_NAME").to_string(),
+            url: None,
+            version: Some(env!("CARGO_PKG_VERSION").to_string()),
+        }
+    };
+}
+
 #[derive(Clone, Default)]
 pub struct Headers {
     pub client_id: Option<String>,
@@ -42,6 +64,20 @@ pub struct Deleted<T> {
     pub deleted: bool,
 }
 
+// Note: `Deleted<T>` deliberately does not implement `Object`; the response to a delete
+// request only carries the id, so there is no owned object type to report.
+impl<T> Deleted<T> {
+    /// Whether Stripe reported the object as deleted.
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    /// Consumes the response, returning the id of the deleted object.
+    pub fn into_id(self) -> T {
+        self.id
+    }
+}
+
 /// The `Expand` struct is used to serialize `expand` arguments in retrieve and list apis.
 #[doc(hidden)]
 #[derive(Serialize)]
@@ -171,44 +207,176 @@ impl<T: Clone> Clone for List<T> {
     }
 }
 
+impl<T> List<T> {
+    /// Returns the number of elements in this page of the list.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if this page of the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the first element of this page, or `None` if it is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Returns the last element of this page, or `None` if it is empty.
+    pub fn last(&self) -> Option<&T> {
+        self.data.last()
+    }
+}
+
 impl<T: DeserializeOwned + Send + 'static> List<T> {
     /// Prefer `List::next` when possible
     pub fn get_next(client: &Client, url: &str, last_id: &str) -> Response<List<T>> {
-        if url.starts_with("/v1/") {
-            // TODO: Maybe parse the URL?  Perhaps `List` should always parse its `url` field.
-            let mut url = url.trim_start_matches("/v1/").to_string();
-            if url.contains('?') {
-                url.push_str(&format!("&starting_after={}", last_id));
-            } else {
-                url.push_str(&format!("?starting_after={}", last_id));
-            }
-            client.get(&url)
+        let query = url.split_once('?').map(|(_, query)| carried_params(query)).unwrap_or_default();
+        match next_page_url(url, last_id) {
+            Some(url) => map(client.get(&url), move |list: List<T>| list.carrying(&query)),
+            None => err(Error::Unsupported(
+                "URL for fetching additional data uses different API version",
+            )),
+        }
+    }
+
+    /// Fetches the first page of a list (e.g. invoices filtered by status, or a subscription
+    /// item's usage record summaries), remembering `params` in the list's `url`.
+    ///
+    /// Stripe only returns the path of the list, so without this the `limit` and filters would
+    /// not be sent again when `next` or `get_all` fetch the following pages.
+    pub(crate) fn get_with_params<P: serde::Serialize>(
+        client: &Client,
+        path: &str,
+        params: P,
+    ) -> Response<List<T>> {
+        let query = match to_form_body(&params) {
+            Ok(query) => carried_params(&query),
+            Err(e) => return err(e),
+        };
+        map(client.get_query(path, params), move |list: List<T>| list.carrying(&query))
+    }
+
+    /// Appends the carried `query` to the list's `url`, unless Stripe already returned one.
+    fn carrying(mut self, query: &str) -> List<T> {
+        if !query.is_empty() && !self.url.contains('?') {
+            self.url = format!("{}?{}", self.url, query);
+        }
+        self
+    }
+}
+
+/// Builds the path of the page following `last_id`, relative to the API root.
+fn next_page_url(url: &str, last_id: &str) -> Option<String> {
+    if url.starts_with("/v1/") {
+        let mut url = url.trim_start_matches("/v1/").to_string();
+        if url.contains('?') {
+            url.push_str(&format!("&starting_after={}", last_id));
         } else {
-            err(Error::Unsupported("URL for fetching additional data uses different API version"))
+            url.push_str(&format!("?starting_after={}", last_id));
         }
+        Some(url)
+    } else {
+        None
     }
 }
 
+/// Encodes request params the same way the client does for form bodies and query strings.
+///
+/// Nested structs and maps become bracketed keys (`address[city]=...`) and sequences become
+/// indexed fields (`items[0][price]=...`), which is the form Stripe expects.
+/// This is useful to check what a params struct will actually send.
+pub fn to_form_body<P: serde::Serialize>(params: &P) -> Result<String, Error> {
+    serde_qs::to_string(params).map_err(Error::serialize)
+}
+
+/// Drops the cursor from a list's query string, since every page brings its own.
+fn carried_params(query: &str) -> String {
+    query
+        .split('&')
+        .filter(|pair| {
+            !pair.is_empty()
+                && !pair.starts_with("starting_after=")
+                && !pair.starts_with("ending_before=")
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
 impl<T: Paginate + DeserializeOwned + Send + 'static> List<T> {
     /// Repeatedly queries Stripe for more data until all elements in list are fetched, using
     /// Stripe's default page size.
     #[cfg(feature = "blocking")]
     pub fn get_all(self, client: &Client) -> Response<Vec<T>> {
         let mut data = Vec::new();
-        let mut next = self;
-        loop {
-            if next.has_more {
-                let resp = next.next(&client)?;
-                data.extend(next.data);
-                next = resp;
-            } else {
-                data.extend(next.data);
+        for page in self.get_all_pages(client) {
+            data.extend(page?.data);
+        }
+        Ok(data)
+    }
+
+    /// Like `List::get_all`, but stops paginating once `max_items` values have been collected,
+    /// even if Stripe reports that there are more.
+    #[cfg(feature = "blocking")]
+    pub fn get_all_max_items(self, client: &Client, max_items: usize) -> Response<Vec<T>> {
+        let mut data = Vec::new();
+        for page in self.get_all_pages(client) {
+            data.extend(page?.data);
+            if data.len() >= max_items {
                 break;
             }
         }
+        data.truncate(max_items);
+        Ok(data)
+    }
+
+    /// Like `List::get_all`, but fails with a timeout error (see `Error::is_timeout`) instead of
+    /// fetching another page once `timeout` has elapsed since the call.
+    #[cfg(feature = "blocking")]
+    pub fn get_all_with_timeout(self, client: &Client, timeout: Duration) -> Response<Vec<T>> {
+        let deadline = Instant::now() + timeout;
+        let mut data = Vec::new();
+        let mut pages = self.get_all_pages(client);
+        while let Some(page) = pages.next() {
+            data.extend(page?.data);
+            if pages.following.is_some() && Instant::now() >= deadline {
+                return Err(Error::timeout());
+            }
+        }
         Ok(data)
     }
 
+    /// Like `List::get_all`, but spaces out page requests so that at most `max_requests_per_second`
+    /// are sent, to stay within Stripe's rate limits when draining a large list.
+    ///
+    /// A page request rejected with `429 Too Many Requests` is retried a few times, after the
+    /// delay given by Stripe's `Retry-After` header.
+    #[cfg(feature = "blocking")]
+    pub fn get_all_throttled(
+        self,
+        client: &Client,
+        max_requests_per_second: u32,
+    ) -> Response<Vec<T>> {
+        let mut pages = self.get_all_pages(client);
+        pages.throttle = Some(Throttle::new(max_requests_per_second));
+        let mut data = Vec::new();
+        for page in pages {
+            data.extend(page?.data);
+        }
+        Ok(data)
+    }
+
+    /// Returns an iterator over this page and each following page of the list, for callers that
+    /// want to handle whole pages at once (e.g. one database transaction per page).
+    ///
+    /// Pages are fetched lazily, with the page size and filters of the original request.
+    /// Iteration ends after the last page, or after the first error.
+    #[cfg(feature = "blocking")]
+    pub fn get_all_pages(self, client: &Client) -> ListPages<'_, T> {
+        ListPages { client, first: Some(self), following: None, throttle: None }
+    }
+
     /// Get all values in this list, consuming self and paginating until all values are fetched.
     ///
     /// This function repeatedly queries Stripe for more data until all elements in list are fetched, using
@@ -227,38 +395,143 @@ impl<T: Paginate + DeserializeOwned + Send + 'static> List<T> {
     /// ```
     #[cfg(not(feature = "blocking"))]
     pub fn get_all(self, client: &Client) -> impl TryStream<Ok = T, Error = Error> {
-        // We are going to be popping items off the end of the list, so we need to reverse it.
-        let mut init_list = self;
-        init_list.data.reverse();
+        self.paginate(client, usize::MAX, None, None)
+    }
 
-        futures_util::stream::unfold(Some((init_list, client.clone())), |state| async move {
-            let (mut list, client) = state?; // if none, we sent the last item in the list last iteration
-            let val = list.data.pop()?; // the initial list was empty, so we're done.
+    /// Like `List::get_all`, but ends the stream once `max_items` values have been yielded,
+    /// even if Stripe reports that there are more.
+    #[cfg(not(feature = "blocking"))]
+    pub fn get_all_max_items(
+        self,
+        client: &Client,
+        max_items: usize,
+    ) -> impl TryStream<Ok = T, Error = Error> {
+        self.paginate(client, max_items, None, None)
+    }
 
-            if !list.data.is_empty() {
-                return Some((Ok(val), Some((list, client)))); // some value on this page that isn't the last value on the page
-            }
+    /// Like `List::get_all`, but the stream ends with a timeout error (see `Error::is_timeout`)
+    /// if it is still paginating once `timeout` has elapsed since the call.
+    #[cfg(not(feature = "blocking"))]
+    pub fn get_all_with_timeout(
+        self,
+        client: &Client,
+        timeout: Duration,
+    ) -> impl TryStream<Ok = T, Error = Error> {
+        self.paginate(client, usize::MAX, Some(Instant::now() + timeout), None)
+    }
 
-            if !list.has_more {
-                return Some((Ok(val), None)); // final value of the stream, no errors
-            }
+    /// Like `List::get_all`, but spaces out page requests so that at most `max_requests_per_second`
+    /// are sent, to stay within Stripe's rate limits when draining a large list.
+    ///
+    /// A page request rejected with `429 Too Many Requests` is retried a few times, after the
+    /// delay given by Stripe's `Retry-After` header. Waiting uses the tokio timer, so no worker
+    /// thread is blocked.
+    #[cfg(not(feature = "blocking"))]
+    pub fn get_all_throttled(
+        self,
+        client: &Client,
+        max_requests_per_second: u32,
+    ) -> impl TryStream<Ok = T, Error = Error> {
+        let throttle = Throttle::new(max_requests_per_second);
+        self.paginate(client, usize::MAX, None, Some(throttle))
+    }
 
-            // We're on the last value of this page, but there's more. We need to fetch the next page.
-            let last_id = val.cursor();
-            let resp = List::get_next(&client, &list.url, last_id.as_ref());
+    /// Returns a stream of this page and each following page of the list, for callers that
+    /// want to handle whole pages at once (e.g. one database transaction per page).
+    ///
+    /// Pages are fetched lazily, with the page size and filters of the original request.
+    /// The stream ends after the last page, or after the first error.
+    ///
+    /// ```ignore
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut pages = list.get_all_pages(&client).boxed();
+    /// while let Some(page) = pages.try_next().await? {
+    ///     insert_rows(&page.data).await?;
+    /// }
+    /// ```
+    #[cfg(not(feature = "blocking"))]
+    pub fn get_all_pages(self, client: &Client) -> impl TryStream<Ok = List<T>, Error = Error> {
+        self.pages(client, None, None)
+    }
 
-            match resp.await {
-                Ok(mut next_list) => {
-                    next_list.data.reverse();
+    #[cfg(not(feature = "blocking"))]
+    fn paginate(
+        self,
+        client: &Client,
+        max_items: usize,
+        deadline: Option<Instant>,
+        throttle: Option<Throttle>,
+    ) -> impl TryStream<Ok = T, Error = Error> {
+        use futures_util::stream::{self, StreamExt, TryStreamExt};
+
+        self.pages(client, deadline, throttle)
+            .map_ok(|page| stream::iter(page.data.into_iter().map(Ok)))
+            .try_flatten()
+            .take(max_items)
+    }
 
-                    // Yield last value of this page, the next page (and client) becomes the state
-                    Some((Ok(val), Some((next_list, client))))
+    #[cfg(not(feature = "blocking"))]
+    fn pages(
+        self,
+        client: &Client,
+        deadline: Option<Instant>,
+        throttle: Option<Throttle>,
+    ) -> impl TryStream<Ok = List<T>, Error = Error> {
+        let init_state = Some((Some(self), None, client.clone(), throttle));
+        futures_util::stream::unfold(init_state, move |state| async move {
+            // if none, the last page or an error was sent
+            let (first, following, client, mut throttle) = state?;
+            let page = match first {
+                Some(page) => page,
+                None => {
+                    let (url, last_id): (String, String) = following?;
+                    let mut retries = 0;
+                    let resp = loop {
+                        if let Some(throttle) = &mut throttle {
+                            tokio::time::sleep(throttle.wait()).await;
+                        }
+                        let resp = List::get_next(&client, &url, &last_id);
+                        let resp = match deadline {
+                            Some(deadline) if Instant::now() >= deadline => Err(Error::timeout()),
+                            Some(deadline) => {
+                                let deadline = tokio::time::Instant::from_std(deadline);
+                                tokio::time::timeout_at(deadline, resp)
+                                    .await
+                                    .unwrap_or_else(|_| Err(Error::timeout()))
+                            }
+                            None => resp.await,
+                        };
+                        let backoff = match (&resp, &throttle) {
+                            (Err(err), Some(throttle)) => throttle.backoff(err, retries),
+                            _ => None,
+                        };
+                        match backoff {
+                            Some(delay) => tokio::time::sleep(delay).await,
+                            None => break resp,
+                        }
+                        retries += 1;
+                    };
+                    match resp {
+                        Ok(page) => page,
+                        Err(e) => return Some((Err(e), None)),
+                    }
                 }
-                Err(e) => Some((Err(e), None)), // we ran into an error. the last value of the stream will be the error.
-            }
+            };
+            let following = page.following();
+            Some((Ok(page), Some((None, following, client, throttle))))
         })
     }
 
+    /// The url and cursor of the page after this one, if Stripe reports there is one.
+    fn following(&self) -> Option<(String, String)> {
+        if !self.has_more {
+            return None;
+        }
+        let last_id = self.data.last()?.cursor();
+        Some((self.url.clone(), last_id.as_ref().to_string()))
+    }
+
     /// Fetch an additional page of data from stripe.
     pub fn next(&self, client: &Client) -> Response<List<T>> {
         if let Some(last_id) = self.data.last().map(|d| d.cursor()) {
@@ -274,9 +547,143 @@ impl<T: Paginate + DeserializeOwned + Send + 'static> List<T> {
     }
 }
 
+/// An iterator over the pages of a list, returned by `List::get_all_pages`.
+#[cfg(feature = "blocking")]
+pub struct ListPages<'a, T> {
+    client: &'a Client,
+    first: Option<List<T>>,
+    following: Option<(String, String)>,
+    throttle: Option<Throttle>,
+}
+
+#[cfg(feature = "blocking")]
+impl<'a, T: Paginate + DeserializeOwned + Send + 'static> Iterator for ListPages<'a, T> {
+    type Item = Response<List<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let page = match self.first.take() {
+            Some(page) => page,
+            None => {
+                let (url, last_id) = self.following.take()?;
+                match self.fetch(&url, &last_id) {
+                    Ok(page) => page,
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+        };
+        self.following = page.following();
+        Some(Ok(page))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<'a, T: Paginate + DeserializeOwned + Send + 'static> ListPages<'a, T> {
+    fn fetch(&mut self, url: &str, last_id: &str) -> Response<List<T>> {
+        let mut retries = 0;
+        loop {
+            if let Some(throttle) = &mut self.throttle {
+                std::thread::sleep(throttle.wait());
+            }
+            let resp = List::get_next(self.client, url, last_id);
+            let backoff = match (&resp, &self.throttle) {
+                (Err(err), Some(throttle)) => throttle.backoff(err, retries),
+                _ => None,
+            };
+            match backoff {
+                Some(delay) => std::thread::sleep(delay),
+                None => return resp,
+            }
+            retries += 1;
+        }
+    }
+}
+
+/// The number of times a throttled page request is retried after Stripe rate limits it.
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+
+/// Spaces out the page requests of `List::get_all_throttled`.
+struct Throttle {
+    interval: Duration,
+    last_request: Instant,
+}
+
+impl Throttle {
+    fn new(max_requests_per_second: u32) -> Throttle {
+        // The page the list started from was just fetched, so the first request waits too.
+        let interval = Duration::from_secs(1) / max_requests_per_second.max(1);
+        Throttle { interval, last_request: Instant::now() }
+    }
+
+    /// How long to wait before sending the next request, which is then counted as sent.
+    fn wait(&mut self) -> Duration {
+        let now = Instant::now();
+        let next = self.last_request + self.interval;
+        self.last_request = next.max(now);
+        next.saturating_duration_since(now)
+    }
+
+    /// How long to wait before retrying a request which failed with `err`, if it is retried at all.
+    ///
+    /// Only rate limited requests are retried, after the delay Stripe asks for in `Retry-After`,
+    /// or else after an exponentially growing multiple of the interval.
+    fn backoff(&self, err: &Error, retries: u32) -> Option<Duration> {
+        match err {
+            Error::Stripe(req) if err.is_rate_limited() && retries < MAX_RATE_LIMIT_RETRIES => {
+                Some(req.retry_after.unwrap_or(self.interval * 2u32.pow(retries + 1)))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Key-value pairs attached to a Stripe object.
+///
+/// Stripe accepts up to 50 keys, with key names up to 40 characters long and values up to 500 characters long.
+/// In a request body the map is encoded as `metadata[key]=value`, nested under its parent's brackets
+/// (e.g. `items[0][metadata][key]=value`), with the key percent-encoded.
 pub type Metadata = HashMap<String, String>;
 pub type Timestamp = i64;
 
+/// A sensitive value, such as a client secret or a card number, which is left out of `Debug`
+/// output so that printing a response doesn't put it in the logs.
+///
+/// It is serialized and deserialized as the plain string; use `Secret::as_str` to read it.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: impl Into<String>) -> Self {
+        Secret(value.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("[redacted]")
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Secret(value)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(value: &str) -> Self {
+        Secret(value.into())
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub struct RangeBounds<T> {
@@ -334,6 +741,15 @@ impl<T> RangeQuery<T> {
         bounds.lte = Some(value);
         RangeQuery::Bounds(bounds)
     }
+
+    /// Filter results to be between two values, inclusive of both
+    pub fn between(start: T, end: T) -> RangeQuery<T> {
+        RangeQuery::Bounds(RangeBounds {
+            gte: Some(start),
+            lte: Some(end),
+            ..RangeBounds::default()
+        })
+    }
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -367,6 +783,39 @@ pub fn to_snakecase(camel: &str) -> String {
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn carried_params() {
+        use super::carried_params;
+
+        assert_eq!(carried_params(""), "");
+        assert_eq!(carried_params("limit=3"), "limit=3");
+        assert_eq!(
+            carried_params("expand%5B0%5D=data.invoice&limit=3&starting_after=sis_123"),
+            "expand%5B0%5D=data.invoice&limit=3"
+        );
+        assert_eq!(carried_params("ending_before=sis_456&limit=3"), "limit=3");
+    }
+
+    #[test]
+    fn next_page_url() {
+        use super::next_page_url;
+
+        let first = "/v1/subscription_items/si_123/usage_record_summaries";
+        assert_eq!(
+            next_page_url(first, "sis_1").unwrap(),
+            "subscription_items/si_123/usage_record_summaries?starting_after=sis_1"
+        );
+
+        // Pages after the first carry the params of the original request, with a single cursor.
+        let first = "/v1/subscription_items/si_123/usage_record_summaries?limit=2";
+        assert_eq!(
+            next_page_url(first, "sis_2").unwrap(),
+            "subscription_items/si_123/usage_record_summaries?limit=2&starting_after=sis_2"
+        );
+
+        assert!(next_page_url("/v2/things", "thing_1").is_none());
+    }
+
     #[test]
     fn to_snakecase() {
         use super::to_snakecase;
@@ -377,4 +826,106 @@ mod tests {
         assert_eq!(to_snakecase("UPPER").as_str(), "upper");
         assert_eq!(to_snakecase("lower").as_str(), "lower");
     }
+
+    #[test]
+    fn app_info_from_env() {
+        let info = crate::app_info_from_env!();
+        assert_eq!(info.name, "stripe-rust");
+        assert_eq!(info.version.as_deref(), Some(env!("CARGO_PKG_VERSION")));
+        assert_eq!(info.url, None);
+    }
+
+    #[test]
+    fn deleted_into_id() {
+        use super::Deleted;
+        use crate::ids::CustomerId;
+
+        let deleted: Deleted<CustomerId> =
+            serde_json::from_str(r#"{"id": "cus_123", "object": "customer", "deleted": true}"#)
+                .unwrap();
+        assert!(deleted.is_deleted());
+        assert_eq!(deleted.into_id(), "cus_123");
+    }
+
+    fn three_cards(has_more: bool) -> super::List<crate::resources::Card> {
+        serde_json::from_value(serde_json::json!({
+            "object": "list",
+            "data": [{ "id": "card_1" }, { "id": "card_2" }, { "id": "card_3" }],
+            "has_more": has_more,
+            "url": "/v1/customers/cus_123/sources"
+        }))
+        .unwrap()
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn get_all_bounded() {
+        use std::time::Duration;
+
+        // Neither call needs another page, so no request is sent.
+        let client = crate::Client::new("sk_test_123");
+        let cards = three_cards(true).get_all_max_items(&client, 2).unwrap();
+        assert_eq!(cards.iter().map(|c| c.id.as_str()).collect::<Vec<_>>(), ["card_1", "card_2"]);
+        let err = three_cards(true).get_all_with_timeout(&client, Duration::from_secs(0));
+        assert!(err.unwrap_err().is_timeout());
+    }
+
+    #[cfg(not(feature = "blocking"))]
+    #[test]
+    fn get_all_bounded() {
+        use futures_util::TryStreamExt;
+        use std::time::Duration;
+
+        // Neither stream needs another page, so no request is sent.
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let client = crate::Client::new("sk_test_123");
+        let cards: Vec<_> = runtime
+            .block_on(three_cards(true).get_all_max_items(&client, 2).try_collect())
+            .unwrap();
+        assert_eq!(cards.iter().map(|c| c.id.as_str()).collect::<Vec<_>>(), ["card_1", "card_2"]);
+        let all: Vec<_> =
+            runtime.block_on(three_cards(false).get_all(&client).try_collect()).unwrap();
+        assert_eq!(all.len(), 3);
+        let err = runtime
+            .block_on(
+                three_cards(true)
+                    .get_all_with_timeout(&client, Duration::from_secs(0))
+                    .try_collect::<Vec<_>>(),
+            )
+            .unwrap_err();
+        assert!(err.is_timeout());
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn get_all_pages() {
+        // The last page, and a page with no cursor to continue from, need no request.
+        let client = crate::Client::new("sk_test_123");
+        let pages: Vec<_> = three_cards(false).get_all_pages(&client).collect();
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].as_ref().unwrap().data.len(), 3);
+
+        let mut empty = three_cards(true);
+        empty.data.clear();
+        assert_eq!(empty.get_all_pages(&client).count(), 1);
+    }
+
+    #[cfg(not(feature = "blocking"))]
+    #[test]
+    fn get_all_pages() {
+        use futures_util::TryStreamExt;
+
+        // The last page, and a page with no cursor to continue from, need no request.
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let client = crate::Client::new("sk_test_123");
+        let pages: Vec<_> =
+            runtime.block_on(three_cards(false).get_all_pages(&client).try_collect()).unwrap();
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].data.len(), 3);
+
+        let mut empty = three_cards(true);
+        empty.data.clear();
+        let pages: Vec<_> = runtime.block_on(empty.get_all_pages(&client).try_collect()).unwrap();
+        assert_eq!(pages.len(), 1);
+    }
 }
@@ -13,8 +13,10 @@ mod charge_ext;
 mod customer;
 mod customer_ext;
 mod dispute;
+mod external_account;
 mod file;
 mod file_link;
+mod funding_instructions;
 mod mandate;
 mod payment_intent;
 mod payment_source;
@@ -25,6 +27,9 @@ mod product;
 mod refund;
 mod reserve_transaction;
 mod setup_intent;
+mod setup_intent_ext;
+mod tax_calculation;
+mod tax_code;
 mod tax_deducted_at_source;
 mod token;
 mod token_ext;
@@ -36,8 +41,10 @@ pub use self::charge_ext::*;
 pub use self::customer::*;
 pub use self::customer_ext::*;
 pub use self::dispute::*;
+pub use self::external_account::*;
 pub use self::file::*;
 pub use self::file_link::*;
+pub use self::funding_instructions::*;
 pub use self::mandate::*;
 pub use self::payment_intent::*;
 pub use self::payment_source::*;
@@ -48,6 +55,9 @@ pub use self::product::*;
 pub use self::refund::*;
 pub use self::reserve_transaction::*;
 pub use self::setup_intent::*;
+pub use self::setup_intent_ext::*;
+pub use self::tax_calculation::*;
+pub use self::tax_code::*;
 pub use self::tax_deducted_at_source::*;
 pub use self::token::*;
 pub use self::token_ext::*;
@@ -56,16 +66,20 @@ pub use self::token_ext::*;
 mod alipay_account;
 mod bank_account;
 mod bank_account_ext;
+mod bitcoin_receiver;
 mod card;
 mod payment_method;
+mod payment_method_domain;
 mod payment_method_ext;
 mod source;
 mod source_ext;
 pub use self::alipay_account::*;
 pub use self::bank_account::*;
 pub use self::bank_account_ext::*;
+pub use self::bitcoin_receiver::*;
 pub use self::card::*;
 pub use self::payment_method::*;
+pub use self::payment_method_domain::*;
 pub use self::payment_method_ext::*;
 pub use self::source::*;
 pub use self::source_ext::*;
@@ -80,14 +94,26 @@ pub use self::event::*;
 #[cfg(feature = "checkout")]
 mod checkout_session;
 #[cfg(feature = "checkout")]
+mod checkout_session_ext;
+#[cfg(feature = "checkout")]
 mod item;
 #[cfg(feature = "checkout")]
+mod payment_link;
+#[cfg(feature = "checkout")]
 pub use self::checkout_session::*;
 #[cfg(feature = "checkout")]
+pub use self::checkout_session_ext::*;
+#[cfg(feature = "checkout")]
 pub use self::item::*;
+#[cfg(feature = "checkout")]
+pub use self::payment_link::*;
 
 // Billing
 #[cfg(feature = "billing")]
+mod billing_portal_session;
+#[cfg(feature = "billing")]
+mod billing_portal_session_ext;
+#[cfg(feature = "billing")]
 mod coupon;
 #[cfg(feature = "billing")]
 mod discount;
@@ -106,6 +132,10 @@ mod plan;
 #[cfg(feature = "billing")]
 mod price;
 #[cfg(feature = "billing")]
+mod promotion_code;
+#[cfg(feature = "billing")]
+mod quote;
+#[cfg(feature = "billing")]
 mod subscription;
 #[cfg(feature = "billing")]
 mod subscription_ext;
@@ -120,6 +150,10 @@ mod tax_id;
 #[cfg(feature = "billing")]
 mod tax_rate;
 #[cfg(feature = "billing")]
+pub use self::billing_portal_session::*;
+#[cfg(feature = "billing")]
+pub use self::billing_portal_session_ext::*;
+#[cfg(feature = "billing")]
 pub use self::coupon::*;
 #[cfg(feature = "billing")]
 pub use self::discount::*;
@@ -138,6 +172,10 @@ pub use self::plan::*;
 #[cfg(feature = "billing")]
 pub use self::price::*;
 #[cfg(feature = "billing")]
+pub use self::promotion_code::*;
+#[cfg(feature = "billing")]
+pub use self::quote::*;
+#[cfg(feature = "billing")]
 pub use self::subscription::*;
 #[cfg(feature = "billing")]
 pub use self::subscription_ext::*;
@@ -156,6 +194,8 @@ pub use self::tax_rate::*;
 #[cfg(feature = "connect")]
 mod account;
 #[cfg(feature = "connect")]
+mod account_ext;
+#[cfg(feature = "connect")]
 mod application;
 #[cfg(feature = "connect")]
 mod application_fee;
@@ -194,6 +234,12 @@ pub use self::transfer::*;
 #[cfg(feature = "connect")]
 pub use self::transfer_reversal::*;
 
+// Financial Connections
+#[cfg(feature = "financial-connections")]
+mod financial_connections_account;
+#[cfg(feature = "financial-connections")]
+pub use self::financial_connections_account::*;
+
 // Fraud
 #[cfg(feature = "fraud")]
 mod review;
@@ -222,6 +268,8 @@ mod issuing_dispute_ext;
 #[cfg(feature = "issuing")]
 mod issuing_merchant_data;
 #[cfg(feature = "issuing")]
+mod issuing_physical_bundle;
+#[cfg(feature = "issuing")]
 mod issuing_transaction;
 #[cfg(feature = "issuing")]
 mod issuing_transaction_ext;
@@ -242,6 +290,8 @@ pub use self::issuing_dispute_ext::*;
 #[cfg(feature = "issuing")]
 pub use self::issuing_merchant_data::*;
 #[cfg(feature = "issuing")]
+pub use self::issuing_physical_bundle::*;
+#[cfg(feature = "issuing")]
 pub use self::issuing_transaction::*;
 #[cfg(feature = "issuing")]
 pub use self::issuing_transaction_ext::*;
@@ -268,11 +318,27 @@ pub use self::order_return::*;
 #[cfg(feature = "orders")]
 pub use self::sku::*;
 
+// Reporting
+#[cfg(feature = "reporting")]
+mod reporting_report_type;
+#[cfg(feature = "reporting")]
+pub use self::reporting_report_type::*;
+
 #[cfg(feature = "sigma")]
 mod scheduled_query_run;
 #[cfg(feature = "sigma")]
 pub use self::scheduled_query_run::*;
 
+// Terminal
+#[cfg(feature = "terminal")]
+mod terminal_reader;
+#[cfg(feature = "terminal")]
+pub use self::terminal_reader::*;
+#[cfg(feature = "terminal")]
+mod terminal_reader_ext;
+#[cfg(feature = "terminal")]
+pub use self::terminal_reader_ext::*;
+
 // Not-yet-implemented feature flags
 #[cfg(feature = "webhook-endpoints")]
 mod webhook_endpoint;
@@ -6,15 +6,17 @@ use crate::config::{Client, Response};
 use crate::ids::AccountId;
 use crate::params::{Deleted, Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
-    Address, BankAccount, BusinessType, Card, Currency, DelayDays, Dob, File, Person,
+    Address, BusinessType, Currency, DelayDays, Dob, ExternalAccount, File, Person,
     PersonVerificationParams, VerificationDocumentParams, Weekday,
 };
 use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// The resource representing a Stripe "Account".
 ///
 /// For more details see [https://stripe.com/docs/api/accounts/object](https://stripe.com/docs/api/accounts/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Account {
     /// Unique identifier for the object.
     pub id: AccountId,
@@ -107,7 +109,7 @@ impl Account {
     ///
     /// If you’re not a platform, the list is empty.
     pub fn list(client: &Client, params: ListAccounts<'_>) -> Response<List<Account>> {
-        client.get_query("/accounts", &params)
+        List::get_with_params(client, "/accounts", &params)
     }
 
     /// With [Connect](https://stripe.com/docs/connect), you can create Stripe accounts for your users.
@@ -403,6 +405,10 @@ pub struct TosAcceptance {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ip: Option<String>,
 
+    /// The user's service agreement type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_agreement: Option<String>,
+
     /// The user agent of the browser from which the Stripe Services Agreement was accepted by the account representative.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_agent: Option<String>,
@@ -544,6 +550,12 @@ pub struct CreateAccount<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_type: Option<BusinessType>,
 
+    /// Each key of the map requests a capability for the account.
+    ///
+    /// A capability is inactive until its requirements have been provided and verified by Stripe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<HashMap<RequestedCapability, CapabilityRequest>>,
+
     /// Information about the company or business.
     ///
     /// This field is null unless `business_type` is set to `company`, `government_entity`, or `non_profit`.
@@ -624,6 +636,7 @@ impl<'a> CreateAccount<'a> {
             account_token: Default::default(),
             business_profile: Default::default(),
             business_type: Default::default(),
+            capabilities: Default::default(),
             company: Default::default(),
             country: Default::default(),
             default_currency: Default::default(),
@@ -698,6 +711,12 @@ pub struct UpdateAccount<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub business_type: Option<BusinessType>,
 
+    /// Each key of the map requests a capability for the account.
+    ///
+    /// A capability is inactive until its requirements have been provided and verified by Stripe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<HashMap<RequestedCapability, CapabilityRequest>>,
+
     /// Information about the company or business.
     ///
     /// This field is null unless `business_type` is set to `company`, `government_entity`, or `non_profit`.
@@ -765,6 +784,7 @@ impl<'a> UpdateAccount<'a> {
             account_token: Default::default(),
             business_profile: Default::default(),
             business_type: Default::default(),
+            capabilities: Default::default(),
             company: Default::default(),
             default_currency: Default::default(),
             email: Default::default(),
@@ -779,7 +799,7 @@ impl<'a> UpdateAccount<'a> {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct AcceptTos {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub date: Option<Timestamp>,
@@ -787,15 +807,21 @@ pub struct AcceptTos {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ip: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_agreement: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_agent: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct AccountSettingsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub branding: Option<BrandingSettingsParams>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_issuing: Option<CardIssuingSettingsParams>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub card_payments: Option<CardPaymentsSettingsParams>,
 
@@ -804,6 +830,18 @@ pub struct AccountSettingsParams {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payouts: Option<PayoutSettingsParams>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub treasury: Option<TreasurySettingsParams>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CapabilityRequest {
+    /// Passing true requests the capability for the account, if it is not already requested.
+    ///
+    /// A requested capability may not immediately become active.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested: Option<bool>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -926,6 +964,13 @@ pub struct BrandingSettingsParams {
     pub secondary_color: Option<String>,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CardIssuingSettingsParams {
+    /// Details on the account's acceptance of the [Stripe Issuing Terms and Disclosures](https://stripe.com/docs/issuing/connect/tos_acceptance).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tos_acceptance: Option<AcceptTos>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CardPaymentsSettingsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -965,6 +1010,13 @@ pub struct PayoutSettingsParams {
     pub statement_descriptor: Option<String>,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasurySettingsParams {
+    /// Details on the account's acceptance of the Stripe Treasury Services Agreement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tos_acceptance: Option<AcceptTos>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeclineChargeOnParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -989,13 +1041,6 @@ pub struct TransferScheduleParams {
     pub weekly_anchor: Option<Weekday>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(tag = "object", rename_all = "snake_case")]
-pub enum ExternalAccount {
-    BankAccount(BankAccount),
-    Card(Card),
-}
-
 /// An enum representing the possible values of an `AccountRequirementsError`'s `code` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -1344,7 +1389,7 @@ impl std::fmt::Display for CompanyStructure {
 }
 
 /// An enum representing the possible values of an `CreateAccount`'s `requested_capabilities` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum RequestedCapability {
     AuBecsDebitPayments,
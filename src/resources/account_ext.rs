@@ -0,0 +1,11 @@
+use crate::config::{Client, Response};
+use crate::resources::Account;
+
+impl Account {
+    /// Retrieves the details of the account associated with the client's secret key.
+    ///
+    /// For more details see https://stripe.com/docs/api/accounts/retrieve.
+    pub fn retrieve_self(client: &Client) -> Response<Account> {
+        client.get("/account")
+    }
+}
@@ -9,6 +9,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "AlipayAccount".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct AlipayAccount {
     /// Unique identifier for the object.
     pub id: AlipayAccountId,
@@ -8,6 +8,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "Application".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Application {
     /// Unique identifier for the object.
     pub id: ApplicationId,
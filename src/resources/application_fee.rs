@@ -14,6 +14,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/application_fees/object](https://stripe.com/docs/api/application_fees/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct ApplicationFee {
     /// Unique identifier for the object.
     pub id: ApplicationFeeId,
@@ -71,7 +72,7 @@ impl ApplicationFee {
         client: &Client,
         params: ListApplicationFees<'_>,
     ) -> Response<List<ApplicationFee>> {
-        client.get_query("/application_fees", &params)
+        List::get_with_params(client, "/application_fees", &params)
     }
 
     /// Retrieves the details of an application fee that your account has collected.
@@ -8,10 +8,12 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "Balance".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Balance {
     /// Funds that are available to be transferred or paid out, whether automatically by Stripe or explicitly via the [Transfers API](https://stripe.com/docs/api#transfers) or [Payouts API](https://stripe.com/docs/api#payouts).
     ///
     /// The available balance for each currency and payment type can be found in the `source_types` property.
+    #[serde(default)]
     pub available: Vec<BalanceAmount>,
 
     /// Funds held due to negative balances on connected Custom accounts.
@@ -29,6 +31,7 @@ pub struct Balance {
     /// Funds that are not yet available in the balance, due to the 7-day rolling pay cycle.
     ///
     /// The pending balance for each currency, and for each payment type, can be found in the `source_types` property.
+    #[serde(default)]
     pub pending: Vec<BalanceAmount>,
 }
 
@@ -72,5 +75,6 @@ pub struct BalanceAmountBySourceType {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct BalanceDetail {
     /// Funds that are available for use.
+    #[serde(default)]
     pub available: Vec<BalanceAmount>,
 }
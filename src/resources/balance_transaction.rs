@@ -17,6 +17,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/balance_transactions/object](https://stripe.com/docs/api/balance_transactions/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct BalanceTransaction {
     /// Unique identifier for the object.
     pub id: BalanceTransactionId,
@@ -57,13 +58,14 @@ pub struct BalanceTransaction {
     pub fee: i64,
 
     /// Detailed breakdown of fees (in %s) paid for this transaction.
+    #[serde(default)]
     pub fee_details: Vec<Fee>,
 
     /// Net amount of the transaction, in %s.
     pub net: i64,
 
     /// [Learn more](https://stripe.com/docs/reports/reporting-categories) about how reporting categories can help you understand balance transactions from an accounting perspective.
-    pub reporting_category: String,
+    pub reporting_category: BalanceTransactionReportingCategory,
 
     /// The Stripe object to which this transaction is related.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -90,7 +92,7 @@ impl BalanceTransaction {
         client: &Client,
         params: ListBalanceTransactions<'_>,
     ) -> Response<List<BalanceTransaction>> {
-        client.get_query("/balance_transactions", &params)
+        List::get_with_params(client, "/balance_transactions", &params)
     }
 
     /// Retrieves the balance transaction with the given ID.
@@ -236,9 +238,204 @@ pub enum BalanceTransactionSource {
     TransferReversal(TransferReversal),
 }
 
+/// An enum representing the possible values of an `BalanceTransaction`'s `reporting_category` field.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum BalanceTransactionReportingCategory {
+    Advance,
+    AdvanceFunding,
+    AnticipationRepayment,
+    Charge,
+    ChargeFailure,
+    ClimateOrderPurchase,
+    ClimateOrderRefund,
+    ConnectCollectionTransfer,
+    ConnectReservedFunds,
+    Contribution,
+    Dispute,
+    DisputeReversal,
+    Fee,
+    FinancingPaydown,
+    FinancingPaydownReversal,
+    FinancingPayout,
+    FinancingPayoutReversal,
+    IssuingAuthorizationHold,
+    IssuingAuthorizationRelease,
+    IssuingDispute,
+    IssuingTransaction,
+    NetworkCost,
+    OtherAdjustment,
+    PartialCaptureReversal,
+    Payout,
+    PayoutReversal,
+    PlatformEarning,
+    PlatformEarningRefund,
+    Refund,
+    RefundFailure,
+    RiskReservedFunds,
+    Tax,
+    Topup,
+    TopupReversal,
+    Transfer,
+    TransferReversal,
+    UnreconciledCustomerFunds,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
+}
+
+impl BalanceTransactionReportingCategory {
+    pub fn as_str(&self) -> &str {
+        match self {
+            BalanceTransactionReportingCategory::Advance => "advance",
+            BalanceTransactionReportingCategory::AdvanceFunding => "advance_funding",
+            BalanceTransactionReportingCategory::AnticipationRepayment => "anticipation_repayment",
+            BalanceTransactionReportingCategory::Charge => "charge",
+            BalanceTransactionReportingCategory::ChargeFailure => "charge_failure",
+            BalanceTransactionReportingCategory::ClimateOrderPurchase => "climate_order_purchase",
+            BalanceTransactionReportingCategory::ClimateOrderRefund => "climate_order_refund",
+            BalanceTransactionReportingCategory::ConnectCollectionTransfer => {
+                "connect_collection_transfer"
+            }
+            BalanceTransactionReportingCategory::ConnectReservedFunds => "connect_reserved_funds",
+            BalanceTransactionReportingCategory::Contribution => "contribution",
+            BalanceTransactionReportingCategory::Dispute => "dispute",
+            BalanceTransactionReportingCategory::DisputeReversal => "dispute_reversal",
+            BalanceTransactionReportingCategory::Fee => "fee",
+            BalanceTransactionReportingCategory::FinancingPaydown => "financing_paydown",
+            BalanceTransactionReportingCategory::FinancingPaydownReversal => {
+                "financing_paydown_reversal"
+            }
+            BalanceTransactionReportingCategory::FinancingPayout => "financing_payout",
+            BalanceTransactionReportingCategory::FinancingPayoutReversal => {
+                "financing_payout_reversal"
+            }
+            BalanceTransactionReportingCategory::IssuingAuthorizationHold => {
+                "issuing_authorization_hold"
+            }
+            BalanceTransactionReportingCategory::IssuingAuthorizationRelease => {
+                "issuing_authorization_release"
+            }
+            BalanceTransactionReportingCategory::IssuingDispute => "issuing_dispute",
+            BalanceTransactionReportingCategory::IssuingTransaction => "issuing_transaction",
+            BalanceTransactionReportingCategory::NetworkCost => "network_cost",
+            BalanceTransactionReportingCategory::OtherAdjustment => "other_adjustment",
+            BalanceTransactionReportingCategory::PartialCaptureReversal => {
+                "partial_capture_reversal"
+            }
+            BalanceTransactionReportingCategory::Payout => "payout",
+            BalanceTransactionReportingCategory::PayoutReversal => "payout_reversal",
+            BalanceTransactionReportingCategory::PlatformEarning => "platform_earning",
+            BalanceTransactionReportingCategory::PlatformEarningRefund => "platform_earning_refund",
+            BalanceTransactionReportingCategory::Refund => "refund",
+            BalanceTransactionReportingCategory::RefundFailure => "refund_failure",
+            BalanceTransactionReportingCategory::RiskReservedFunds => "risk_reserved_funds",
+            BalanceTransactionReportingCategory::Tax => "tax",
+            BalanceTransactionReportingCategory::Topup => "topup",
+            BalanceTransactionReportingCategory::TopupReversal => "topup_reversal",
+            BalanceTransactionReportingCategory::Transfer => "transfer",
+            BalanceTransactionReportingCategory::TransferReversal => "transfer_reversal",
+            BalanceTransactionReportingCategory::UnreconciledCustomerFunds => {
+                "unreconciled_customer_funds"
+            }
+            BalanceTransactionReportingCategory::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for BalanceTransactionReportingCategory {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "advance" => BalanceTransactionReportingCategory::Advance,
+            "advance_funding" => BalanceTransactionReportingCategory::AdvanceFunding,
+            "anticipation_repayment" => BalanceTransactionReportingCategory::AnticipationRepayment,
+            "charge" => BalanceTransactionReportingCategory::Charge,
+            "charge_failure" => BalanceTransactionReportingCategory::ChargeFailure,
+            "climate_order_purchase" => BalanceTransactionReportingCategory::ClimateOrderPurchase,
+            "climate_order_refund" => BalanceTransactionReportingCategory::ClimateOrderRefund,
+            "connect_collection_transfer" => {
+                BalanceTransactionReportingCategory::ConnectCollectionTransfer
+            }
+            "connect_reserved_funds" => BalanceTransactionReportingCategory::ConnectReservedFunds,
+            "contribution" => BalanceTransactionReportingCategory::Contribution,
+            "dispute" => BalanceTransactionReportingCategory::Dispute,
+            "dispute_reversal" => BalanceTransactionReportingCategory::DisputeReversal,
+            "fee" => BalanceTransactionReportingCategory::Fee,
+            "financing_paydown" => BalanceTransactionReportingCategory::FinancingPaydown,
+            "financing_paydown_reversal" => {
+                BalanceTransactionReportingCategory::FinancingPaydownReversal
+            }
+            "financing_payout" => BalanceTransactionReportingCategory::FinancingPayout,
+            "financing_payout_reversal" => {
+                BalanceTransactionReportingCategory::FinancingPayoutReversal
+            }
+            "issuing_authorization_hold" => {
+                BalanceTransactionReportingCategory::IssuingAuthorizationHold
+            }
+            "issuing_authorization_release" => {
+                BalanceTransactionReportingCategory::IssuingAuthorizationRelease
+            }
+            "issuing_dispute" => BalanceTransactionReportingCategory::IssuingDispute,
+            "issuing_transaction" => BalanceTransactionReportingCategory::IssuingTransaction,
+            "network_cost" => BalanceTransactionReportingCategory::NetworkCost,
+            "other_adjustment" => BalanceTransactionReportingCategory::OtherAdjustment,
+            "partial_capture_reversal" => {
+                BalanceTransactionReportingCategory::PartialCaptureReversal
+            }
+            "payout" => BalanceTransactionReportingCategory::Payout,
+            "payout_reversal" => BalanceTransactionReportingCategory::PayoutReversal,
+            "platform_earning" => BalanceTransactionReportingCategory::PlatformEarning,
+            "platform_earning_refund" => BalanceTransactionReportingCategory::PlatformEarningRefund,
+            "refund" => BalanceTransactionReportingCategory::Refund,
+            "refund_failure" => BalanceTransactionReportingCategory::RefundFailure,
+            "risk_reserved_funds" => BalanceTransactionReportingCategory::RiskReservedFunds,
+            "tax" => BalanceTransactionReportingCategory::Tax,
+            "topup" => BalanceTransactionReportingCategory::Topup,
+            "topup_reversal" => BalanceTransactionReportingCategory::TopupReversal,
+            "transfer" => BalanceTransactionReportingCategory::Transfer,
+            "transfer_reversal" => BalanceTransactionReportingCategory::TransferReversal,
+            "unreconciled_customer_funds" => {
+                BalanceTransactionReportingCategory::UnreconciledCustomerFunds
+            }
+            _ => BalanceTransactionReportingCategory::Other(value),
+        }
+    }
+}
+
+impl AsRef<str> for BalanceTransactionReportingCategory {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BalanceTransactionReportingCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for BalanceTransactionReportingCategory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BalanceTransactionReportingCategory {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(BalanceTransactionReportingCategory::from(s))
+    }
+}
+
 /// An enum representing the possible values of an `BalanceTransaction`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum BalanceTransactionType {
     Adjustment,
     Advance,
@@ -269,10 +466,15 @@ pub enum BalanceTransactionType {
     TransferCancel,
     TransferFailure,
     TransferRefund,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
 }
 
 impl BalanceTransactionType {
-    pub fn as_str(self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             BalanceTransactionType::Adjustment => "adjustment",
             BalanceTransactionType::Advance => "advance",
@@ -303,6 +505,44 @@ impl BalanceTransactionType {
             BalanceTransactionType::TransferCancel => "transfer_cancel",
             BalanceTransactionType::TransferFailure => "transfer_failure",
             BalanceTransactionType::TransferRefund => "transfer_refund",
+            BalanceTransactionType::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for BalanceTransactionType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "adjustment" => BalanceTransactionType::Adjustment,
+            "advance" => BalanceTransactionType::Advance,
+            "advance_funding" => BalanceTransactionType::AdvanceFunding,
+            "application_fee" => BalanceTransactionType::ApplicationFee,
+            "application_fee_refund" => BalanceTransactionType::ApplicationFeeRefund,
+            "charge" => BalanceTransactionType::Charge,
+            "connect_collection_transfer" => BalanceTransactionType::ConnectCollectionTransfer,
+            "issuing_authorization_hold" => BalanceTransactionType::IssuingAuthorizationHold,
+            "issuing_authorization_release" => BalanceTransactionType::IssuingAuthorizationRelease,
+            "issuing_transaction" => BalanceTransactionType::IssuingTransaction,
+            "payment" => BalanceTransactionType::Payment,
+            "payment_failure_refund" => BalanceTransactionType::PaymentFailureRefund,
+            "payment_refund" => BalanceTransactionType::PaymentRefund,
+            "payout" => BalanceTransactionType::Payout,
+            "payout_cancel" => BalanceTransactionType::PayoutCancel,
+            "payout_failure" => BalanceTransactionType::PayoutFailure,
+            "refund" => BalanceTransactionType::Refund,
+            "refund_failure" => BalanceTransactionType::RefundFailure,
+            "reserve_transaction" => BalanceTransactionType::ReserveTransaction,
+            "reserved_funds" => BalanceTransactionType::ReservedFunds,
+            "stripe_fee" => BalanceTransactionType::StripeFee,
+            "stripe_fx_fee" => BalanceTransactionType::StripeFxFee,
+            "tax_fee" => BalanceTransactionType::TaxFee,
+            "topup" => BalanceTransactionType::Topup,
+            "topup_reversal" => BalanceTransactionType::TopupReversal,
+            "transfer" => BalanceTransactionType::Transfer,
+            "transfer_cancel" => BalanceTransactionType::TransferCancel,
+            "transfer_failure" => BalanceTransactionType::TransferFailure,
+            "transfer_refund" => BalanceTransactionType::TransferRefund,
+            _ => BalanceTransactionType::Other(value),
         }
     }
 }
@@ -318,3 +558,22 @@ impl std::fmt::Display for BalanceTransactionType {
         self.as_str().fmt(f)
     }
 }
+
+impl serde::Serialize for BalanceTransactionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BalanceTransactionType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(BalanceTransactionType::from(s))
+    }
+}
@@ -0,0 +1,217 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::ids::{BillingPortalSessionId, CustomerId};
+use crate::params::{Object, Timestamp};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "PortalSession".
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct BillingPortalSession {
+    /// Unique identifier for the object.
+    pub id: BillingPortalSessionId,
+
+    /// The configuration used by this session, describing the features available.
+    pub configuration: String,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// The ID of the customer for this session.
+    pub customer: CustomerId,
+
+    /// Information about a specific flow for the customer to go through.
+    ///
+    /// See the [docs](https://stripe.com/docs/customer-management/portal-deep-links) to learn more about using customer portal deep links and flows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flow: Option<PortalFlowData>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// The IETF language tag of the locale Customer Portal is displayed in.
+    ///
+    /// If blank or auto, the customer’s `preferred_locales` or browser’s locale is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+
+    /// The account for which the session was created on behalf of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<String>,
+
+    /// The URL to redirect customers to when they click on the portal’s link to return to your website.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<String>,
+
+    /// The short-lived URL of the session that gives customers access to the customer portal.
+    pub url: String,
+}
+
+impl Object for BillingPortalSession {
+    type Id = BillingPortalSessionId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "billing_portal.session"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PortalFlowData {
+    /// Behavior after the flow is completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_completion: Option<PortalFlowAfterCompletion>,
+
+    /// Configuration when `flow_data.type=subscription_cancel`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_cancel: Option<PortalFlowSubscriptionCancel>,
+
+    /// Configuration when `flow_data.type=subscription_update`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_update: Option<PortalFlowSubscriptionUpdate>,
+
+    /// Configuration when `flow_data.type=subscription_update_confirm`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_update_confirm: Option<PortalFlowSubscriptionUpdateConfirm>,
+
+    /// Type of flow that the customer will go through.
+    #[serde(rename = "type")]
+    pub type_: PortalFlowType,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PortalFlowAfterCompletion {
+    /// Configuration when `after_completion.type=hosted_confirmation`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hosted_confirmation: Option<PortalFlowAfterCompletionHostedConfirmation>,
+
+    /// Configuration when `after_completion.type=redirect`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect: Option<PortalFlowAfterCompletionRedirect>,
+
+    /// The specified behavior after the flow is completed.
+    #[serde(rename = "type")]
+    pub type_: PortalFlowAfterCompletionType,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PortalFlowAfterCompletionHostedConfirmation {
+    /// A custom message to display to the customer after the flow is completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_message: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PortalFlowAfterCompletionRedirect {
+    /// The URL the customer will be redirected to after the flow is completed.
+    pub return_url: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PortalFlowSubscriptionCancel {
+    /// The ID of the subscription to be canceled.
+    pub subscription: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PortalFlowSubscriptionUpdate {
+    /// The ID of the subscription to be updated.
+    pub subscription: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PortalFlowSubscriptionUpdateConfirm {
+    /// The [subscription item](https://stripe.com/docs/api/subscription_items) to be updated through this flow.
+    ///
+    /// Currently, only up to one may be specified and subscriptions with multiple items are not updatable.
+    #[serde(default)]
+    pub items: Vec<PortalFlowSubscriptionUpdateConfirmItem>,
+
+    /// The ID of the subscription to be updated.
+    pub subscription: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PortalFlowSubscriptionUpdateConfirmItem {
+    /// The ID of the [subscription item](https://stripe.com/docs/api/subscriptions/object#subscription_object-items-data-id) to be updated.
+    pub id: String,
+
+    /// The price the customer should subscribe to through this flow.
+    ///
+    /// The price must also be included in the configuration's [`features.subscription_update.products`](https://stripe.com/docs/api/customer_portal/configuration#portal_configuration_object-features-subscription_update-products).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// [Quantity](https://stripe.com/docs/subscriptions/quantities) for this item that the customer should subscribe to through this flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+}
+
+/// An enum representing the possible values of an `PortalFlowAfterCompletion`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PortalFlowAfterCompletionType {
+    HostedConfirmation,
+    PortalHomepage,
+    Redirect,
+}
+
+impl PortalFlowAfterCompletionType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PortalFlowAfterCompletionType::HostedConfirmation => "hosted_confirmation",
+            PortalFlowAfterCompletionType::PortalHomepage => "portal_homepage",
+            PortalFlowAfterCompletionType::Redirect => "redirect",
+        }
+    }
+}
+
+impl AsRef<str> for PortalFlowAfterCompletionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PortalFlowAfterCompletionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `PortalFlowData`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PortalFlowType {
+    PaymentMethodUpdate,
+    SubscriptionCancel,
+    SubscriptionUpdate,
+    SubscriptionUpdateConfirm,
+}
+
+impl PortalFlowType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PortalFlowType::PaymentMethodUpdate => "payment_method_update",
+            PortalFlowType::SubscriptionCancel => "subscription_cancel",
+            PortalFlowType::SubscriptionUpdate => "subscription_update",
+            PortalFlowType::SubscriptionUpdateConfirm => "subscription_update_confirm",
+        }
+    }
+}
+
+impl AsRef<str> for PortalFlowType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PortalFlowType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::config::{Client, Response};
+use crate::ids::CustomerId;
+use crate::params::Expand;
+use crate::resources::{BillingPortalSession, PortalFlowData, PortalFlowType};
+use serde_derive::Serialize;
+
+impl BillingPortalSession {
+    /// Creates a session of the customer portal.
+    pub fn create(
+        client: &Client,
+        params: CreateBillingPortalSession<'_>,
+    ) -> Response<BillingPortalSession> {
+        client.post_form("/billing_portal/sessions", &params)
+    }
+}
+
+/// The parameters for `BillingPortalSession::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateBillingPortalSession<'a> {
+    /// The ID of an existing [configuration](https://stripe.com/docs/api/customer_portal/configuration) to use for this session, describing its functionality and features.
+    ///
+    /// If not specified, the session uses the default configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configuration: Option<&'a str>,
+
+    /// The ID of an existing customer.
+    pub customer: CustomerId,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Information about a specific flow for the customer to go through.
+    ///
+    /// See the [docs](https://stripe.com/docs/customer-management/portal-deep-links) to learn more about using customer portal deep links and flows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flow_data: Option<PortalFlowData>,
+
+    /// The IETF language tag of the locale Customer Portal is displayed in.
+    ///
+    /// If blank or auto, the customer’s `preferred_locales` or browser’s locale is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<&'a str>,
+
+    /// The `on_behalf_of` account to use for this session.
+    ///
+    /// When specified, only subscriptions and invoices with this `on_behalf_of` account appear in the portal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<&'a str>,
+
+    /// The default URL to redirect customers to when they click on the portal’s link to return to your website.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
+}
+
+impl<'a> CreateBillingPortalSession<'a> {
+    pub fn new(customer: CustomerId) -> Self {
+        CreateBillingPortalSession {
+            configuration: Default::default(),
+            customer,
+            expand: Default::default(),
+            flow_data: Default::default(),
+            locale: Default::default(),
+            on_behalf_of: Default::default(),
+            return_url: Default::default(),
+        }
+    }
+}
+
+impl PortalFlowData {
+    pub fn new(type_: PortalFlowType) -> Self {
+        PortalFlowData {
+            after_completion: Default::default(),
+            subscription_cancel: Default::default(),
+            subscription_update: Default::default(),
+            subscription_update_confirm: Default::default(),
+            type_,
+        }
+    }
+}
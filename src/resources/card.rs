@@ -7,6 +7,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/cards/object](https://stripe.com/docs/api/cards/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Card {
     /// Unique identifier for the object.
     pub id: CardId,
@@ -6,9 +6,9 @@ use crate::config::{Client, Response};
 use crate::ids::{ChargeId, CustomerId, PaymentIntentId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
-    Account, Application, ApplicationFee, BalanceTransaction, BillingDetails, ChargeSourceParams,
-    Currency, Customer, FraudDetailsReport, Invoice, Order, PaymentIntent, PaymentMethodDetails,
-    PaymentSource, Refund, Review, Shipping, Transfer,
+    Account, Application, ApplicationFee, BalanceTransaction, BillingDetails, ChargeShippingParams,
+    ChargeSourceParams, Currency, Customer, FraudDetailsReport, Invoice, Order, PaymentIntent,
+    PaymentMethodDetails, PaymentSource, Refund, Review, Shipping, Transfer,
 };
 use serde_derive::{Deserialize, Serialize};
 
@@ -16,6 +16,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/charges/object](https://stripe.com/docs/api/charges/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Charge {
     /// Unique identifier for the object.
     pub id: ChargeId,
@@ -106,6 +107,7 @@ pub struct Charge {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
     /// The account (if any) the charge was made on behalf of without triggering an automatic transfer.
@@ -221,7 +223,7 @@ impl Charge {
     ///
     /// The charges are returned in sorted order, with the most recent charges appearing first.
     pub fn list(client: &Client, params: ListCharges<'_>) -> Response<List<Charge>> {
-        client.get_query("/charges", &params)
+        List::get_with_params(client, "/charges", &params)
     }
 
     /// To charge a credit card or other payment source, you create a `Charge` object.
@@ -270,7 +272,7 @@ pub struct FraudDetails {
     ///
     /// If set, possible values of are `safe` and `fraudulent`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_report: Option<String>,
+    pub user_report: Option<FraudDetailsReport>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -423,7 +425,7 @@ pub struct CreateCharge<'a> {
     ///
     /// Helps prevent fraud on charges for physical goods.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping: Option<Shipping>,
+    pub shipping: Option<ChargeShippingParams>,
 
     /// A payment source to be charged.
     ///
@@ -587,7 +589,7 @@ pub struct UpdateCharge<'a> {
     ///
     /// Helps prevent fraud on charges for physical goods.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping: Option<Shipping>,
+    pub shipping: Option<ChargeShippingParams>,
 
     /// A string that identifies this transaction as part of a group.
     ///
@@ -22,9 +22,9 @@ pub enum ChargeSourceParams {
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CaptureCharge<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<u64>,
+    pub amount: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub application_fee: Option<u64>,
+    pub application_fee: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub receipt_email: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5,19 +5,28 @@
 use crate::ids::CheckoutSessionId;
 use crate::params::{Expandable, List, Metadata, Object};
 use crate::resources::{
-    CheckoutSessionItem, Currency, Customer, PaymentIntent, Plan, SetupIntent, Shipping, Sku,
-    Subscription,
+    Address, CheckoutSessionItem, Currency, Customer, CustomerTaxExempt, PaymentIntent, Plan,
+    SetupIntent, Shipping, Sku, Subscription, TaxIdData,
 };
 use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "Session".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct CheckoutSession {
     /// Unique identifier for the object.
     ///
     /// Used to pass to `redirectToCheckout` in Stripe.js.
     pub id: CheckoutSessionId,
 
+    /// Total of all items before discounts or taxes are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_subtotal: Option<i64>,
+
+    /// Total of all items after discounts and taxes are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_total: Option<i64>,
+
     /// The value (`auto` or `required`) for whether Checkout collected the
     /// customer's billing address.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -32,6 +41,20 @@ pub struct CheckoutSession {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_reference_id: Option<String>,
 
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
+    /// Additional fields collected from the customer at checkout, with the values they entered.
+    #[serde(default)]
+    pub custom_fields: Vec<CheckoutSessionCustomField>,
+
+    /// Custom messages displayed to the customer on the checkout page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_text: Option<CheckoutSessionCustomText>,
+
     /// The ID of the customer for this session.
     /// For Checkout Sessions in `payment` or `subscription` mode, Checkout
     /// will create a new customer object based on information provided
@@ -40,6 +63,12 @@ pub struct CheckoutSession {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub customer: Option<Expandable<Customer>>,
 
+    /// The customer details including the customer's tax exempt status and the customer's tax IDs.
+    ///
+    /// Only the customer's email is present on Sessions in `setup` mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_details: Option<CheckoutSessionCustomerDetails>,
+
     /// If provided, this value will be used when the Customer object is created.
     /// If not provided, customers will be asked to enter their email address.
     /// Use this parameter to prefill customer data if you already have an email
@@ -85,8 +114,15 @@ pub struct CheckoutSession {
     /// A list of the types of payment methods (e.g.
     ///
     /// card) this Checkout Session is allowed to accept.
+    #[serde(default)]
     pub payment_method_types: Vec<String>,
 
+    /// The payment status of the Checkout Session, one of `paid`, `unpaid`, or `no_payment_required`.
+    ///
+    /// You can use this value to decide when to fulfill your customer's order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_status: Option<CheckoutSessionPaymentStatus>,
+
     /// The ID of the SetupIntent for Checkout Sessions in `setup` mode.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub setup_intent: Option<Expandable<SetupIntent>>,
@@ -99,6 +135,10 @@ pub struct CheckoutSession {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shipping_address_collection: Option<ShippingAddressCollection>,
 
+    /// The status of the Checkout Session, one of `open`, `complete`, or `expired`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CheckoutSessionStatus>,
+
     /// Describes the type of transaction being performed by Checkout in order to customize
     /// relevant text on the page, such as the submit button.
     ///
@@ -113,6 +153,13 @@ pub struct CheckoutSession {
     /// The URL the customer will be directed to after the payment or
     /// subscription creation is successful.
     pub success_url: String,
+
+    /// The URL to the Checkout Session.
+    ///
+    /// Redirect customers to this URL to take them to Checkout.
+    /// This is `null` once the session is complete or expired.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
 }
 
 impl Object for CheckoutSession {
@@ -172,12 +219,153 @@ pub struct CheckoutSessionCustomDisplayItemDescription {
     pub name: String,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomField {
+    /// Configuration and value for `dropdown` fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dropdown: Option<CustomFieldDropdown>,
+
+    /// String of your choice that your integration can use to reconcile this field.
+    ///
+    /// Must be unique to this field, alphanumeric, and up to 200 characters.
+    pub key: String,
+
+    pub label: CustomFieldLabel,
+
+    /// Configuration and value for `numeric` fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub numeric: Option<CustomFieldNumeric>,
+
+    /// Whether the customer is required to complete the field before completing the Checkout Session.
+    ///
+    /// Defaults to `false`.
+    pub optional: bool,
+
+    /// Configuration and value for `text` fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<CustomFieldText>,
+
+    /// The type of the field.
+    #[serde(rename = "type")]
+    pub type_: CustomFieldType,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomFieldDropdown {
+    /// The options available for the customer to select.
+    ///
+    /// Up to 200 options allowed.
+    #[serde(default)]
+    pub options: Vec<CustomFieldDropdownOption>,
+
+    /// The option selected by the customer.
+    ///
+    /// This will be the `value` for the option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomFieldLabel {
+    /// Custom text for the label, displayed to the customer.
+    ///
+    /// Up to 50 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom: Option<String>,
+
+    /// The type of the label.
+    #[serde(rename = "type")]
+    pub type_: CustomFieldLabelType,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomFieldNumeric {
+    /// The value entered by the customer, containing only digits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomFieldDropdownOption {
+    /// The label for the option, displayed to the customer.
+    ///
+    /// Up to 100 characters.
+    pub label: String,
+
+    /// The value for this option, not displayed to the customer, used by your integration to reconcile the option selected by the customer.
+    ///
+    /// Must be unique to this option, alphanumeric, and up to 100 characters.
+    pub value: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomFieldText {
+    /// The value entered by the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomText {
+    /// Custom text that should be displayed alongside shipping address collection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<CustomTextPosition>,
+
+    /// Custom text that should be displayed alongside the payment confirmation button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit: Option<CustomTextPosition>,
+
+    /// Custom text that should be displayed in place of the default terms of service agreement text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terms_of_service_acceptance: Option<CustomTextPosition>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomTextPosition {
+    /// Text may be up to 1200 characters in length.
+    pub message: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckoutSessionCustomerDetails {
+    /// The customer's address after a completed Checkout Session.
+    ///
+    /// Note: This property is populated only for sessions on or after March 30, 2022.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+
+    /// The email associated with the Customer, if one exists, on the Checkout Session after a completed Checkout Session or at time of session expiry.
+    ///
+    /// Otherwise, if the customer has consented to promotional content, this value is the most recent valid email provided by the customer on the Checkout form.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// The customer's name after a completed Checkout Session.
+    ///
+    /// Note: This property is populated only for sessions on or after March 30, 2022.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The customer's phone number after a completed Checkout Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+
+    /// The customer’s tax exempt status after a completed Checkout Session.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_exempt: Option<CustomerTaxExempt>,
+
+    /// The customer’s tax IDs after a completed Checkout Session.
+    #[serde(default)]
+    pub tax_ids: Vec<TaxIdData>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ShippingAddressCollection {
     /// An array of two-letter ISO country codes representing which countries Checkout should provide as options for
     /// shipping locations.
     ///
     /// Unsupported country codes: `AS, CX, CC, CU, HM, IR, KP, MH, FM, NF, MP, PW, SD, SY, UM, VI`.
+    #[serde(default)]
     pub allowed_countries: Vec<ShippingAddressCollectionAllowedCountries>,
 }
 
@@ -272,6 +460,68 @@ impl std::fmt::Display for CheckoutSessionMode {
     }
 }
 
+/// An enum representing the possible values of an `CheckoutSession`'s `payment_status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionPaymentStatus {
+    NoPaymentRequired,
+    Paid,
+    Unpaid,
+}
+
+impl CheckoutSessionPaymentStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionPaymentStatus::NoPaymentRequired => "no_payment_required",
+            CheckoutSessionPaymentStatus::Paid => "paid",
+            CheckoutSessionPaymentStatus::Unpaid => "unpaid",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionPaymentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionPaymentStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSession`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSessionStatus {
+    Complete,
+    Expired,
+    Open,
+}
+
+impl CheckoutSessionStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckoutSessionStatus::Complete => "complete",
+            CheckoutSessionStatus::Expired => "expired",
+            CheckoutSessionStatus::Open => "open",
+        }
+    }
+}
+
+impl AsRef<str> for CheckoutSessionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CheckoutSessionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `CheckoutSession`'s `submit_type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -305,6 +555,64 @@ impl std::fmt::Display for CheckoutSessionSubmitType {
     }
 }
 
+/// An enum representing the possible values of an `CustomFieldLabel`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomFieldLabelType {
+    Custom,
+}
+
+impl CustomFieldLabelType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CustomFieldLabelType::Custom => "custom",
+        }
+    }
+}
+
+impl AsRef<str> for CustomFieldLabelType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CustomFieldLabelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CheckoutSessionCustomField`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomFieldType {
+    Dropdown,
+    Numeric,
+    Text,
+}
+
+impl CustomFieldType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CustomFieldType::Dropdown => "dropdown",
+            CustomFieldType::Numeric => "numeric",
+            CustomFieldType::Text => "text",
+        }
+    }
+}
+
+impl AsRef<str> for CustomFieldType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CustomFieldType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `ShippingAddressCollection`'s `allowed_countries` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
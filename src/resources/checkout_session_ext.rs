@@ -0,0 +1,100 @@
+use crate::config::{Client, Response};
+use crate::ids::{CheckoutSessionId, CustomerId, PriceId};
+use crate::params::{Expand, Metadata};
+use crate::resources::{CheckoutSession, CheckoutSessionCustomText, CheckoutSessionMode};
+use serde_derive::{Deserialize, Serialize};
+
+impl CheckoutSession {
+    /// Creates a Session object.
+    pub fn create(client: &Client, params: CreateCheckoutSession<'_>) -> Response<CheckoutSession> {
+        client.post_form("/checkout/sessions", &params)
+    }
+
+    /// Retrieves a Session object.
+    pub fn retrieve(
+        client: &Client,
+        id: &CheckoutSessionId,
+        expand: &[&str],
+    ) -> Response<CheckoutSession> {
+        client.get_query(&format!("/checkout/sessions/{}", id), &Expand { expand })
+    }
+}
+
+/// The parameters for `CheckoutSession::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateCheckoutSession<'a> {
+    /// If set, Checkout displays a back button and customers will be directed to this URL if they decide to cancel payment and return to your website.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_url: Option<&'a str>,
+
+    /// A unique string to reference the Checkout Session.
+    ///
+    /// This can be a customer ID, a cart ID, or similar, and can be used to reconcile the session with your internal systems.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_reference_id: Option<&'a str>,
+
+    /// Display additional text for your customers using custom text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_text: Option<CheckoutSessionCustomText>,
+
+    /// ID of an existing Customer, if one exists.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+
+    /// If provided, this value will be used when the Customer object is created.
+    ///
+    /// If not provided, customers will be asked to enter their email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_email: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A list of items the customer is purchasing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<CreateCheckoutSessionLineItems>>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The mode of the Checkout Session.
+    ///
+    /// Pass `subscription` if the Checkout Session includes at least one recurring item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<CheckoutSessionMode>,
+
+    /// The URL to which Stripe should send customers when payment or setup is complete.
+    pub success_url: &'a str,
+}
+
+impl<'a> CreateCheckoutSession<'a> {
+    pub fn new(success_url: &'a str) -> Self {
+        CreateCheckoutSession {
+            cancel_url: Default::default(),
+            client_reference_id: Default::default(),
+            custom_text: Default::default(),
+            customer: Default::default(),
+            customer_email: Default::default(),
+            expand: Default::default(),
+            line_items: Default::default(),
+            metadata: Default::default(),
+            mode: Default::default(),
+            success_url,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CreateCheckoutSessionLineItems {
+    /// The ID of the [Price](https://stripe.com/docs/api/prices) or [Plan](https://stripe.com/docs/api/plans) object.
+    pub price: PriceId,
+
+    /// The quantity of the line item being purchased.
+    pub quantity: u64,
+}
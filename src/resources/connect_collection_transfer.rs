@@ -8,6 +8,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "ConnectCollectionTransfer".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct ConnectCollectionTransfer {
     /// Amount transferred, in %s.
     pub amount: i64,
@@ -3,15 +3,17 @@
 // ======================================
 
 use crate::config::{Client, Response};
-use crate::ids::CouponId;
+use crate::ids::{CouponId, ProductId};
 use crate::params::{Deleted, Expand, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::Currency;
 use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// The resource representing a Stripe "Coupon".
 ///
 /// For more details see [https://stripe.com/docs/api/coupons/object](https://stripe.com/docs/api/coupons/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Coupon {
     /// Unique identifier for the object.
     pub id: CouponId,
@@ -20,6 +22,10 @@ pub struct Coupon {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub amount_off: Option<i64>,
 
+    /// Contains information about what this coupon applies to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applies_to: Option<CouponAppliesTo>,
+
     /// Time at which the object was created.
     ///
     /// Measured in seconds since the Unix epoch.
@@ -30,6 +36,12 @@ pub struct Coupon {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
 
+    /// Coupons defined in each available currency option.
+    ///
+    /// Each key must be a three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html) and a [supported currency](https://stripe.com/docs/currencies).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_options: Option<HashMap<Currency, CouponCurrencyOption>>,
+
     // Always true for a deleted object
     #[serde(default)]
     pub deleted: bool,
@@ -86,7 +98,7 @@ pub struct Coupon {
 impl Coupon {
     /// Returns a list of your coupons.
     pub fn list(client: &Client, params: ListCoupons<'_>) -> Response<List<Coupon>> {
-        client.get_query("/coupons", &params)
+        List::get_with_params(client, "/coupons", &params)
     }
 
     /// You can create coupons easily via the [coupon management](https://dashboard.stripe.com/coupons) page of the Stripe dashboard.
@@ -129,6 +141,19 @@ impl Object for Coupon {
     }
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CouponAppliesTo {
+    /// A list of product IDs this coupon applies to.
+    #[serde(default)]
+    pub products: Vec<ProductId>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CouponCurrencyOption {
+    /// Amount (in the `currency` specified) that will be taken off the subtotal of any invoices for this customer.
+    pub amount_off: i64,
+}
+
 /// The parameters for `Coupon::create`.
 #[derive(Clone, Debug, Serialize)]
 pub struct CreateCoupon<'a> {
@@ -136,10 +161,20 @@ pub struct CreateCoupon<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub amount_off: Option<i64>,
 
+    /// A hash containing directions for what this Coupon will apply discounts to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applies_to: Option<CouponAppliesTo>,
+
     /// Three-letter [ISO code for the currency](https://stripe.com/docs/currencies) of the `amount_off` parameter (required if `amount_off` is passed).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
 
+    /// Coupons defined in each available currency option (only supported if `amount_off` is passed).
+    ///
+    /// Each key must be a three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html) and a [supported currency](https://stripe.com/docs/currencies).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_options: Option<HashMap<Currency, CreateCouponCurrencyOptions>>,
+
     /// Specifies how long the discount will be in effect.
     ///
     /// Can be `forever`, `once`, or `repeating`.
@@ -194,7 +229,9 @@ impl<'a> CreateCoupon<'a> {
     pub fn new(duration: CouponDuration) -> Self {
         CreateCoupon {
             amount_off: Default::default(),
+            applies_to: Default::default(),
             currency: Default::default(),
+            currency_options: Default::default(),
             duration,
             duration_in_months: Default::default(),
             expand: Default::default(),
@@ -257,6 +294,12 @@ impl<'a> ListCoupons<'a> {
 /// The parameters for `Coupon::update`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct UpdateCoupon<'a> {
+    /// Coupons defined in each available currency option (only supported if the coupon is amount-based).
+    ///
+    /// Each key must be a three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html) and a [supported currency](https://stripe.com/docs/currencies).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_options: Option<HashMap<Currency, UpdateCouponCurrencyOptions>>,
+
     /// Specifies which fields in the response should be expanded.
     #[serde(skip_serializing_if = "Expand::is_empty")]
     pub expand: &'a [&'a str],
@@ -279,6 +322,7 @@ pub struct UpdateCoupon<'a> {
 impl<'a> UpdateCoupon<'a> {
     pub fn new() -> Self {
         UpdateCoupon {
+            currency_options: Default::default(),
             expand: Default::default(),
             metadata: Default::default(),
             name: Default::default(),
@@ -286,6 +330,18 @@ impl<'a> UpdateCoupon<'a> {
     }
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateCouponCurrencyOptions {
+    /// A positive integer representing the amount to subtract from an invoice total.
+    pub amount_off: i64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateCouponCurrencyOptions {
+    /// A positive integer representing the amount to subtract from an invoice total.
+    pub amount_off: i64,
+}
+
 /// An enum representing the possible values of an `Coupon`'s `duration` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
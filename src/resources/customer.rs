@@ -17,6 +17,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/customers/object](https://stripe.com/docs/api/customers/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Customer {
     /// Unique identifier for the object.
     pub id: CustomerId,
@@ -137,8 +138,9 @@ impl Customer {
     /// Returns a list of your customers.
     ///
     /// The customers are returned sorted by creation date, with the most recent customers appearing first.
+    /// Set `email` to look up existing customers by email address, e.g. before creating a new one.
     pub fn list(client: &Client, params: ListCustomers<'_>) -> Response<List<Customer>> {
-        client.get_query("/customers", &params)
+        List::get_with_params(client, "/customers", &params)
     }
 
     /// Creates a new customer object.
@@ -272,6 +274,10 @@ pub struct CreateCustomer<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_invoice_sequence: Option<i64>,
 
+    /// The ID of a PaymentMethod to attach to the new customer.
+    ///
+    /// The PaymentMethod must not yet be attached to another customer, e.g. one created client-side with Stripe.js.
+    /// Set `invoice_settings.default_payment_method` to the same ID to also make it the default for invoices and subscriptions.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_method: Option<PaymentMethodId>,
 
@@ -334,7 +340,7 @@ pub struct ListCustomers<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<RangeQuery<Timestamp>>,
 
-    /// A filter on the list based on the customer's `email` field.
+    /// A case-sensitive filter on the list based on the customer's `email` field.
     ///
     /// The value must be a string.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -520,11 +526,12 @@ impl<'a> UpdateCustomer<'a> {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CustomerInvoiceSettings {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_fields: Option<Vec<CustomField>>,
 
+    /// ID of a payment method that's attached to the customer, to be used as the customer's default payment method for subscriptions and invoices.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_payment_method: Option<String>,
 
@@ -603,8 +610,7 @@ impl std::fmt::Display for CustomerTaxExemptFilter {
 }
 
 /// An enum representing the possible values of an `TaxIdData`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum TaxIdType {
     AuAbn,
     BrCnpj,
@@ -631,10 +637,15 @@ pub enum TaxIdType {
     TwVat,
     UsEin,
     ZaVat,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
 }
 
 impl TaxIdType {
-    pub fn as_str(self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             TaxIdType::AuAbn => "au_abn",
             TaxIdType::BrCnpj => "br_cnpj",
@@ -661,6 +672,40 @@ impl TaxIdType {
             TaxIdType::TwVat => "tw_vat",
             TaxIdType::UsEin => "us_ein",
             TaxIdType::ZaVat => "za_vat",
+            TaxIdType::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for TaxIdType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "au_abn" => TaxIdType::AuAbn,
+            "br_cnpj" => TaxIdType::BrCnpj,
+            "br_cpf" => TaxIdType::BrCpf,
+            "ca_bn" => TaxIdType::CaBn,
+            "ca_qst" => TaxIdType::CaQst,
+            "ch_vat" => TaxIdType::ChVat,
+            "es_cif" => TaxIdType::EsCif,
+            "eu_vat" => TaxIdType::EuVat,
+            "hk_br" => TaxIdType::HkBr,
+            "in_gst" => TaxIdType::InGst,
+            "jp_cn" => TaxIdType::JpCn,
+            "kr_brn" => TaxIdType::KrBrn,
+            "li_uid" => TaxIdType::LiUid,
+            "mx_rfc" => TaxIdType::MxRfc,
+            "my_itn" => TaxIdType::MyItn,
+            "my_sst" => TaxIdType::MySst,
+            "no_vat" => TaxIdType::NoVat,
+            "nz_gst" => TaxIdType::NzGst,
+            "ru_inn" => TaxIdType::RuInn,
+            "sg_gst" => TaxIdType::SgGst,
+            "sg_uen" => TaxIdType::SgUen,
+            "th_vat" => TaxIdType::ThVat,
+            "tw_vat" => TaxIdType::TwVat,
+            "us_ein" => TaxIdType::UsEin,
+            "za_vat" => TaxIdType::ZaVat,
+            _ => TaxIdType::Other(value),
         }
     }
 }
@@ -676,3 +721,22 @@ impl std::fmt::Display for TaxIdType {
         self.as_str().fmt(f)
     }
 }
+
+impl serde::Serialize for TaxIdType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TaxIdType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(TaxIdType::from(s))
+    }
+}
@@ -1,5 +1,5 @@
 use crate::config::{Client, Response};
-use crate::ids::{BankAccountId, CardId, CustomerId, PaymentSourceId};
+use crate::ids::{BankAccountId, CardId, CustomerId, DiscountId, PaymentSourceId};
 use crate::params::Deleted;
 use crate::resources::{BankAccount, Customer, PaymentSource, PaymentSourceParams, Source};
 use serde_derive::{Deserialize, Serialize};
@@ -32,6 +32,17 @@ impl Customer {
         client.delete(&format!("/customers/{}/sources/{}", customer_id, source_id))
     }
 
+    /// Removes the currently applied discount on a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/discounts/delete.
+    /// The id is missing from the response on API versions before 2020-08-27.
+    pub fn delete_discount(
+        client: &Client,
+        customer_id: &CustomerId,
+    ) -> Response<Deleted<Option<DiscountId>>> {
+        client.delete(&format!("/customers/{}/discount", customer_id))
+    }
+
     /// Retrieves a Card, BankAccount, or Source for a Customer
     pub fn retrieve_source(
         client: &Client,
@@ -2,17 +2,32 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::ids::{CheckoutSessionId, DiscountId, InvoiceId, SubscriptionId};
 use crate::params::{Expandable, Object, Timestamp};
-use crate::resources::{Coupon, Customer};
+use crate::resources::{Coupon, Customer, PromotionCode};
 use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "Discount".
 ///
 /// For more details see [https://stripe.com/docs/api/discounts/object](https://stripe.com/docs/api/discounts/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Discount {
+    /// The ID of the discount object.
+    ///
+    /// Discounts cannot be fetched by ID.
+    /// Use `expand[]=discounts` in API calls to expand discount IDs in an array.
+    #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub coupon: Option<Coupon>,
+    pub id: Option<DiscountId>,
+
+    /// The Checkout session that this coupon is applied to, if it is applied to a particular session in payment mode.
+    ///
+    /// Will not be present for subscription mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkout_session: Option<CheckoutSessionId>,
+
+    pub coupon: Coupon,
 
     /// The ID of the customer associated with this discount.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -28,18 +43,32 @@ pub struct Discount {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end: Option<Timestamp>,
 
+    /// The invoice that the discount's coupon was applied to, if it was applied directly to a particular invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<InvoiceId>,
+
+    /// The invoice item `id` (or invoice line item `id` for invoice line items of type='subscription') that the discount's coupon was applied to, if it was applied directly to a particular invoice item or invoice line item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_item: Option<String>,
+
+    /// The promotion code applied to create this discount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promotion_code: Option<Expandable<PromotionCode>>,
+
     /// Date that the coupon was applied.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start: Option<Timestamp>,
 
     /// The subscription that this coupon is applied to, if it is applied to a particular subscription.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription: Option<String>,
+    pub subscription: Option<SubscriptionId>,
 }
 
 impl Object for Discount {
-    type Id = ();
-    fn id(&self) -> Self::Id {}
+    type Id = DiscountId;
+    fn id(&self) -> Self::Id {
+        self.id.clone().unwrap_or_else(DiscountId::none)
+    }
     fn object(&self) -> &'static str {
         "discount"
     }
@@ -12,6 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/disputes/object](https://stripe.com/docs/api/disputes/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Dispute {
     /// Unique identifier for the object.
     pub id: DisputeId,
@@ -22,6 +23,7 @@ pub struct Dispute {
     pub amount: i64,
 
     /// List of zero, one, or two balance transactions that show funds withdrawn and reinstated to your Stripe account as a result of this dispute.
+    #[serde(default)]
     pub balance_transactions: Vec<BalanceTransaction>,
 
     /// ID of the charge that was disputed.
@@ -52,6 +54,7 @@ pub struct Dispute {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
     /// ID of the PaymentIntent that was disputed.
@@ -73,13 +76,22 @@ pub struct Dispute {
 impl Dispute {
     /// Returns a list of your disputes.
     pub fn list(client: &Client, params: ListDisputes<'_>) -> Response<List<Dispute>> {
-        client.get_query("/disputes", &params)
+        List::get_with_params(client, "/disputes", &params)
     }
 
     /// Retrieves the dispute with the given ID.
     pub fn retrieve(client: &Client, id: &DisputeId, expand: &[&str]) -> Response<Dispute> {
         client.get_query(&format!("/disputes/{}", id), &Expand { expand })
     }
+
+    /// When you get a dispute, contacting your customer is always the best first step.
+    ///
+    /// If that doesn’t work, you can submit evidence to help us resolve the dispute in your favor.
+    /// You can do this in your [dashboard](https://dashboard.stripe.com/disputes), but if you prefer, you can use the API to submit evidence programmatically.  Depending on your dispute type, different evidence fields will give you a better chance of winning your dispute.
+    /// To figure out which evidence fields to provide, see our [guide to dispute types](https://stripe.com/docs/disputes/categories).
+    pub fn update(client: &Client, id: &DisputeId, params: UpdateDispute<'_>) -> Response<Dispute> {
+        client.post_form(&format!("/disputes/{}", id), &params)
+    }
 }
 
 impl Object for Dispute {
@@ -92,7 +104,7 @@ impl Object for Dispute {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct DisputeEvidence {
     /// Any server or activity logs showing proof that the customer accessed or downloaded the purchased digital product.
     ///
@@ -296,6 +308,48 @@ impl<'a> ListDisputes<'a> {
     }
 }
 
+/// The parameters for `Dispute::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateDispute<'a> {
+    /// Evidence to upload, to respond to a dispute.
+    ///
+    /// Updating any field in the hash will submit all fields in the hash for review.
+    /// The combined character count of all fields is limited to 150,000.
+    /// File fields are sent as file ids, so evidence read from a dispute should be retrieved without expanding them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<DisputeEvidence>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Whether to immediately submit evidence to the bank.
+    ///
+    /// If `false`, evidence is staged on the dispute.
+    /// Staged evidence is visible in the API and Dashboard, and can be submitted to the bank by making another request with this attribute set to `true` (the default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit: Option<bool>,
+}
+
+impl<'a> UpdateDispute<'a> {
+    pub fn new() -> Self {
+        UpdateDispute {
+            evidence: Default::default(),
+            expand: Default::default(),
+            metadata: Default::default(),
+            submit: Default::default(),
+        }
+    }
+}
+
 /// An enum representing the possible values of an `Dispute`'s `status` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
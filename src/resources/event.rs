@@ -1,13 +1,16 @@
+use crate::config::{Client, Response};
 use crate::error::WebhookError;
 use crate::ids::{AccountId, EventId};
+use crate::params::{Expand, List, Object, RangeQuery, Timestamp};
 use crate::resources::*;
 
-use chrono::Utc;
 #[cfg(feature = "webhook-events")]
 use hmac::{Hmac, Mac};
 use serde_derive::{Deserialize, Serialize};
 #[cfg(feature = "webhook-events")]
 use sha2::Sha256;
+#[cfg(feature = "webhook-events")]
+use std::time::{SystemTime, UNIX_EPOCH};
 
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
 pub enum EventType {
@@ -203,58 +206,290 @@ pub struct Event {
     pub data: EventData,
     pub created: i64,
     pub livemode: bool,
+    /// The connected account that originated the event, for events delivered to Connect webhook endpoints.
     pub account: Option<AccountId>,
+    /// The Stripe API version used to render `data`.
+    ///
+    /// This is kept as a string since events can be rendered with versions older than any `ApiVersion`.
+    #[serde(default)]
+    pub api_version: Option<String>,
+    /// Number of webhooks that haven't been successfully delivered.
+    #[serde(default)]
+    pub pending_webhooks: u64,
+    /// Information on the API request that triggered the event, if any.
+    #[serde(default)]
+    pub request: Option<EventRequest>,
     // ...
 }
 
+impl Event {
+    /// List events, going back up to 30 days.
+    pub fn list(client: &Client, params: ListEvents<'_>) -> Response<List<Event>> {
+        List::get_with_params(client, "/events", &params)
+    }
+
+    /// Retrieves the details of an event.
+    ///
+    /// Supply the unique identifier of the event, which you might have received in a webhook.
+    pub fn retrieve(client: &Client, id: &EventId, expand: &[&str]) -> Response<Event> {
+        client.get_query(&format!("/events/{}", id), &Expand { expand })
+    }
+}
+
+impl Object for Event {
+    type Id = EventId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "event"
+    }
+}
+
+/// The parameters for `Event::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListEvents<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// Filter events by whether all webhooks were successfully delivered.
+    ///
+    /// If false, events which are still pending or have failed all delivery attempts to a webhook endpoint will be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_success: Option<bool>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<EventId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<EventId>,
+
+    /// Only return events of this type.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<EventType>,
+}
+
+impl<'a> ListEvents<'a> {
+    pub fn new() -> Self {
+        ListEvents {
+            created: Default::default(),
+            delivery_success: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+            type_: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EventRequest {
+    /// ID of the API request that caused the event.
+    ///
+    /// If null, the event was automatic (e.g., Stripe's automatic subscription handling).
+    pub id: Option<String>,
+    /// The idempotency key transmitted during the request, if any.
+    pub idempotency_key: Option<String>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EventData {
+    #[serde(deserialize_with = "deserialize_event_object")]
+    #[serde(serialize_with = "serialize_event_object")]
     pub object: EventObject,
     // previous_attributes: ...
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(tag = "object", rename_all = "snake_case")]
-pub enum EventObject {
-    Account(Account),
-    ApplicationFee(ApplicationFee),
-    #[serde(rename = "fee_refund")]
-    ApplicationFeeRefund(ApplicationFeeRefund),
-    Balance(Balance),
-    BankAccount(BankAccount),
-    Card(Card),
-    Charge(Charge),
-    Customer(Customer),
-    Dispute(Dispute),
-    #[serde(rename = "checkout.session")]
-    CheckoutSession(CheckoutSession),
-    File(File),
-    Invoice(Invoice),
-    #[serde(rename = "invoiceitem")]
-    InvoiceItem(InvoiceItem),
-    Order(Order),
-    OrderReturn(OrderReturn),
-    PaymentIntent(PaymentIntent),
-    PaymentMethod(PaymentMethod),
-    Payout(Payout),
-    Plan(Plan),
-    Product(Product),
-    Refund(Refund),
-    Review(Review),
-    Sku(Sku),
-    Subscription(Subscription),
-    Transfer(Transfer),
+/// Declares `EventObject` with one variant per `object` type, so that the list of known
+/// types used when deserializing is derived from the variants themselves.
+macro_rules! def_event_object {
+    ($($variant:ident($ty:ty) => $object:literal,)*) => {
+        #[derive(Clone, Debug, Deserialize, Serialize)]
+        #[serde(tag = "object")]
+        pub enum EventObject {
+            $(
+                #[serde(rename = $object)]
+                $variant($ty),
+            )*
+            /// An object type not yet supported by the library.
+            ///
+            /// `object` is the type reported by Stripe and `data` is the raw payload of the object.
+            /// This variant is only produced and serialized as part of an `EventData`.
+            #[serde(skip)]
+            Unknown {
+                object: String,
+                data: serde_json::Value,
+            },
+        }
+
+        impl EventObject {
+            /// Whether `object` is the type of one of the variants above, other than `Unknown`.
+            fn is_known_object(object: &str) -> bool {
+                matches!(object, $($object)|*)
+            }
+        }
+    };
+}
+
+def_event_object! {
+    Account(Account) => "account",
+    ApplicationFee(ApplicationFee) => "application_fee",
+    ApplicationFeeRefund(ApplicationFeeRefund) => "fee_refund",
+    Balance(Balance) => "balance",
+    BankAccount(BankAccount) => "bank_account",
+    Card(Card) => "card",
+    Charge(Charge) => "charge",
+    Customer(Customer) => "customer",
+    Dispute(Dispute) => "dispute",
+    CheckoutSession(CheckoutSession) => "checkout.session",
+    File(File) => "file",
+    Invoice(Invoice) => "invoice",
+    InvoiceItem(InvoiceItem) => "invoiceitem",
+    Order(Order) => "order",
+    OrderReturn(OrderReturn) => "order_return",
+    PaymentIntent(PaymentIntent) => "payment_intent",
+    PaymentMethod(PaymentMethod) => "payment_method",
+    Payout(Payout) => "payout",
+    Plan(Plan) => "plan",
+    Product(Product) => "product",
+    Refund(Refund) => "refund",
+    Review(Review) => "review",
+    Sku(Sku) => "sku",
+    Subscription(Subscription) => "subscription",
+    Transfer(Transfer) => "transfer",
+}
+
+/// Deserializes an `EventObject`, falling back to `EventObject::Unknown` when
+/// the `object` type is not one the library knows about.
+///
+/// Objects of a known type which fail to deserialize are still reported as errors.
+fn deserialize_event_object<'de, D>(deserializer: D) -> Result<EventObject, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    use serde::Deserialize;
+
+    let data = serde_json::Value::deserialize(deserializer)?;
+    let object = match data.get("object").and_then(|object| object.as_str()) {
+        Some(object) => object.to_string(),
+        None => return Err(D::Error::missing_field("object")),
+    };
+    if !EventObject::is_known_object(&object) {
+        return Ok(EventObject::Unknown { object, data });
+    }
+    EventObject::deserialize(data).map_err(D::Error::custom)
+}
+
+fn serialize_event_object<S>(object: &EventObject, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::Serialize;
+
+    match object {
+        EventObject::Unknown { data, .. } => data.serialize(serializer),
+        object => object.serialize(serializer),
+    }
 }
 
+/// How far the timestamp of a webhook signature may be from the current time, in seconds.
+#[cfg(feature = "webhook-events")]
+const DEFAULT_TOLERANCE: i64 = 300;
+
 #[cfg(feature = "webhook-events")]
 pub struct Webhook {
     current_timestamp: i64,
+    tolerance: i64,
 }
 
 #[cfg(feature = "webhook-events")]
 impl Webhook {
+    /// Verifies the `Stripe-Signature` header `sig` of a webhook request and parses its `payload`.
+    ///
+    /// The signature must have been made with the endpoint `secret` within the last five minutes.
     pub fn construct_event(payload: &str, sig: &str, secret: &str) -> Result<Event, WebhookError> {
-        Self { current_timestamp: Utc::now().timestamp() }.do_construct_event(payload, sig, secret)
+        Self::construct_event_with_tolerance(payload, sig, secret, DEFAULT_TOLERANCE)
+    }
+
+    /// Like `Webhook::construct_event`, but accepts signatures made up to `tolerance_secs` seconds
+    /// away from the current time.
+    pub fn construct_event_with_tolerance(
+        payload: &str,
+        sig: &str,
+        secret: &str,
+        tolerance_secs: i64,
+    ) -> Result<Event, WebhookError> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |now| now.as_secs());
+        Self { current_timestamp: now as i64, tolerance: tolerance_secs }
+            .do_construct_event(payload, sig, secret)
+    }
+
+    /// Like `Webhook::construct_event`, but checks the signature's timestamp against `now`
+    /// instead of the system time, e.g. to verify recorded events in tests.
+    pub fn construct_event_at(
+        payload: &str,
+        sig: &str,
+        secret: &str,
+        now: Timestamp,
+    ) -> Result<Event, WebhookError> {
+        Self { current_timestamp: now, tolerance: DEFAULT_TOLERANCE }
+            .do_construct_event(payload, sig, secret)
+    }
+
+    /// Builds a `Stripe-Signature` header for `payload`, as Stripe would send it when signing
+    /// the payload with the endpoint `secret` at `timestamp`.
+    ///
+    /// This is for unit testing webhook handlers without an event delivered by Stripe, like
+    /// `generateTestHeaderString` in stripe-node. `Webhook::construct_event` only accepts the
+    /// header if `timestamp` is within five minutes of the current time.
+    ///
+    /// ```
+    /// use stripe::{EventObject, EventType, Webhook};
+    ///
+    /// let payload = serde_json::json!({
+    ///     "id": "evt_123",
+    ///     "object": "event",
+    ///     "type": "customer.source.created",
+    ///     "created": 1533204620,
+    ///     "livemode": false,
+    ///     "data": { "object": { "id": "card_123", "object": "card" } }
+    /// })
+    /// .to_string();
+    /// let secret = "whsec_test";
+    /// let now = chrono::Utc::now().timestamp();
+    ///
+    /// let header = Webhook::generate_test_header(&payload, secret, now);
+    /// let event = Webhook::construct_event(&payload, &header, secret).unwrap();
+    /// assert_eq!(event.event_type, EventType::CustomerSourceCreated);
+    /// match event.data.object {
+    ///     EventObject::Card(card) => assert_eq!(card.id, "card_123"),
+    ///     other => panic!("unexpected object {:?}", other),
+    /// }
+    /// ```
+    pub fn generate_test_header(payload: &str, secret: &str, timestamp: Timestamp) -> String {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key size");
+        mac.update(format!("{}.{}", timestamp, payload).as_bytes());
+        format!("t={},v1={}", timestamp, hex::encode(mac.finalize().into_bytes()))
     }
 
     fn do_construct_event(
@@ -268,7 +503,7 @@ impl Webhook {
         let signed_payload = format!("{}{}{}", signature.t, ".", payload);
 
         // Compute HMAC with the SHA256 hash function, using endpoing secret as key
-        // and signed_payload string as the message.
+        // and signed_payload string as the message; `verify_slice` compares in constant time.
         let mut mac =
             Hmac::<Sha256>::new_from_slice(secret.as_bytes()).map_err(|_| WebhookError::BadKey)?;
         mac.update(signed_payload.as_bytes());
@@ -276,7 +511,7 @@ impl Webhook {
         mac.verify_slice(sig.as_slice()).map_err(|_| WebhookError::BadSignature)?;
 
         // Get current timestamp to compare to signature timestamp
-        if (self.current_timestamp - signature.t).abs() > 300 {
+        if (self.current_timestamp - signature.t).abs() > self.tolerance {
             return Err(WebhookError::BadTimestamp(signature.t));
         }
 
@@ -391,13 +626,87 @@ mod tests {
         let secret = "webhook_secret".to_string();
         let signature = format!("t={},v1=f0bdba6d4eacbd8ad8a3bbadd7248e633ec1477f7899c124c51b39405fa36613,v0=63f3a72374a733066c4be69ed7f8e5ac85c22c9f0a6a612ab9a025a9e4ee7eef", event_timestamp);
 
-        let webhook = super::Webhook { current_timestamp: event_timestamp };
-
-        let event = webhook
-            .do_construct_event(payload, &signature, &secret)
-            .expect("Failed to construct event");
+        let event =
+            super::Webhook::construct_event_at(payload, &signature, &secret, event_timestamp)
+                .expect("Failed to construct event");
 
         assert_eq!(event.event_type, super::EventType::InvoiceItemCreated);
         assert_eq!(event.id.to_string(), "evt_123");
+        assert_eq!(event.account.as_ref().map(|id| id.as_str()), Some("acct_123"));
+        assert_eq!(event.api_version.as_deref(), Some("2017-05-25"));
+        assert_eq!(event.pending_webhooks, 1);
+        let request = event.request.expect("request");
+        assert_eq!(request.id.as_deref(), Some("req_123"));
+        assert_eq!(request.idempotency_key.as_deref(), Some("idempotency-key-123"));
+    }
+
+    #[cfg(feature = "webhook-events")]
+    #[test]
+    fn test_webhook_construct_event_unknown_object() {
+        let payload = r#"{
+  "id": "evt_123",
+  "object": "event",
+  "created": 1533204620,
+  "data": {
+    "object": {
+      "id": "bnb_123",
+      "object": "brand_new_beta",
+      "nested": { "value": 1 }
+    }
+  },
+  "livemode": false,
+  "type": "account.updated"
+}"#;
+        let event_timestamp = 1533204620;
+        let secret = "webhook_secret";
+        let signature = super::Webhook::generate_test_header(payload, secret, event_timestamp);
+
+        let event =
+            super::Webhook::construct_event_at(payload, &signature, secret, event_timestamp)
+                .expect("Failed to construct event");
+
+        match event.data.object {
+            super::EventObject::Unknown { object, data } => {
+                assert_eq!(object, "brand_new_beta");
+                assert_eq!(data["nested"]["value"], 1);
+            }
+            other => panic!("expected an unknown object; was {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "webhook-events")]
+    #[test]
+    fn test_webhook_construct_event_tolerance() {
+        use super::Webhook;
+        use crate::error::WebhookError;
+
+        let payload = r#"{"id": "evt_123", "object": "event", "created": 1533204620, "data": {"object": {"id": "card_123", "object": "card"}}, "livemode": false, "type": "customer.source.created"}"#;
+        let secret = "webhook_secret";
+        let signed_at = 1533204620;
+        let signature = Webhook::generate_test_header(payload, secret, signed_at);
+
+        assert!(Webhook::construct_event_at(payload, &signature, secret, signed_at + 300).is_ok());
+        let err = Webhook::construct_event_at(payload, &signature, secret, signed_at + 301);
+        assert!(matches!(err, Err(WebhookError::BadTimestamp(t)) if t == signed_at));
+        let tampered = payload.replace("card_123", "card_456");
+        let err = Webhook::construct_event_at(&tampered, &signature, secret, signed_at);
+        assert!(matches!(err, Err(WebhookError::BadSignature)));
+
+        let now = chrono::Utc::now().timestamp();
+        let signature = Webhook::generate_test_header(payload, secret, now - 600);
+        assert!(Webhook::construct_event_with_tolerance(payload, &signature, secret, 900).is_ok());
+        let err = Webhook::construct_event(payload, &signature, secret);
+        assert!(matches!(err, Err(WebhookError::BadTimestamp(_))));
+    }
+
+    #[cfg(feature = "webhook-events")]
+    #[test]
+    fn test_webhook_generate_test_header() {
+        let payload = r#"{"id": "evt_123"}"#;
+        let header = super::Webhook::generate_test_header(payload, "webhook_secret", 1533204620);
+        assert_eq!(
+            header,
+            "t=1533204620,v1=27ce3bda7fbb0da4b0c4a4c514e58439cc8738dfedd588df6d46a7f611ba2237"
+        );
     }
 }
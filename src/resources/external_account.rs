@@ -0,0 +1,32 @@
+use crate::ids::ExternalAccountId;
+use crate::params::Object;
+use crate::resources::{BankAccount, Card};
+use serde_derive::{Deserialize, Serialize};
+
+/// An ExternalAccount is a bank account or debit card that funds can be paid out to.
+///
+/// This is the type of a connected account's `external_accounts` and of a payout's `destination`.
+///
+/// For more details see [https://stripe.com/docs/api/external_accounts](https://stripe.com/docs/api/external_accounts).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "object", rename_all = "snake_case")]
+pub enum ExternalAccount {
+    BankAccount(BankAccount),
+    Card(Card),
+}
+
+impl Object for ExternalAccount {
+    type Id = ExternalAccountId;
+    fn id(&self) -> Self::Id {
+        match self {
+            ExternalAccount::BankAccount(x) => ExternalAccountId::BankAccount(x.id()),
+            ExternalAccount::Card(x) => ExternalAccountId::Card(x.id()),
+        }
+    }
+    fn object(&self) -> &'static str {
+        match self {
+            ExternalAccount::BankAccount(x) => x.object(),
+            ExternalAccount::Card(x) => x.object(),
+        }
+    }
+}
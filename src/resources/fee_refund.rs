@@ -11,6 +11,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/fee_refunds/object](https://stripe.com/docs/api/fee_refunds/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct ApplicationFeeRefund {
     /// Unique identifier for the object.
     pub id: ApplicationFeeRefundId,
@@ -38,6 +39,7 @@ pub struct ApplicationFeeRefund {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 }
 
@@ -12,6 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/files/object](https://stripe.com/docs/api/files/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct File {
     /// Unique identifier for the object.
     pub id: FileId,
@@ -56,7 +57,7 @@ impl File {
     ///
     /// The files are returned sorted by creation date, with the most recently created files appearing first.
     pub fn list(client: &Client, params: ListFiles<'_>) -> Response<List<File>> {
-        client.get_query("/files", &params)
+        List::get_with_params(client, "/files", &params)
     }
 
     /// Retrieves the details of an existing file object.
@@ -12,6 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/file_links/object](https://stripe.com/docs/api/file_links/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct FileLink {
     /// Unique identifier for the object.
     pub id: FileLinkId,
@@ -37,6 +38,7 @@ pub struct FileLink {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
     /// The publicly accessible URL to download the file.
@@ -47,7 +49,7 @@ pub struct FileLink {
 impl FileLink {
     /// Returns a list of file links.
     pub fn list(client: &Client, params: ListFileLinks<'_>) -> Response<List<FileLink>> {
-        client.get_query("/file_links", &params)
+        List::get_with_params(client, "/file_links", &params)
     }
 
     /// Creates a new file link object.
@@ -0,0 +1,564 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::config::{Client, Response};
+use crate::ids::{
+    FinancialConnectionsAccountId, FinancialConnectionsAccountOwnerId,
+    FinancialConnectionsAccountOwnershipId, FinancialConnectionsTransactionId,
+};
+use crate::params::{Expand, List, Object, RangeQuery, Timestamp};
+use crate::resources::Currency;
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "BankConnectionsResourceLinkedAccount".
+///
+/// For more details see [https://stripe.com/docs/api/financial_connections/accounts/object](https://stripe.com/docs/api/financial_connections/accounts/object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct FinancialConnectionsAccount {
+    /// Unique identifier for the object.
+    pub id: FinancialConnectionsAccountId,
+
+    /// The account holder that this account belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder: Option<FinancialConnectionsAccountHolder>,
+
+    /// The state of the most recent attempt to refresh the account balance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance_refresh: Option<FinancialConnectionsAccountRefresh>,
+
+    /// The type of the account.
+    pub category: FinancialConnectionsAccountCategory,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// A human-readable name that has been assigned to this account, either by the account holder or by the institution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// The name of the institution that holds this account.
+    pub institution_name: String,
+
+    /// The last 4 digits of the account number.
+    ///
+    /// If present, this will be 4 numeric characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last4: Option<String>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// The most recent information about the account's owners.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ownership: Option<FinancialConnectionsAccountOwnershipId>,
+
+    /// The state of the most recent attempt to refresh the account owners.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ownership_refresh: Option<FinancialConnectionsAccountRefresh>,
+
+    /// The status of the link to the account.
+    pub status: FinancialConnectionsAccountStatus,
+
+    /// The state of the most recent attempt to refresh the account transactions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_refresh: Option<FinancialConnectionsAccountRefresh>,
+}
+
+impl FinancialConnectionsAccount {
+    /// Retrieves the details of an Financial Connections `Account`.
+    pub fn retrieve(
+        client: &Client,
+        id: &FinancialConnectionsAccountId,
+        expand: &[&str],
+    ) -> Response<FinancialConnectionsAccount> {
+        client.get_query(&format!("/financial_connections/accounts/{}", id), &Expand { expand })
+    }
+
+    /// Refreshes the data associated with a Financial Connections `Account`.
+    pub fn refresh(
+        client: &Client,
+        id: &FinancialConnectionsAccountId,
+        params: RefreshFinancialConnectionsAccount<'_>,
+    ) -> Response<FinancialConnectionsAccount> {
+        client.post_form(&format!("/financial_connections/accounts/{}/refresh", id), &params)
+    }
+
+    /// Disables your access to a Financial Connections `Account`.
+    ///
+    /// You will no longer be able to access data associated with the account (e.g. balances, transactions).
+    pub fn disconnect(
+        client: &Client,
+        id: &FinancialConnectionsAccountId,
+    ) -> Response<FinancialConnectionsAccount> {
+        client.post(&format!("/financial_connections/accounts/{}/disconnect", id))
+    }
+
+    /// Lists all owners for a given `Account`.
+    pub fn list_owners(
+        client: &Client,
+        id: &FinancialConnectionsAccountId,
+        params: ListFinancialConnectionsAccountOwners<'_>,
+    ) -> Response<List<FinancialConnectionsAccountOwner>> {
+        List::get_with_params(
+            client,
+            &format!("/financial_connections/accounts/{}/owners", id),
+            &params,
+        )
+    }
+
+    /// Returns a list of Financial Connections `Transaction` objects of the given `Account`.
+    pub fn list_transactions(
+        client: &Client,
+        id: &FinancialConnectionsAccountId,
+        params: ListFinancialConnectionsTransactions<'_>,
+    ) -> Response<List<FinancialConnectionsTransaction>> {
+        #[derive(Serialize)]
+        struct AccountTransactions<'a> {
+            account: &'a FinancialConnectionsAccountId,
+            #[serde(flatten)]
+            params: ListFinancialConnectionsTransactions<'a>,
+        }
+
+        List::get_with_params(
+            client,
+            "/financial_connections/transactions",
+            &AccountTransactions { account: id, params },
+        )
+    }
+}
+
+impl Object for FinancialConnectionsAccount {
+    type Id = FinancialConnectionsAccountId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "financial_connections.account"
+    }
+}
+
+/// The resource representing a Stripe "BankConnectionsResourceOwner".
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct FinancialConnectionsAccountOwner {
+    /// Unique identifier for the object.
+    pub id: FinancialConnectionsAccountOwnerId,
+
+    /// The email address of the owner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// The full name of the owner.
+    pub name: String,
+
+    /// The ownership object that this owner belongs to.
+    pub ownership: FinancialConnectionsAccountOwnershipId,
+
+    /// The raw phone number of the owner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+
+    /// The raw physical address of the owner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_address: Option<String>,
+
+    /// The timestamp of the refresh that updated this owner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refreshed_at: Option<Timestamp>,
+}
+
+impl Object for FinancialConnectionsAccountOwner {
+    type Id = FinancialConnectionsAccountOwnerId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "financial_connections.account_owner"
+    }
+}
+
+/// The resource representing a Stripe "BankConnectionsResourceTransaction".
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct FinancialConnectionsTransaction {
+    /// Unique identifier for the object.
+    pub id: FinancialConnectionsTransactionId,
+
+    /// The ID of the Financial Connections Account this transaction belongs to.
+    pub account: FinancialConnectionsAccountId,
+
+    /// The amount of this transaction, in cents (or local equivalent).
+    pub amount: i64,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// The description of this transaction.
+    pub description: String,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// The status of the transaction.
+    pub status: FinancialConnectionsTransactionStatus,
+
+    /// Time at which the transaction was transacted.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub transacted_at: Timestamp,
+
+    /// The token of the transaction refresh that last updated or created this transaction.
+    pub transaction_refresh: String,
+
+    /// Time at which the object was last updated.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub updated: Timestamp,
+}
+
+impl Object for FinancialConnectionsTransaction {
+    type Id = FinancialConnectionsTransactionId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "financial_connections.transaction"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FinancialConnectionsAccountHolder {
+    /// The ID of the Stripe account this account belongs to.
+    ///
+    /// Should only be present if `account_holder.type` is `account`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+
+    /// ID of the Stripe customer this account belongs to.
+    ///
+    /// Present if and only if `account_holder.type` is `customer`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<String>,
+
+    /// Type of account holder that this account belongs to.
+    #[serde(rename = "type")]
+    pub type_: FinancialConnectionsAccountHolderType,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FinancialConnectionsAccountRefresh {
+    /// The time at which the last refresh attempt was initiated.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub last_attempted_at: Timestamp,
+
+    /// The status of the last refresh attempt.
+    pub status: FinancialConnectionsAccountRefreshStatus,
+}
+
+/// The parameters for `FinancialConnectionsAccount::refresh`.
+#[derive(Clone, Debug, Serialize)]
+pub struct RefreshFinancialConnectionsAccount<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The list of account features that you would like to refresh.
+    pub features: Vec<RefreshableFeature>,
+}
+
+impl<'a> RefreshFinancialConnectionsAccount<'a> {
+    pub fn new(features: Vec<RefreshableFeature>) -> Self {
+        RefreshFinancialConnectionsAccount { expand: Default::default(), features }
+    }
+}
+
+/// The parameters for `FinancialConnectionsAccount::list_owners`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListFinancialConnectionsAccountOwners<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<FinancialConnectionsAccountOwnerId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// The ID of the ownership object to fetch owners from.
+    pub ownership: FinancialConnectionsAccountOwnershipId,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<FinancialConnectionsAccountOwnerId>,
+}
+
+impl<'a> ListFinancialConnectionsAccountOwners<'a> {
+    pub fn new(ownership: FinancialConnectionsAccountOwnershipId) -> Self {
+        ListFinancialConnectionsAccountOwners {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            ownership,
+            starting_after: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `FinancialConnectionsAccount::list_transactions`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListFinancialConnectionsTransactions<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<FinancialConnectionsTransactionId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<FinancialConnectionsTransactionId>,
+
+    /// A filter on the list based on the object `transacted_at` field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transacted_at: Option<RangeQuery<Timestamp>>,
+
+    /// A filter on the list based on the object `transaction_refresh` field.
+    ///
+    /// The value can be a dictionary with the following options:.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_refresh: Option<ListFinancialConnectionsTransactionsTransactionRefresh>,
+}
+
+impl<'a> ListFinancialConnectionsTransactions<'a> {
+    pub fn new() -> Self {
+        ListFinancialConnectionsTransactions {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+            transacted_at: Default::default(),
+            transaction_refresh: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ListFinancialConnectionsTransactionsTransactionRefresh {
+    /// Return results where the transactions were created or updated by a refresh that took place after this refresh (non-inclusive).
+    pub after: String,
+}
+
+/// An enum representing the possible values of an `FinancialConnectionsAccountHolder`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FinancialConnectionsAccountHolderType {
+    Account,
+    Customer,
+}
+
+impl FinancialConnectionsAccountHolderType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FinancialConnectionsAccountHolderType::Account => "account",
+            FinancialConnectionsAccountHolderType::Customer => "customer",
+        }
+    }
+}
+
+impl AsRef<str> for FinancialConnectionsAccountHolderType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FinancialConnectionsAccountHolderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `FinancialConnectionsAccount`'s `category` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FinancialConnectionsAccountCategory {
+    Cash,
+    Credit,
+    Investment,
+}
+
+impl FinancialConnectionsAccountCategory {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FinancialConnectionsAccountCategory::Cash => "cash",
+            FinancialConnectionsAccountCategory::Credit => "credit",
+            FinancialConnectionsAccountCategory::Investment => "investment",
+        }
+    }
+}
+
+impl AsRef<str> for FinancialConnectionsAccountCategory {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FinancialConnectionsAccountCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `FinancialConnectionsAccountRefresh`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FinancialConnectionsAccountRefreshStatus {
+    Failed,
+    Pending,
+    Succeeded,
+}
+
+impl FinancialConnectionsAccountRefreshStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FinancialConnectionsAccountRefreshStatus::Failed => "failed",
+            FinancialConnectionsAccountRefreshStatus::Pending => "pending",
+            FinancialConnectionsAccountRefreshStatus::Succeeded => "succeeded",
+        }
+    }
+}
+
+impl AsRef<str> for FinancialConnectionsAccountRefreshStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FinancialConnectionsAccountRefreshStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `FinancialConnectionsAccount`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FinancialConnectionsAccountStatus {
+    Active,
+    Disconnected,
+    Inactive,
+}
+
+impl FinancialConnectionsAccountStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FinancialConnectionsAccountStatus::Active => "active",
+            FinancialConnectionsAccountStatus::Disconnected => "disconnected",
+            FinancialConnectionsAccountStatus::Inactive => "inactive",
+        }
+    }
+}
+
+impl AsRef<str> for FinancialConnectionsAccountStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FinancialConnectionsAccountStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `FinancialConnectionsTransaction`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FinancialConnectionsTransactionStatus {
+    Pending,
+    Posted,
+    Void,
+}
+
+impl FinancialConnectionsTransactionStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FinancialConnectionsTransactionStatus::Pending => "pending",
+            FinancialConnectionsTransactionStatus::Posted => "posted",
+            FinancialConnectionsTransactionStatus::Void => "void",
+        }
+    }
+}
+
+impl AsRef<str> for FinancialConnectionsTransactionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FinancialConnectionsTransactionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `RefreshFinancialConnectionsAccount`'s `features` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RefreshableFeature {
+    Balance,
+    Ownership,
+    Transactions,
+}
+
+impl RefreshableFeature {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RefreshableFeature::Balance => "balance",
+            RefreshableFeature::Ownership => "ownership",
+            RefreshableFeature::Transactions => "transactions",
+        }
+    }
+}
+
+impl AsRef<str> for RefreshableFeature {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for RefreshableFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
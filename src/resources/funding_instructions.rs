@@ -0,0 +1,227 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::config::{Client, Response};
+use crate::ids::CustomerId;
+use crate::params::Expand;
+use crate::resources::{
+    Currency, FundingInstructionsBankTransferFinancialAddress,
+    FundingInstructionsBankTransferFinancialAddressType,
+};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "FundingInstructions".
+///
+/// Each customer has a [`balance`](https://stripe.com/docs/api/customers/object#customer_object-balance) that is automatically applied to future invoices and payments using the `customer_balance` payment method.
+/// Customers can fund this balance by initiating a bank transfer to any account in the `financial_addresses` field.
+///
+/// For more details see [https://stripe.com/docs/api/customers/create_funding_instructions](https://stripe.com/docs/api/customers/create_funding_instructions).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FundingInstructions {
+    pub bank_transfer: FundingInstructionsBankTransfer,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// The `funding_type` of the returned instructions.
+    pub funding_type: FundingType,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+}
+
+impl FundingInstructions {
+    /// Retrieve funding instructions for a customer cash balance.
+    ///
+    /// If funding instructions do not yet exist for the customer, new funding instructions will be created.
+    /// If funding instructions have already been created for a given customer, the same funding instructions will be retrieved.
+    /// In other words, we will return the same funding instructions each time.
+    pub fn create(
+        client: &Client,
+        customer_id: &CustomerId,
+        params: CreateFundingInstructions<'_>,
+    ) -> Response<FundingInstructions> {
+        client.post_form(&format!("/customers/{}/funding_instructions", customer_id), &params)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FundingInstructionsBankTransfer {
+    /// The country of the bank account to fund.
+    pub country: String,
+
+    /// A list of financial addresses that can be used to fund a particular balance.
+    #[serde(default)]
+    pub financial_addresses: Vec<FundingInstructionsBankTransferFinancialAddress>,
+
+    /// The bank_transfer type.
+    #[serde(rename = "type")]
+    pub type_: BankTransferType,
+}
+
+/// The parameters for `FundingInstructions::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateFundingInstructions<'a> {
+    /// Additional parameters for `bank_transfer` funding types.
+    pub bank_transfer: BankTransferParams,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The `funding_type` to get the instructions for.
+    pub funding_type: FundingType,
+}
+
+impl<'a> CreateFundingInstructions<'a> {
+    pub fn new(bank_transfer: BankTransferParams, currency: Currency) -> Self {
+        CreateFundingInstructions {
+            bank_transfer,
+            currency,
+            expand: Default::default(),
+            funding_type: FundingType::BankTransfer,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BankTransferParams {
+    /// Configuration for eu_bank_transfer funding type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eu_bank_transfer: Option<EuBankTransferParams>,
+
+    /// List of address types that should be returned in the financial_addresses response.
+    ///
+    /// If not specified, all valid types will be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_address_types: Option<Vec<FundingInstructionsBankTransferFinancialAddressType>>,
+
+    /// The type of the `bank_transfer`.
+    #[serde(rename = "type")]
+    pub type_: BankTransferType,
+}
+
+impl BankTransferParams {
+    pub fn new(type_: BankTransferType) -> Self {
+        BankTransferParams {
+            eu_bank_transfer: Default::default(),
+            requested_address_types: Default::default(),
+            type_,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EuBankTransferParams {
+    /// The desired country code of the bank account information.
+    ///
+    /// Permitted values include: `BE`, `DE`, `ES`, `FR`, `IE`, or `NL`.
+    pub country: String,
+}
+
+/// An enum representing the possible values of an `FundingInstructionsBankTransfer`'s `type` field.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum BankTransferType {
+    EuBankTransfer,
+    GbBankTransfer,
+    JpBankTransfer,
+    MxBankTransfer,
+    UsBankTransfer,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
+}
+
+impl BankTransferType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            BankTransferType::EuBankTransfer => "eu_bank_transfer",
+            BankTransferType::GbBankTransfer => "gb_bank_transfer",
+            BankTransferType::JpBankTransfer => "jp_bank_transfer",
+            BankTransferType::MxBankTransfer => "mx_bank_transfer",
+            BankTransferType::UsBankTransfer => "us_bank_transfer",
+            BankTransferType::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for BankTransferType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "eu_bank_transfer" => BankTransferType::EuBankTransfer,
+            "gb_bank_transfer" => BankTransferType::GbBankTransfer,
+            "jp_bank_transfer" => BankTransferType::JpBankTransfer,
+            "mx_bank_transfer" => BankTransferType::MxBankTransfer,
+            "us_bank_transfer" => BankTransferType::UsBankTransfer,
+            _ => BankTransferType::Other(value),
+        }
+    }
+}
+
+impl AsRef<str> for BankTransferType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BankTransferType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for BankTransferType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BankTransferType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(BankTransferType::from(s))
+    }
+}
+
+/// An enum representing the possible values of an `FundingInstructions`'s `funding_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FundingType {
+    BankTransfer,
+}
+
+impl FundingType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FundingType::BankTransfer => "bank_transfer",
+        }
+    }
+}
+
+impl AsRef<str> for FundingType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FundingType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
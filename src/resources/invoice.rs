@@ -4,10 +4,11 @@
 
 use crate::config::{Client, Response};
 use crate::ids::{CustomerId, InvoiceId, SubscriptionId};
-use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
+use crate::params::{Deleted, Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
     Address, Charge, Currency, CustomField, Customer, Discount, InvoiceLineItem, PaymentIntent,
-    PaymentMethod, PaymentSource, Shipping, Subscription, TaxRate,
+    PaymentMethod, PaymentSource, Shipping, Subscription, SubscriptionPaymentMethodOptions,
+    SubscriptionPaymentMethodType, TaxRate,
 };
 use serde_derive::{Deserialize, Serialize};
 
@@ -15,6 +16,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/invoices/object](https://stripe.com/docs/api/invoices/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Invoice {
     /// Unique identifier for the object.
     #[serde(default = "InvoiceId::none")]
@@ -67,6 +69,10 @@ pub struct Invoice {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub auto_advance: Option<bool>,
 
+    /// Settings and latest results for automatic tax lookup for this invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<InvoiceAutomaticTax>,
+
     /// Indicates the reason why the invoice was created.
     ///
     /// `subscription_cycle` indicates an invoice created by a subscription advancing into a new period.
@@ -192,6 +198,13 @@ pub struct Invoice {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub discount: Option<Discount>,
 
+    /// The discounts applied to the invoice.
+    ///
+    /// Line item discounts are applied before invoice discounts.
+    /// Use `expand[]=discounts` to expand each discount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<Expandable<Discount>>>,
+
     /// The date on which payment for this invoice is due.
     ///
     /// This value will be `null` for invoices where `collection_method=charge_automatically`.
@@ -352,7 +365,7 @@ impl Invoice {
     ///
     /// The invoices are returned sorted by creation date, with the most recently created invoices appearing first.
     pub fn list(client: &Client, params: ListInvoices<'_>) -> Response<List<Invoice>> {
-        client.get_query("/invoices", &params)
+        List::get_with_params(client, "/invoices", &params)
     }
 
     /// This endpoint creates a draft invoice for a given customer.
@@ -366,6 +379,21 @@ impl Invoice {
     pub fn retrieve(client: &Client, id: &InvoiceId, expand: &[&str]) -> Response<Invoice> {
         client.get_query(&format!("/invoices/{}", id), &Expand { expand })
     }
+
+    /// Draft invoices are fully editable.
+    ///
+    /// Once an invoice is [finalized](https://stripe.com/docs/billing/invoices/workflow#finalized), monetary values, as well as `collection_method`, become uneditable.  If you would like to stop the Stripe Billing engine from automatically finalizing, reattempting payments on, sending reminders for, or [automatically reconciling](https://stripe.com/docs/billing/invoices/reconciliation) invoices, pass `auto_advance=false`.
+    pub fn update(client: &Client, id: &InvoiceId, params: UpdateInvoice<'_>) -> Response<Invoice> {
+        client.post_form(&format!("/invoices/{}", id), &params)
+    }
+
+    /// Permanently deletes a draft invoice.
+    ///
+    /// This cannot be undone.
+    /// Attempts to delete invoices that are no longer in a draft state will fail; once an invoice has been finalized, it must be [voided](https://stripe.com/docs/api#void_invoice).
+    pub fn delete(client: &Client, id: &InvoiceId) -> Response<Deleted<InvoiceId>> {
+        client.delete(&format!("/invoices/{}", id))
+    }
 }
 
 impl Object for Invoice {
@@ -378,6 +406,16 @@ impl Object for Invoice {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InvoiceAutomaticTax {
+    /// Whether Stripe automatically computes tax on this invoice.
+    pub enabled: bool,
+
+    /// The status of the most recent automated tax calculation for this invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<InvoiceAutomaticTaxStatus>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InvoiceSettingCustomField {
     /// The name of the custom field.
@@ -406,12 +444,14 @@ pub struct InvoiceThresholdReason {
     pub amount_gte: Option<i64>,
 
     /// Indicates which line items triggered a threshold invoice.
+    #[serde(default)]
     pub item_reasons: Vec<InvoiceItemThresholdReason>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InvoiceItemThresholdReason {
     /// The IDs of the line items that triggered the threshold invoice.
+    #[serde(default)]
     pub line_item_ids: Vec<String>,
 
     /// The quantity threshold boundary that applied to the given line item.
@@ -464,6 +504,10 @@ pub struct CreateInvoice<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub auto_advance: Option<bool>,
 
+    /// Settings for automatic tax lookup for this invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<CreateInvoiceAutomaticTax>,
+
     /// Either `charge_automatically`, or `send_invoice`.
     ///
     /// When charging automatically, Stripe will attempt to pay this invoice using the default source attached to the customer.
@@ -472,6 +516,12 @@ pub struct CreateInvoice<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub collection_method: Option<CollectionMethod>,
 
+    /// The currency to create this invoice in.
+    ///
+    /// Defaults to that of `customer` if not specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
     /// A list of up to 4 custom fields to be displayed on the invoice.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_fields: Option<Vec<CustomField>>,
@@ -510,6 +560,12 @@ pub struct CreateInvoice<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
 
+    /// The coupons to redeem into discounts for the invoice.
+    ///
+    /// If not specified, inherits the discount from the invoice's customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<CreateInvoiceDiscounts>>,
+
     /// The date on which payment for this invoice is due.
     ///
     /// Valid only for invoices where `collection_method=send_invoice`.
@@ -524,6 +580,13 @@ pub struct CreateInvoice<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub footer: Option<&'a str>,
 
+    /// Revise an existing invoice.
+    ///
+    /// The new invoice will be created in `status=draft`.
+    /// See the [revision documentation](https://stripe.com/docs/invoicing/invoice-revisions) for more details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_invoice: Option<FromInvoice>,
+
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
@@ -532,6 +595,29 @@ pub struct CreateInvoice<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
 
+    /// The account (if any) for which the funds of the invoice payment are intended.
+    ///
+    /// If set, the invoice will be presented with the branding and support information of the specified account.
+    /// See the [Invoices with Connect](https://stripe.com/docs/billing/invoices/connect) documentation for details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<&'a str>,
+
+    /// Configuration settings for the PaymentIntent that is generated when the invoice is finalized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_settings: Option<CreateInvoicePaymentSettings>,
+
+    /// How to handle pending invoice items on invoice creation.
+    ///
+    /// One of `include` or `exclude`.
+    /// `include` will include any pending invoice items, and will create an empty draft invoice if no pending invoice items exist.
+    /// `exclude` will always create an empty invoice draft regardless if there are pending invoice items or not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_invoice_items_behavior: Option<InvoicePendingInvoiceItemsBehavior>,
+
+    /// Options for invoice PDF rendering.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendering: Option<CreateInvoiceRendering>,
+
     /// Extra information about a charge for the customer's credit card statement.
     ///
     /// It must contain at least one letter.
@@ -559,7 +645,9 @@ impl<'a> CreateInvoice<'a> {
         CreateInvoice {
             application_fee_amount: Default::default(),
             auto_advance: Default::default(),
+            automatic_tax: Default::default(),
             collection_method: Default::default(),
+            currency: Default::default(),
             custom_fields: Default::default(),
             customer,
             days_until_due: Default::default(),
@@ -567,10 +655,16 @@ impl<'a> CreateInvoice<'a> {
             default_source: Default::default(),
             default_tax_rates: Default::default(),
             description: Default::default(),
+            discounts: Default::default(),
             due_date: Default::default(),
             expand: Default::default(),
             footer: Default::default(),
+            from_invoice: Default::default(),
             metadata: Default::default(),
+            on_behalf_of: Default::default(),
+            payment_settings: Default::default(),
+            pending_invoice_items_behavior: Default::default(),
+            rendering: Default::default(),
             statement_descriptor: Default::default(),
             subscription: Default::default(),
             tax_percent: Default::default(),
@@ -649,6 +743,228 @@ impl<'a> ListInvoices<'a> {
     }
 }
 
+/// The parameters for `Invoice::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateInvoice<'a> {
+    /// A fee in %s that will be applied to the invoice and transferred to the application owner's Stripe account.
+    ///
+    /// The request must be made with an OAuth key or the Stripe-Account header in order to take an application fee.
+    /// For more information, see the application fees [documentation](https://stripe.com/docs/connect/subscriptions#invoices).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<i64>,
+
+    /// Controls whether Stripe will perform [automatic collection](https://stripe.com/docs/billing/invoices/workflow/#auto_advance) of the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_advance: Option<bool>,
+
+    /// Settings for automatic tax lookup for this invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<UpdateInvoiceAutomaticTax>,
+
+    /// Either `charge_automatically` or `send_invoice`.
+    ///
+    /// This field can be updated only on `draft` invoices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_method: Option<CollectionMethod>,
+
+    /// A list of up to 4 custom fields to be displayed on the invoice.
+    ///
+    /// If a value for `custom_fields` is specified, the list specified will replace the existing custom field list on this invoice.
+    /// Pass an empty string to remove previously-defined fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CustomField>>,
+
+    /// The number of days from which the invoice is created until it is due.
+    ///
+    /// Only valid for invoices where `collection_method=send_invoice`.
+    /// This field can only be updated on `draft` invoices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_until_due: Option<u32>,
+
+    /// ID of the default payment method for the invoice.
+    ///
+    /// It must belong to the customer associated with the invoice.
+    /// If not set, defaults to the subscription's default payment method, if any, or to the default payment method in the customer's invoice settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_payment_method: Option<&'a str>,
+
+    /// ID of the default payment source for the invoice.
+    ///
+    /// It must belong to the customer associated with the invoice and be in a chargeable state.
+    /// If not set, defaults to the subscription's default source, if any, or to the customer's default source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_source: Option<&'a str>,
+
+    /// The tax rates that will apply to any line item that does not have `tax_rates` set.
+    ///
+    /// Pass an empty string to remove previously-defined tax rates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<String>>,
+
+    /// An arbitrary string attached to the object.
+    ///
+    /// Often useful for displaying to users.
+    /// Referenced as 'memo' in the Dashboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// The coupons to redeem into discounts for the invoice.
+    ///
+    /// If not specified, inherits the discount from the invoice's customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<UpdateInvoiceDiscounts>>,
+
+    /// The date on which payment for this invoice is due.
+    ///
+    /// Only valid for invoices where `collection_method=send_invoice`.
+    /// This field can only be updated on `draft` invoices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<Timestamp>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Footer to be displayed on the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<&'a str>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Extra information about a charge for the customer's credit card statement.
+    ///
+    /// It must contain at least one letter.
+    /// If not specified and this invoice is part of a subscription, the default `statement_descriptor` will be set to the first subscription item's product's `statement_descriptor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+
+    /// The percent tax rate applied to the invoice, represented as a non-negative decimal number (with at most four decimal places) between 0 and 100.
+    ///
+    /// To unset a previously-set value, pass an empty string.
+    /// This field can be updated only on `draft` invoices.
+    /// This field has been deprecated and will be removed in a future API version, for further information view the [migration docs](https://stripe.com/docs/billing/migration/taxes) for `tax_rates`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_percent: Option<f64>,
+}
+
+impl<'a> UpdateInvoice<'a> {
+    pub fn new() -> Self {
+        UpdateInvoice {
+            application_fee_amount: Default::default(),
+            auto_advance: Default::default(),
+            automatic_tax: Default::default(),
+            collection_method: Default::default(),
+            custom_fields: Default::default(),
+            days_until_due: Default::default(),
+            default_payment_method: Default::default(),
+            default_source: Default::default(),
+            default_tax_rates: Default::default(),
+            description: Default::default(),
+            discounts: Default::default(),
+            due_date: Default::default(),
+            expand: Default::default(),
+            footer: Default::default(),
+            metadata: Default::default(),
+            statement_descriptor: Default::default(),
+            tax_percent: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateInvoiceAutomaticTax {
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateInvoiceDiscounts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promotion_code: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateInvoicePaymentSettings {
+    /// ID of the mandate to be used for this invoice.
+    ///
+    /// It must correspond to the payment method used to pay the invoice, including the invoice's default_payment_method or default_source, if set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_mandate: Option<String>,
+
+    /// Payment-method-specific configuration to provide to the invoice’s PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_options: Option<SubscriptionPaymentMethodOptions>,
+
+    /// The list of payment method types (e.g.
+    ///
+    /// card) to provide to the invoice’s PaymentIntent.
+    /// If not set, Stripe attempts to automatically determine the types to use by looking at the invoice’s default payment method, the subscription’s default payment method, the customer’s default payment method, and your [invoice template settings](https://dashboard.stripe.com/settings/billing/invoice).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_types: Option<Vec<SubscriptionPaymentMethodType>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateInvoiceRendering {
+    /// How line-item prices and amounts will be displayed with respect to tax on invoice PDFs.
+    ///
+    /// One of `exclude_tax` or `include_inclusive_tax`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_tax_display: Option<InvoiceRenderingAmountTaxDisplay>,
+
+    /// Invoice pdf rendering options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pdf: Option<CreateInvoiceRenderingPdf>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FromInvoice {
+    /// The relation between the new invoice and the original invoice.
+    ///
+    /// Currently, only 'revision' is permitted.
+    pub action: FromInvoiceAction,
+
+    /// The `id` of the invoice that will be cloned.
+    pub invoice: InvoiceId,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateInvoiceAutomaticTax {
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateInvoiceDiscounts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promotion_code: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateInvoiceRenderingPdf {
+    /// Page size for invoice PDF.
+    ///
+    /// Can be set to `a4`, `letter`, or `auto`.
+    /// If set to `auto`, invoice PDF page size defaults to `a4` for customers with Japanese locale and `letter` for customers with other locales.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<InvoiceRenderingPdfPageSize>,
+}
+
 /// An enum representing the possible values of an `Invoice`'s `collection_method` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -678,6 +994,64 @@ impl std::fmt::Display for CollectionMethod {
     }
 }
 
+/// An enum representing the possible values of an `FromInvoice`'s `action` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FromInvoiceAction {
+    Revision,
+}
+
+impl FromInvoiceAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FromInvoiceAction::Revision => "revision",
+        }
+    }
+}
+
+impl AsRef<str> for FromInvoiceAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FromInvoiceAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `InvoiceAutomaticTax`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceAutomaticTaxStatus {
+    Complete,
+    Failed,
+    RequiresLocationInputs,
+}
+
+impl InvoiceAutomaticTaxStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InvoiceAutomaticTaxStatus::Complete => "complete",
+            InvoiceAutomaticTaxStatus::Failed => "failed",
+            InvoiceAutomaticTaxStatus::RequiresLocationInputs => "requires_location_inputs",
+        }
+    }
+}
+
+impl AsRef<str> for InvoiceAutomaticTaxStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for InvoiceAutomaticTaxStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `Invoice`'s `billing_reason` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -752,6 +1126,97 @@ impl std::fmt::Display for InvoiceCustomerTaxExempt {
     }
 }
 
+/// An enum representing the possible values of an `CreateInvoice`'s `pending_invoice_items_behavior` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoicePendingInvoiceItemsBehavior {
+    Exclude,
+    Include,
+    IncludeAndRequire,
+}
+
+impl InvoicePendingInvoiceItemsBehavior {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InvoicePendingInvoiceItemsBehavior::Exclude => "exclude",
+            InvoicePendingInvoiceItemsBehavior::Include => "include",
+            InvoicePendingInvoiceItemsBehavior::IncludeAndRequire => "include_and_require",
+        }
+    }
+}
+
+impl AsRef<str> for InvoicePendingInvoiceItemsBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for InvoicePendingInvoiceItemsBehavior {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CreateInvoiceRendering`'s `amount_tax_display` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceRenderingAmountTaxDisplay {
+    ExcludeTax,
+    IncludeInclusiveTax,
+}
+
+impl InvoiceRenderingAmountTaxDisplay {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InvoiceRenderingAmountTaxDisplay::ExcludeTax => "exclude_tax",
+            InvoiceRenderingAmountTaxDisplay::IncludeInclusiveTax => "include_inclusive_tax",
+        }
+    }
+}
+
+impl AsRef<str> for InvoiceRenderingAmountTaxDisplay {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for InvoiceRenderingAmountTaxDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `CreateInvoiceRenderingPdf`'s `page_size` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceRenderingPdfPageSize {
+    A4,
+    Auto,
+    Letter,
+}
+
+impl InvoiceRenderingPdfPageSize {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InvoiceRenderingPdfPageSize::A4 => "a4",
+            InvoiceRenderingPdfPageSize::Auto => "auto",
+            InvoiceRenderingPdfPageSize::Letter => "letter",
+        }
+    }
+}
+
+impl AsRef<str> for InvoiceRenderingPdfPageSize {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for InvoiceRenderingPdfPageSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `Invoice`'s `status` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -825,8 +1290,7 @@ impl std::fmt::Display for InvoiceStatusFilter {
 }
 
 /// An enum representing the possible values of an `InvoicesResourceInvoiceTaxId`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum TaxIdType {
     AuAbn,
     BrCnpj,
@@ -854,10 +1318,15 @@ pub enum TaxIdType {
     Unknown,
     UsEin,
     ZaVat,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
 }
 
 impl TaxIdType {
-    pub fn as_str(self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             TaxIdType::AuAbn => "au_abn",
             TaxIdType::BrCnpj => "br_cnpj",
@@ -885,6 +1354,41 @@ impl TaxIdType {
             TaxIdType::Unknown => "unknown",
             TaxIdType::UsEin => "us_ein",
             TaxIdType::ZaVat => "za_vat",
+            TaxIdType::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for TaxIdType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "au_abn" => TaxIdType::AuAbn,
+            "br_cnpj" => TaxIdType::BrCnpj,
+            "br_cpf" => TaxIdType::BrCpf,
+            "ca_bn" => TaxIdType::CaBn,
+            "ca_qst" => TaxIdType::CaQst,
+            "ch_vat" => TaxIdType::ChVat,
+            "es_cif" => TaxIdType::EsCif,
+            "eu_vat" => TaxIdType::EuVat,
+            "hk_br" => TaxIdType::HkBr,
+            "in_gst" => TaxIdType::InGst,
+            "jp_cn" => TaxIdType::JpCn,
+            "kr_brn" => TaxIdType::KrBrn,
+            "li_uid" => TaxIdType::LiUid,
+            "mx_rfc" => TaxIdType::MxRfc,
+            "my_itn" => TaxIdType::MyItn,
+            "my_sst" => TaxIdType::MySst,
+            "no_vat" => TaxIdType::NoVat,
+            "nz_gst" => TaxIdType::NzGst,
+            "ru_inn" => TaxIdType::RuInn,
+            "sg_gst" => TaxIdType::SgGst,
+            "sg_uen" => TaxIdType::SgUen,
+            "th_vat" => TaxIdType::ThVat,
+            "tw_vat" => TaxIdType::TwVat,
+            "unknown" => TaxIdType::Unknown,
+            "us_ein" => TaxIdType::UsEin,
+            "za_vat" => TaxIdType::ZaVat,
+            _ => TaxIdType::Other(value),
         }
     }
 }
@@ -900,3 +1404,22 @@ impl std::fmt::Display for TaxIdType {
         self.as_str().fmt(f)
     }
 }
+
+impl serde::Serialize for TaxIdType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TaxIdType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(TaxIdType::from(s))
+    }
+}
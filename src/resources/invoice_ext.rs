@@ -1,12 +1,18 @@
 use crate::config::{Client, Response};
-use crate::ids::{CouponId, CustomerId, InvoiceId, PlanId, SubscriptionId, SubscriptionItemId};
-use crate::params::{Metadata, Timestamp};
+use crate::ids::{
+    CouponId, CustomerId, InvoiceId, MandateId, PaymentMethodId, PaymentSourceId, PlanId,
+    SubscriptionId, SubscriptionItemId,
+};
+use crate::params::{Expand, Metadata, Timestamp};
 use crate::resources::{CollectionMethod, Invoice};
 use serde_derive::Serialize;
 
 #[deprecated(since = "0.12.0")]
 pub type InvoiceCollectionMethod = CollectionMethod;
 
+#[deprecated(since = "0.13.0")]
+pub type SubscriptionScheduleDefaultSettingsCollectionMethod = CollectionMethod;
+
 impl Invoice {
     /// Retrieves the details of an upcoming invoice_id
     ///
@@ -15,12 +21,103 @@ impl Invoice {
         client.get_query("/invoices/upcoming", &params)
     }
 
+    /// Retrieves the next invoice that will be billed for a subscription.
+    ///
+    /// This is a shorthand for `Invoice::upcoming` which only needs the subscription,
+    /// e.g. to show the amount and date of the next payment.
+    ///
+    /// For more details see https://stripe.com/docs/api#upcoming_invoice
+    pub fn upcoming_for_subscription(
+        client: &Client,
+        subscription_id: &SubscriptionId,
+    ) -> Response<Invoice> {
+        client.get_query(
+            "/invoices/upcoming",
+            &UpcomingInvoiceForSubscription { subscription: subscription_id },
+        )
+    }
+
     /// Pays an invoice.
     ///
     /// For more details see https://stripe.com/docs/api#pay_invoice.
     pub fn pay(client: &Client, invoice_id: &InvoiceId) -> Response<Invoice> {
         client.post(&format!("/invoices/{}/pay", invoice_id))
     }
+
+    /// Pays an invoice, e.g. with an explicit payment method or by marking it as paid out of band.
+    ///
+    /// For more details see https://stripe.com/docs/api#pay_invoice.
+    pub fn pay_with_params(
+        client: &Client,
+        invoice_id: &InvoiceId,
+        params: PayInvoice<'_>,
+    ) -> Response<Invoice> {
+        client.post_form(&format!("/invoices/{}/pay", invoice_id), &params)
+    }
+}
+
+/// The parameters for `Invoice::pay_with_params`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct PayInvoice<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// In cases where the source used to pay the invoice has insufficient funds, passing `forgive=true` controls whether a charge should be attempted for the full amount available on the source, up to the amount to fully pay the invoice.
+    ///
+    /// This effectively forgives the difference between the amount available on the source and the amount due.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forgive: Option<bool>,
+
+    /// ID of the mandate to be used for this invoice.
+    ///
+    /// It must correspond to the payment method used to pay the invoice, including the `payment_method` param or the invoice's `default_payment_method` or `default_source`, if set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate: Option<MandateId>,
+
+    /// Indicates if a customer is on or off-session while an invoice payment is attempted.
+    ///
+    /// Defaults to `true` (off-session).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub off_session: Option<bool>,
+
+    /// Boolean representing whether an invoice is paid outside of Stripe.
+    ///
+    /// This will result in no charge being made.
+    /// Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_out_of_band: Option<bool>,
+
+    /// A PaymentMethod to be charged.
+    ///
+    /// The PaymentMethod must be the ID of a PaymentMethod belonging to the customer associated with the invoice being paid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<PaymentMethodId>,
+
+    /// A payment source to be charged.
+    ///
+    /// The source must be the ID of a source belonging to the customer associated with the invoice being paid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<PaymentSourceId>,
+}
+
+impl<'a> PayInvoice<'a> {
+    pub fn new() -> Self {
+        PayInvoice {
+            expand: Default::default(),
+            forgive: Default::default(),
+            mandate: Default::default(),
+            off_session: Default::default(),
+            paid_out_of_band: Default::default(),
+            payment_method: Default::default(),
+            source: Default::default(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct UpcomingInvoiceForSubscription<'a> {
+    subscription: &'a SubscriptionId,
 }
 
 #[derive(Clone, Debug, Serialize)]
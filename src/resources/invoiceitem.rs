@@ -5,13 +5,16 @@
 use crate::config::{Client, Response};
 use crate::ids::{CustomerId, InvoiceId, InvoiceItemId, PriceId, SubscriptionId};
 use crate::params::{Deleted, Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
-use crate::resources::{Currency, Customer, Invoice, Period, Plan, Price, Subscription, TaxRate};
+use crate::resources::{
+    Currency, Customer, Discount, Invoice, Period, Plan, Price, Subscription, TaxRate,
+};
 use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "InvoiceItem".
 ///
 /// For more details see [https://stripe.com/docs/api/invoiceitems/object](https://stripe.com/docs/api/invoiceitems/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct InvoiceItem {
     /// Unique identifier for the object.
     pub id: InvoiceItemId,
@@ -54,6 +57,13 @@ pub struct InvoiceItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub discountable: Option<bool>,
 
+    /// The discounts which apply to the invoice item.
+    ///
+    /// Item discounts are applied before invoice discounts.
+    /// Use `expand[]=discounts` to expand each discount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<Expandable<Discount>>>,
+
     /// The ID of the invoice this invoice item belongs to.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub invoice: Option<Expandable<Invoice>>,
@@ -117,7 +127,7 @@ impl InvoiceItem {
     ///
     /// Invoice items are returned sorted by creation date, with the most recently created invoice items appearing first.
     pub fn list(client: &Client, params: ListInvoiceItems<'_>) -> Response<List<InvoiceItem>> {
-        client.get_query("/invoiceitems", &params)
+        List::get_with_params(client, "/invoiceitems", &params)
     }
 
     /// Creates an item to be added to a draft invoice.
@@ -191,6 +201,10 @@ pub struct CreateInvoiceItem<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub discountable: Option<bool>,
 
+    /// The coupons to redeem into discounts for the invoice item or invoice line item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<CreateInvoiceItemDiscounts>>,
+
     /// Specifies which fields in the response should be expanded.
     #[serde(skip_serializing_if = "Expand::is_empty")]
     pub expand: &'a [&'a str],
@@ -265,6 +279,7 @@ impl<'a> CreateInvoiceItem<'a> {
             customer,
             description: Default::default(),
             discountable: Default::default(),
+            discounts: Default::default(),
             expand: Default::default(),
             invoice: Default::default(),
             metadata: Default::default(),
@@ -368,6 +383,10 @@ pub struct UpdateInvoiceItem<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub discountable: Option<bool>,
 
+    /// The coupons to redeem into discounts for the invoice item or invoice line item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<UpdateInvoiceItemDiscounts>>,
+
     /// Specifies which fields in the response should be expanded.
     #[serde(skip_serializing_if = "Expand::is_empty")]
     pub expand: &'a [&'a str],
@@ -425,6 +444,7 @@ impl<'a> UpdateInvoiceItem<'a> {
             amount: Default::default(),
             description: Default::default(),
             discountable: Default::default(),
+            discounts: Default::default(),
             expand: Default::default(),
             metadata: Default::default(),
             period: Default::default(),
@@ -438,6 +458,18 @@ impl<'a> UpdateInvoiceItem<'a> {
     }
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateInvoiceItemDiscounts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promotion_code: Option<String>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InvoiceItemPriceData {
     pub currency: Currency,
@@ -450,3 +482,15 @@ pub struct InvoiceItemPriceData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub unit_amount_decimal: Option<String>,
 }
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdateInvoiceItemDiscounts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promotion_code: Option<String>,
+}
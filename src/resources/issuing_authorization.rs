@@ -12,6 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "IssuingAuthorization".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct IssuingAuthorization {
     /// Unique identifier for the object.
     pub id: IssuingAuthorizationId,
@@ -28,6 +29,7 @@ pub struct IssuingAuthorization {
     pub authorization_method: IssuingAuthorizationMethod,
 
     /// List of balance transactions associated with this authorization.
+    #[serde(default)]
     pub balance_transactions: Vec<BalanceTransaction>,
 
     pub card: IssuingCard,
@@ -65,6 +67,7 @@ pub struct IssuingAuthorization {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
     /// The pending authorization request.
@@ -76,12 +79,14 @@ pub struct IssuingAuthorization {
     /// History of every time the authorization was approved/denied (whether approved/denied by you directly or by Stripe based on your `spending_controls`).
     ///
     /// If the merchant changes the authorization by performing an [incremental authorization or partial capture](https://stripe.com/docs/issuing/purchases/authorizations), you can look at this field to see the previous states of the authorization.
+    #[serde(default)]
     pub request_history: Vec<IssuingAuthorizationRequest>,
 
     /// The current status of the authorization in its lifecycle.
     pub status: IssuingAuthorizationStatus,
 
     /// List of [transactions](https://stripe.com/docs/api/issuing/transactions) associated with this authorization.
+    #[serde(default)]
     pub transactions: Vec<IssuingTransaction>,
 
     pub verification_data: IssuingAuthorizationVerificationData,
@@ -1,5 +1,265 @@
+use crate::config::{Client, Response};
+use crate::ids::{IssuingAuthorizationId, IssuingCardId};
+use crate::params::{Expand, Metadata};
+use crate::resources::{Currency, IssuingAuthorization, MerchantCategory};
 use serde_derive::{Deserialize, Serialize};
 
+impl IssuingAuthorization {
+    /// Creates a test mode authorization on a card, as if the card had been used at a merchant.
+    ///
+    /// This is only available in test mode and lets integration tests simulate incoming authorization requests.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/authorizations/test_mode_create.
+    pub fn test_create(
+        client: &Client,
+        params: TestCreateIssuingAuthorization<'_>,
+    ) -> Response<IssuingAuthorization> {
+        client.post_form("/test_helpers/issuing/authorizations", &params)
+    }
+
+    /// Approves a pending Issuing `Authorization` object.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/authorizations/approve.
+    pub fn approve(
+        client: &Client,
+        id: &IssuingAuthorizationId,
+        params: ApproveIssuingAuthorization<'_>,
+    ) -> Response<IssuingAuthorization> {
+        client.post_form(&format!("/issuing/authorizations/{}/approve", id), &params)
+    }
+
+    /// Declines a pending Issuing `Authorization` object.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/authorizations/decline.
+    pub fn decline(
+        client: &Client,
+        id: &IssuingAuthorizationId,
+        params: DeclineIssuingAuthorization<'_>,
+    ) -> Response<IssuingAuthorization> {
+        client.post_form(&format!("/issuing/authorizations/{}/decline", id), &params)
+    }
+
+    /// Captures a test mode authorization, creating the transaction that settles it.
+    ///
+    /// This is only available in test mode.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/authorizations/test_mode_capture.
+    pub fn test_capture(
+        client: &Client,
+        id: &IssuingAuthorizationId,
+        params: TestCaptureIssuingAuthorization<'_>,
+    ) -> Response<IssuingAuthorization> {
+        client.post_form(&format!("/test_helpers/issuing/authorizations/{}/capture", id), &params)
+    }
+}
+
+/// The parameters for `IssuingAuthorization::approve`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ApproveIssuingAuthorization<'a> {
+    /// If the authorization's `pending_request.is_amount_controllable` property is `true`, you may provide this value to control how much to hold for the authorization.
+    ///
+    /// Must be positive (use `decline` to decline an authorization request).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> ApproveIssuingAuthorization<'a> {
+    pub fn new() -> Self {
+        ApproveIssuingAuthorization {
+            amount: Default::default(),
+            expand: Default::default(),
+            metadata: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `IssuingAuthorization::decline`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct DeclineIssuingAuthorization<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> DeclineIssuingAuthorization<'a> {
+    pub fn new() -> Self {
+        DeclineIssuingAuthorization { expand: Default::default(), metadata: Default::default() }
+    }
+}
+
+/// The parameters for `IssuingAuthorization::test_capture`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct TestCaptureIssuingAuthorization<'a> {
+    /// The amount to capture from the authorization.
+    ///
+    /// If not provided, the full amount of the authorization will be captured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_amount: Option<i64>,
+
+    /// Whether to close the authorization after capture.
+    ///
+    /// Defaults to true.
+    /// Set to false to enable multi-capture flows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_authorization: Option<bool>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> TestCaptureIssuingAuthorization<'a> {
+    pub fn new() -> Self {
+        TestCaptureIssuingAuthorization {
+            capture_amount: Default::default(),
+            close_authorization: Default::default(),
+            expand: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `IssuingAuthorization::test_create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct TestCreateIssuingAuthorization<'a> {
+    /// The total amount to attempt to authorize.
+    ///
+    /// This amount is in the provided currency, or defaults to the card's currency, and in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal).
+    pub amount: i64,
+
+    /// Detailed breakdown of amount components.
+    ///
+    /// These amounts are denominated in `currency` and in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_details: Option<TestCreateIssuingAuthorizationAmountDetails>,
+
+    /// How the card details were provided.
+    ///
+    /// Defaults to online.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_method: Option<IssuingAuthorizationMethod>,
+
+    /// Card associated with this authorization.
+    pub card: IssuingCardId,
+
+    /// The currency of the authorization.
+    ///
+    /// If not provided, defaults to the currency of the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// If set `true`, you may provide an `amount` when approving the authorization to control how much to hold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_amount_controllable: Option<bool>,
+
+    /// Details about the seller (grocery store, e-commerce website, etc.) where the card authorization happened.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_data: Option<TestCreateIssuingAuthorizationMerchantData>,
+
+    /// Details about the authorization, such as identifiers, set by the card network.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_data: Option<TestCreateIssuingAuthorizationNetworkData>,
+
+    /// Verifications that Stripe performed on information that the cardholder provided to the merchant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_data: Option<TestCreateIssuingAuthorizationVerificationData>,
+
+    /// The digital wallet used for this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet: Option<IssuingAuthorizationWalletProvider>,
+}
+
+impl<'a> TestCreateIssuingAuthorization<'a> {
+    pub fn new(card: IssuingCardId, amount: i64) -> Self {
+        TestCreateIssuingAuthorization {
+            amount,
+            amount_details: Default::default(),
+            authorization_method: Default::default(),
+            card,
+            currency: Default::default(),
+            expand: Default::default(),
+            is_amount_controllable: Default::default(),
+            merchant_data: Default::default(),
+            network_data: Default::default(),
+            verification_data: Default::default(),
+            wallet: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TestCreateIssuingAuthorizationAmountDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub atm_fee: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cashback_amount: Option<i64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TestCreateIssuingAuthorizationMerchantData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<MerchantCategory>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TestCreateIssuingAuthorizationNetworkData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acquiring_institution_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TestCreateIssuingAuthorizationVerificationData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line1_check: Option<IssuingAuthorizationCheck>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_postal_code_check: Option<IssuingAuthorizationCheck>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cvc_check: Option<IssuingAuthorizationCheck>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry_check: Option<IssuingAuthorizationCheck>,
+}
+
 /// An enum representing the possible values of the `IssuingAuthorizationVerificationData` fields.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -3,7 +3,7 @@
 // ======================================
 
 use crate::ids::IssuingCardId;
-use crate::params::{Expandable, Metadata, Object, Timestamp};
+use crate::params::{Expandable, Metadata, Object, Secret, Timestamp};
 use crate::resources::{
     Address, CardBrand, Currency, IssuingCardShippingStatus, IssuingCardShippingType,
     IssuingCardType, IssuingCardholder, MerchantCategory, SpendingLimit,
@@ -12,6 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "IssuingCard".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct IssuingCard {
     /// Unique identifier for the object.
     pub id: IssuingCardId,
@@ -40,7 +41,7 @@ pub struct IssuingCard {
     /// For security reasons, this is only available for virtual cards, and will be omitted unless you explicitly request it with [the `expand` parameter](https://stripe.com/docs/api/expanding_objects).
     /// Additionally, it's only available via the ["Retrieve a card" endpoint](https://stripe.com/docs/api/issuing/cards/retrieve), not via "List all cards" or any other endpoint.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cvc: Option<String>,
+    pub cvc: Option<Secret>,
 
     /// The expiration month of the card.
     pub exp_month: i64,
@@ -57,6 +58,7 @@ pub struct IssuingCard {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
     /// The full unredacted card number.
@@ -64,7 +66,7 @@ pub struct IssuingCard {
     /// For security reasons, this is only available for virtual cards, and will be omitted unless you explicitly request it with [the `expand` parameter](https://stripe.com/docs/api/expanding_objects).
     /// Additionally, it's only available via the ["Retrieve a card" endpoint](https://stripe.com/docs/api/issuing/cards/retrieve), not via "List all cards" or any other endpoint.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub number: Option<String>,
+    pub number: Option<Secret>,
 
     /// The latest card that replaces this card, if any.
     #[serde(skip_serializing_if = "Option::is_none")]
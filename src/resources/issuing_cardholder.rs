@@ -9,6 +9,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "IssuingCardholder".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct IssuingCardholder {
     /// Unique identifier for the object.
     pub id: IssuingCardholderId,
@@ -38,6 +39,7 @@ pub struct IssuingCardholder {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
     /// The cardholder's name.
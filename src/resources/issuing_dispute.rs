@@ -8,6 +8,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "IssuingDispute".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct IssuingDispute {
     /// Unique identifier for the object.
     pub id: IssuingDisputeId,
@@ -0,0 +1,217 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::config::{Client, Response};
+use crate::ids::IssuingPhysicalBundleId;
+use crate::params::{Expand, List, Object};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "IssuingPhysicalBundle".
+///
+/// For more details see [https://stripe.com/docs/api/issuing/physical_bundles/object](https://stripe.com/docs/api/issuing/physical_bundles/object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct IssuingPhysicalBundle {
+    /// Unique identifier for the object.
+    pub id: IssuingPhysicalBundleId,
+
+    pub features: IssuingPhysicalBundleFeatures,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// Friendly display name.
+    pub name: String,
+
+    /// Whether this physical bundle can be used to create cards.
+    pub status: IssuingPhysicalBundleStatus,
+
+    /// Whether this physical bundle is a standard Stripe offering or custom-made for you.
+    #[serde(rename = "type")]
+    pub type_: IssuingPhysicalBundleType,
+}
+
+impl IssuingPhysicalBundle {
+    /// Returns a list of physical bundle objects.
+    ///
+    /// The objects are sorted in descending order by creation date, with the most recently created object appearing first.
+    pub fn list(
+        client: &Client,
+        params: ListIssuingPhysicalBundles<'_>,
+    ) -> Response<List<IssuingPhysicalBundle>> {
+        List::get_with_params(client, "/issuing/physical_bundles", &params)
+    }
+
+    /// Retrieves a physical bundle object.
+    pub fn retrieve(
+        client: &Client,
+        id: &IssuingPhysicalBundleId,
+        expand: &[&str],
+    ) -> Response<IssuingPhysicalBundle> {
+        client.get_query(&format!("/issuing/physical_bundles/{}", id), &Expand { expand })
+    }
+}
+
+impl Object for IssuingPhysicalBundle {
+    type Id = IssuingPhysicalBundleId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "issuing.physical_bundle"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IssuingPhysicalBundleFeatures {
+    /// The policy for how to use card logo images in a card design with this physical bundle.
+    pub card_logo: IssuingPhysicalBundleFeatureAvailability,
+
+    /// The policy for how to use carrier letter text in a card design with this physical bundle.
+    pub carrier_text: IssuingPhysicalBundleFeatureAvailability,
+
+    /// The policy for how to use a second line on a card with this physical bundle.
+    pub second_line: IssuingPhysicalBundleFeatureAvailability,
+}
+
+/// The parameters for `IssuingPhysicalBundle::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListIssuingPhysicalBundles<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<IssuingPhysicalBundleId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<IssuingPhysicalBundleId>,
+
+    /// Only return physical bundles with the given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingPhysicalBundleStatus>,
+
+    /// Only return physical bundles with the given type.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IssuingPhysicalBundleType>,
+}
+
+impl<'a> ListIssuingPhysicalBundles<'a> {
+    pub fn new() -> Self {
+        ListIssuingPhysicalBundles {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+            type_: Default::default(),
+        }
+    }
+}
+
+/// An enum representing the possible values of an `IssuingPhysicalBundleFeatures`'s `card_logo`, `carrier_text` and `second_line` fields.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IssuingPhysicalBundleFeatureAvailability {
+    Optional,
+    Required,
+    Unsupported,
+}
+
+impl IssuingPhysicalBundleFeatureAvailability {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            IssuingPhysicalBundleFeatureAvailability::Optional => "optional",
+            IssuingPhysicalBundleFeatureAvailability::Required => "required",
+            IssuingPhysicalBundleFeatureAvailability::Unsupported => "unsupported",
+        }
+    }
+}
+
+impl AsRef<str> for IssuingPhysicalBundleFeatureAvailability {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for IssuingPhysicalBundleFeatureAvailability {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `IssuingPhysicalBundle`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IssuingPhysicalBundleStatus {
+    Active,
+    Inactive,
+    Review,
+}
+
+impl IssuingPhysicalBundleStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            IssuingPhysicalBundleStatus::Active => "active",
+            IssuingPhysicalBundleStatus::Inactive => "inactive",
+            IssuingPhysicalBundleStatus::Review => "review",
+        }
+    }
+}
+
+impl AsRef<str> for IssuingPhysicalBundleStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for IssuingPhysicalBundleStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `IssuingPhysicalBundle`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IssuingPhysicalBundleType {
+    Custom,
+    Standard,
+}
+
+impl IssuingPhysicalBundleType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            IssuingPhysicalBundleType::Custom => "custom",
+            IssuingPhysicalBundleType::Standard => "standard",
+        }
+    }
+}
+
+impl AsRef<str> for IssuingPhysicalBundleType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for IssuingPhysicalBundleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
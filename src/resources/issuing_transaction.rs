@@ -12,6 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "IssuingTransaction".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct IssuingTransaction {
     /// Unique identifier for the object.
     pub id: IssuingTransactionId,
@@ -62,6 +63,7 @@ pub struct IssuingTransaction {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
     /// Additional purchase information that is optionally provided by the merchant.
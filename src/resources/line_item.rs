@@ -4,11 +4,12 @@
 
 use crate::ids::InvoiceLineItemId;
 use crate::params::{Expandable, Metadata, Object};
-use crate::resources::{Currency, Period, Plan, Price, TaxRate};
+use crate::resources::{Currency, Discount, Period, Plan, Price, TaxRate};
 use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "InvoiceLineItem".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct InvoiceLineItem {
     /// Unique identifier for the object.
     pub id: InvoiceLineItemId,
@@ -32,6 +33,13 @@ pub struct InvoiceLineItem {
     /// Always false for prorations.
     pub discountable: bool,
 
+    /// The discounts applied to the invoice line item.
+    ///
+    /// Line item discounts are applied before invoice discounts.
+    /// Use `expand[]=discounts` to expand each discount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<Expandable<Discount>>>,
+
     /// The ID of the [invoice item](https://stripe.com/docs/api/invoiceitems) associated with this line item if any.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub invoice_item: Option<String>,
@@ -43,6 +51,7 @@ pub struct InvoiceLineItem {
     ///
     /// This can be useful for storing additional information about the object in a structured format.
     /// Note that for line items with `type=subscription` this will reflect the metadata of the subscription that caused the line item to be created.
+    #[serde(default)]
     pub metadata: Metadata,
 
     pub period: Option<Period>,
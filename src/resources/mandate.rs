@@ -12,6 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/mandates/object](https://stripe.com/docs/api/mandates/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Mandate {
     /// Unique identifier for the object.
     pub id: MandateId,
@@ -14,6 +14,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/orders/object](https://stripe.com/docs/api/orders/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Order {
     /// Unique identifier for the object.
     pub id: OrderId,
@@ -67,6 +68,7 @@ pub struct Order {
     /// List of items constituting the order.
     ///
     /// An order can have up to 25 items.
+    #[serde(default)]
     pub items: Vec<OrderItem>,
 
     /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
@@ -75,6 +77,7 @@ pub struct Order {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
     /// A list of returns that have taken place for this order.
@@ -126,7 +129,7 @@ impl Order {
     ///
     /// The orders are returned sorted by creation date, with the most recently created orders appearing first.
     pub fn list(client: &Client, params: ListOrders<'_>) -> Response<List<Order>> {
-        client.get_query("/orders", &params)
+        List::get_with_params(client, "/orders", &params)
     }
 
     /// Creates a new order object.
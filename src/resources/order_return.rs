@@ -12,6 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/order_returns/object](https://stripe.com/docs/api/order_returns/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct OrderReturn {
     /// Unique identifier for the object.
     pub id: OrderReturnId,
@@ -30,6 +31,7 @@ pub struct OrderReturn {
     pub currency: Currency,
 
     /// The items included in this order return.
+    #[serde(default)]
     pub items: Vec<OrderItem>,
 
     /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
@@ -49,7 +51,7 @@ impl OrderReturn {
     ///
     /// The returns are returned sorted by creation date, with the most recently created return appearing first.
     pub fn list(client: &Client, params: ListOrderReturns<'_>) -> Response<List<OrderReturn>> {
-        client.get_query("/order_returns", &params)
+        List::get_with_params(client, "/order_returns", &params)
     }
 
     /// Retrieves the details of an existing order return.
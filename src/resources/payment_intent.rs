@@ -1,9 +1,10 @@
 use crate::config::{Client, Response};
-use crate::ids::{CustomerId, PaymentIntentId};
-use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
+use crate::ids::{CustomerId, PaymentIntentId, PaymentMethodId};
+use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Secret, Timestamp};
 use crate::resources::{
-    Account, Application, Charge, Currency, Customer, Invoice, PaymentIntentOffSession,
-    PaymentMethod, PaymentSource, Review, Shipping, TransferDataParams,
+    Account, Application, Charge, ChargeShippingParams, Currency, Customer, Invoice,
+    LinkedAccountOptionsUsBankAccount, PaymentIntentOffSession, PaymentMethod, PaymentSource,
+    Review, Shipping, TransferDataParams,
 };
 use serde_derive::{Deserialize, Serialize};
 
@@ -11,6 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/payment_intents/object](https://stripe.com/docs/api/payment_intents/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct PaymentIntent {
     /// Unique identifier for the object.
     pub id: PaymentIntentId,
@@ -36,6 +38,10 @@ pub struct PaymentIntent {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee_amount: Option<i64>,
 
+    /// Settings to configure compatible payment methods from the [Stripe Dashboard](https://dashboard.stripe.com/settings/payment_methods).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_payment_methods: Option<PaymentIntentAutomaticPaymentMethods>,
+
     /// Populated when `status` is `canceled`, this is the time at which the PaymentIntent was canceled.
     ///
     /// Measured in seconds since the Unix epoch.
@@ -58,7 +64,7 @@ pub struct PaymentIntent {
     /// Used for client-side retrieval using a publishable key.
     /// Please refer to our [automatic confirmation quickstart guide](https://stripe.com/docs/payments/payment-intents/quickstart#automatic-confirmation-flow) to learn about how `client_secret` should be handled.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_secret: Option<String>,
+    pub client_secret: Option<Secret>,
 
     /// Confirmation method of this PaymentIntent, one of `manual` or `automatic`.
     pub confirmation_method: PaymentIntentConfirmationMethod,
@@ -115,9 +121,14 @@ pub struct PaymentIntent {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_method: Option<Expandable<PaymentMethod>>,
 
+    /// Payment-method-specific configuration for this PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_options: Option<PaymentIntentPaymentMethodOptions>,
+
     /// The list of payment method types (e.g.
     ///
     /// card) that this PaymentIntent is allowed to use.
+    #[serde(default)]
     pub payment_method_types: Vec<String>,
 
     /// Email address that the receipt for the resulting payment will be sent to.
@@ -132,6 +143,12 @@ pub struct PaymentIntent {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shipping: Option<Shipping>,
 
+    /// Indicates that you intend to make future payments with this PaymentIntent's payment method.
+    ///
+    /// Providing this parameter will [attach the payment method](https://stripe.com/docs/payments/save-during-payment) to the PaymentIntent's Customer, if present, after the PaymentIntent is confirmed and any required actions from the user are complete.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<PaymentIntentSetupFutureUsage>,
+
     /// ID of the source used in this PaymentIntent.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<Expandable<PaymentSource>>,
@@ -225,11 +242,25 @@ impl PaymentIntent {
         client.post_form(&format!("/payment_intents/{}/cancel", payment_intent_id), params)
     }
 
+    /// Verifies microdeposits on a PaymentIntent object.
+    ///
+    /// For more details see [https://stripe.com/docs/api/payment_intents/verify_microdeposits](https://stripe.com/docs/api/payment_intents/verify_microdeposits).
+    pub fn verify_microdeposits(
+        client: &Client,
+        payment_intent_id: &str,
+        params: VerifyMicrodeposits<'_>,
+    ) -> Response<PaymentIntent> {
+        client.post_form(
+            &format!("/payment_intents/{}/verify_microdeposits", payment_intent_id),
+            params,
+        )
+    }
+
     /// List all payment_intents.
     ///
     /// For more details see [https://stripe.com/docs/api/payment_intents/list](https://stripe.com/docs/api/payment_intents/list).
-    pub fn list(client: &Client, params: ListPaymentIntents) -> Response<List<PaymentIntent>> {
-        client.get_query("/payment_intents", &params)
+    pub fn list(client: &Client, params: ListPaymentIntents<'_>) -> Response<List<PaymentIntent>> {
+        List::get_with_params(client, "/payment_intents", &params)
     }
 }
 
@@ -335,8 +366,13 @@ pub enum ConfirmationMethod {
 #[derive(Deserialize, Serialize, PartialEq, Debug, Clone, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum PaymentIntentNextActionType {
+    BoletoDisplayDetails,
+    DisplayBankTransferInstructions,
+    OxxoDisplayDetails,
     RedirectToUrl,
     UseStripeSdk,
+    VerifyWithMicrodeposits,
+    WechatPayDisplayQrCode,
 
     /// A variant not yet supported by the library.
     /// It is an error to send `Other` as part of a request.
@@ -346,10 +382,22 @@ pub enum PaymentIntentNextActionType {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PaymentIntentNextAction {
-    /// Type of the next action to perform, one of `redirect_to_url` or `use_stripe_sdk`.
+    /// Type of the next action to perform.
+    ///
+    /// The details of the action are found in the field of the same name.
     #[serde(rename = "type")]
     pub type_: PaymentIntentNextActionType,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boleto_display_details: Option<PaymentIntentNextActionBoletoDisplayDetails>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_bank_transfer_instructions:
+        Option<PaymentIntentNextActionDisplayBankTransferInstructions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oxxo_display_details: Option<PaymentIntentNextActionOxxoDisplayDetails>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub redirect_to_url: Option<PaymentIntentNextActionRedirectToUrl>,
 
@@ -358,6 +406,241 @@ pub struct PaymentIntentNextAction {
     /// The shape of the contents is subject to change and is only intended to be used by Stripe.js.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_stripe_sdk: Option<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verify_with_microdeposits: Option<PaymentIntentNextActionVerifyWithMicrodeposits>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wechat_pay_display_qr_code: Option<PaymentIntentNextActionWechatPayDisplayQrCode>,
+
+    /// The details of any next action not yet supported by the library, keyed by action type.
+    ///
+    /// When `type_` is `Other`, the raw payload of the action can be found here.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentIntentNextActionBoletoDisplayDetails {
+    /// The timestamp after which the boleto expires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// The URL to the hosted boleto voucher page, which allows customers to view the boleto voucher.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hosted_voucher_url: Option<String>,
+
+    /// The boleto number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+
+    /// The URL to the downloadable boleto voucher PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pdf: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentIntentNextActionDisplayBankTransferInstructions {
+    /// The remaining amount that needs to be transferred to complete the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_remaining: Option<i64>,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
+    /// A list of financial addresses that can be used to fund the customer balance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub financial_addresses: Option<Vec<FundingInstructionsBankTransferFinancialAddress>>,
+
+    /// A link to a hosted page that guides your customer through completing the transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hosted_instructions_url: Option<String>,
+
+    /// A string identifying this payment.
+    ///
+    /// Instruct your customer to include this code in the reference or memo field of their bank transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    /// Type of bank transfer.
+    #[serde(rename = "type")]
+    pub type_: PaymentIntentNextActionDisplayBankTransferInstructionsType,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FundingInstructionsBankTransferFinancialAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aba: Option<FundingInstructionsBankTransferAbaRecord>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iban: Option<FundingInstructionsBankTransferIbanRecord>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_code: Option<FundingInstructionsBankTransferSortCodeRecord>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spei: Option<FundingInstructionsBankTransferSpeiRecord>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub swift: Option<FundingInstructionsBankTransferSwiftRecord>,
+
+    /// The payment networks supported by this FinancialAddress.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supported_networks:
+        Option<Vec<FundingInstructionsBankTransferFinancialAddressSupportedNetworks>>,
+
+    /// The type of financial address.
+    #[serde(rename = "type")]
+    pub type_: FundingInstructionsBankTransferFinancialAddressType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zengin: Option<FundingInstructionsBankTransferZenginRecord>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FundingInstructionsBankTransferAbaRecord {
+    /// The ABA account number.
+    pub account_number: String,
+
+    /// The bank name.
+    pub bank_name: String,
+
+    /// The ABA routing number.
+    pub routing_number: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FundingInstructionsBankTransferIbanRecord {
+    /// The name of the person or business that owns the bank account.
+    pub account_holder_name: String,
+
+    /// The BIC/SWIFT code of the account.
+    pub bic: String,
+
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
+    pub country: String,
+
+    /// The IBAN of the account.
+    pub iban: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FundingInstructionsBankTransferSortCodeRecord {
+    /// The name of the person or business that owns the bank account.
+    pub account_holder_name: String,
+
+    /// The account number.
+    pub account_number: String,
+
+    /// The six-digit sort code.
+    pub sort_code: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FundingInstructionsBankTransferSpeiRecord {
+    /// The three-digit bank code.
+    pub bank_code: String,
+
+    /// The short banking institution name.
+    pub bank_name: String,
+
+    /// The CLABE number.
+    pub clabe: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FundingInstructionsBankTransferSwiftRecord {
+    /// The account number.
+    pub account_number: String,
+
+    /// The bank name.
+    pub bank_name: String,
+
+    /// The SWIFT code.
+    pub swift_code: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FundingInstructionsBankTransferZenginRecord {
+    /// The account holder name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_name: Option<String>,
+
+    /// The account number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<String>,
+
+    /// The bank account type.
+    ///
+    /// In Japan, this can only be `futsu` or `toza`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<String>,
+
+    /// The bank code of the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_code: Option<String>,
+
+    /// The bank name of the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_name: Option<String>,
+
+    /// The branch code of the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch_code: Option<String>,
+
+    /// The branch name of the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentIntentNextActionOxxoDisplayDetails {
+    /// The timestamp after which the OXXO voucher expires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_after: Option<Timestamp>,
+
+    /// The URL for the hosted OXXO voucher page, which allows customers to view and print an OXXO voucher.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hosted_voucher_url: Option<String>,
+
+    /// OXXO reference number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentIntentNextActionVerifyWithMicrodeposits {
+    /// The timestamp when the microdeposits are expected to land.
+    pub arrival_date: Timestamp,
+
+    /// The URL for the hosted verification page, which allows customers to verify their bank account.
+    pub hosted_verification_url: String,
+
+    /// The type of the microdeposit sent to the customer.
+    ///
+    /// Used to distinguish between different verification methods.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub microdeposit_type: Option<PaymentIntentNextActionVerifyWithMicrodepositsMicrodepositType>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentIntentNextActionWechatPayDisplayQrCode {
+    /// The data being used to generate QR code.
+    pub data: String,
+
+    /// The URL to the hosted WeChat Pay instructions page, which allows customers to view the WeChat Pay QR code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hosted_instructions_url: Option<String>,
+
+    /// The base64 image data for a pre-generated QR code.
+    pub image_data_url: String,
+
+    /// The image_url_png string used to render QR code.
+    pub image_url_png: String,
+
+    /// The image_url_svg string used to render QR code.
+    pub image_url_svg: String,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -371,8 +654,161 @@ pub struct PaymentIntentNextActionRedirectToUrl {
     pub url: Option<String>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentIntentAutomaticPaymentMethods {
+    /// Whether this feature is enabled.
+    pub enabled: bool,
+
+    /// Controls whether this PaymentIntent will accept redirect-based payment methods.
+    ///
+    /// Redirect-based payment methods may require your customer to be redirected to a payment method's app or site for authentication or additional steps.
+    /// To [confirm](https://stripe.com/docs/api/payment_intents/confirm) this PaymentIntent, you may be required to provide a `return_url` to redirect customers back to your site after they authenticate or complete the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_redirects: Option<PaymentIntentAutomaticPaymentMethodsAllowRedirects>,
+}
+
+/// Payment-method-specific configuration for a PaymentIntent.
+///
+/// Blocks for payment methods the library does not know about are ignored when deserializing.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentIntentPaymentMethodOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acss_debit: Option<PaymentIntentPaymentMethodOptionsAcssDebit>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<PaymentIntentPaymentMethodOptionsCard>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sepa_debit: Option<PaymentIntentPaymentMethodOptionsSepaDebit>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account: Option<PaymentIntentPaymentMethodOptionsUsBankAccount>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentIntentPaymentMethodOptionsAcssDebit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_options: Option<PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebit>,
+
+    /// Bank account verification method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_method: Option<PaymentIntentPaymentMethodOptionsVerificationMethod>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentIntentPaymentMethodOptionsCard {
+    /// Configuration options for setting up an eMandate for cards issued in India.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_options: Option<PaymentIntentPaymentMethodOptionsCardMandateOptions>,
+
+    /// Selected network to process this payment intent on.
+    ///
+    /// Depends on the available networks of the card attached to the payment intent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+
+    /// We strongly recommend that you rely on our SCA Engine to automatically prompt your customers for authentication based on risk level and [other requirements](https://stripe.com/docs/strong-customer-authentication).
+    ///
+    /// However, if you wish to request 3D Secure based on logic from your own fraud engine, provide this option.
+    /// Permitted values include: `automatic` or `any`.
+    /// If not provided, defaults to `automatic`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_three_d_secure: Option<PaymentIntentPaymentMethodOptionsCardRequestThreeDSecure>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentIntentPaymentMethodOptionsCardMandateOptions {
+    /// Amount to be charged for future payments.
+    pub amount: i64,
+
+    /// One of `fixed` or `maximum`.
+    ///
+    /// If `fixed`, the `amount` param refers to the exact amount to be charged in future payments.
+    /// If `maximum`, the amount charged can be up to the value passed for the `amount` param.
+    pub amount_type: PaymentIntentPaymentMethodOptionsCardMandateOptionsAmountType,
+
+    /// A description of the mandate or subscription that is meant to be displayed to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// End date of the mandate or subscription.
+    ///
+    /// If not provided, the mandate will be active until canceled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<Timestamp>,
+
+    /// Specifies payment frequency.
+    ///
+    /// One of `day`, `week`, `month`, `year`, or `sporadic`.
+    pub interval: PaymentIntentPaymentMethodOptionsCardMandateOptionsInterval,
+
+    /// The number of intervals between payments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_count: Option<u64>,
+
+    /// Unique identifier for the mandate or subscription.
+    pub reference: String,
+
+    /// Start date of the mandate or subscription.
+    pub start_date: Timestamp,
+
+    /// Specifies the type of mandates supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supported_types:
+        Option<Vec<PaymentIntentPaymentMethodOptionsCardMandateOptionsSupportedTypes>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebit {
+    /// A URL for custom mandate text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_mandate_url: Option<String>,
+
+    /// Description of the interval.
+    ///
+    /// Only required if the 'payment_schedule' parameter is 'interval' or 'combined'.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_description: Option<String>,
+
+    /// Payment schedule for the mandate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_schedule:
+        Option<PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule>,
+
+    /// Transaction type of the mandate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_type:
+        Option<PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebitTransactionType>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentIntentPaymentMethodOptionsMandateOptionsSepaDebit {}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentIntentPaymentMethodOptionsSepaDebit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_options: Option<PaymentIntentPaymentMethodOptionsMandateOptionsSepaDebit>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentIntentPaymentMethodOptionsUsBankAccount {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub financial_connections: Option<LinkedAccountOptionsUsBankAccount>,
+
+    /// Bank account verification method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_method: Option<PaymentIntentPaymentMethodOptionsVerificationMethod>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TransferData {
+    /// Amount intended to be collected by this PaymentIntent.
+    ///
+    /// A positive integer representing how much to charge in the smallest currency unit (e.g., 100 cents to charge $1.00 or 100 to charge ¥100, a zero-decimal currency).
+    /// If unset, the full amount of the PaymentIntent is transferred to the destination account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
     /// The account (if any) the payment will be attributed to for tax
     /// reporting, and where funds from the payment will be transferred to upon
     /// payment success.
@@ -386,36 +822,80 @@ pub struct TransferData {
 pub struct CreatePaymentIntent<'a> {
     /// The list of payment types (e.g. card) that this PaymentIntent is allowed to use.
     pub payment_method_types: Vec<PaymentIntentMethodType>,
-    pub amount: u64,
+    pub amount: i64,
     pub currency: Currency,
-    pub payment_method: Option<&'a str>,
+
+    /// ID of the payment method (a PaymentMethod or Card object) to attach to this PaymentIntent.
+    ///
+    /// Required together with `confirm=true` unless the customer has a default payment method.
+    pub payment_method: Option<PaymentMethodId>,
     pub confirmation_method: Option<PaymentIntentConfirmationMethod>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub application_fee_amount: Option<u64>,
+    pub application_fee_amount: Option<i64>,
+
+    /// When enabled, this PaymentIntent will accept payment methods that you have enabled in the Dashboard and are compatible with this PaymentIntent's other parameters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_payment_methods: Option<PaymentIntentAutomaticPaymentMethods>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub capture_method: Option<PaymentIntentCaptureMethod>,
 
-    /// Attempt to confirm this PaymentIntent on source attachment.
+    /// Set to `true` to attempt to confirm this PaymentIntent immediately.
+    ///
+    /// This saves a separate `PaymentIntent::confirm` call when the server already knows the `payment_method`.
+    /// Redirect-based payment methods also need a `return_url`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub confirm: Option<bool>, // TODO: Is this the correct type?
+    pub confirm: Option<bool>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub customer: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
+
+    /// Set to `true` to fail the payment attempt if the PaymentIntent transitions into `requires_action`.
+    ///
+    /// Only used together with `confirm=true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_on_requires_action: Option<bool>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
+
+    /// Set to `true` to indicate that the customer is not in your checkout flow during this payment attempt.
+    ///
+    /// Only used together with `confirm=true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub off_session: Option<PaymentIntentOffSession>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub on_behalf_of: Option<&'a str>,
+
+    /// Payment-method-specific configuration for this PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_options: Option<PaymentIntentPaymentMethodOptions>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub receipt_email: Option<&'a str>,
+
+    /// The URL to redirect your customer back to after they authenticate or cancel their payment on the payment method's app or site.
+    ///
+    /// Only used together with `confirm=true`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub return_url: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub save_source_to_customer: Option<bool>,
+
+    /// Indicates that you intend to make future payments with this PaymentIntent's payment method.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping: Option<Shipping>,
+    pub setup_future_usage: Option<PaymentIntentSetupFutureUsage>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<ChargeShippingParams>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -427,7 +907,7 @@ pub struct CreatePaymentIntent<'a> {
 }
 
 impl<'a> CreatePaymentIntent<'a> {
-    pub fn new(amount: u64, currency: Currency) -> Self {
+    pub fn new(amount: i64, currency: Currency) -> Self {
         CreatePaymentIntent {
             payment_method_types: Default::default(),
             amount,
@@ -435,15 +915,21 @@ impl<'a> CreatePaymentIntent<'a> {
             payment_method: Default::default(),
             confirmation_method: Default::default(),
             application_fee_amount: Default::default(),
+            automatic_payment_methods: Default::default(),
             capture_method: Default::default(),
             confirm: Default::default(),
             customer: Default::default(),
             description: Default::default(),
+            error_on_requires_action: Default::default(),
+            expand: Default::default(),
             metadata: Default::default(),
+            off_session: Default::default(),
             on_behalf_of: Default::default(),
+            payment_method_options: Default::default(),
             receipt_email: Default::default(),
             return_url: Default::default(),
             save_source_to_customer: Default::default(),
+            setup_future_usage: Default::default(),
             shipping: Default::default(),
             source: Default::default(),
             statement_descriptor: Default::default(),
@@ -459,15 +945,20 @@ impl<'a> CreatePaymentIntent<'a> {
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct PaymentIntentUpdateParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<u64>,
+    pub amount: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub application_fee_amount: Option<u64>,
+    pub application_fee_amount: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub customer: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -475,26 +966,61 @@ pub struct PaymentIntentUpdateParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub save_source_to_customer: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping: Option<Shipping>,
+    pub shipping: Option<ChargeShippingParams>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<&'a str>,
+
+    /// The parameters used to automatically create a Transfer when the payment succeeds.
+    ///
+    /// Only the transfer `amount` can be updated; the destination is fixed at creation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<UpdatePaymentIntentTransferData>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transfer_group: Option<&'a str>,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UpdatePaymentIntentTransferData {
+    /// The amount that will be transferred automatically when a charge succeeds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+}
+
 /// The set of parameters that can be used when confirming a payment_intent object.
 ///
 /// For more details see [https://stripe.com/docs/api/payment_intents/confirm](https://stripe.com/docs/api/payment_intents/confirm)
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct PaymentIntentConfirmParams<'a> {
+    /// Set to `true` to fail the payment attempt if the PaymentIntent transitions into `requires_action`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_on_requires_action: Option<bool>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set to `true` to indicate that the customer is not in your checkout flow during this payment attempt, and therefore is unable to authenticate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub off_session: Option<PaymentIntentOffSession>,
+
+    /// Payment-method-specific configuration for this PaymentIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_options: Option<PaymentIntentPaymentMethodOptions>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub receipt_email: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub return_url: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub save_source_to_customer: Option<bool>,
+
+    /// Indicates that you intend to make future payments with this PaymentIntent's payment method.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping: Option<Shipping>,
+    pub setup_future_usage: Option<PaymentIntentSetupFutureUsage>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<ChargeShippingParams>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<&'a str>,
 }
@@ -505,9 +1031,9 @@ pub struct PaymentIntentConfirmParams<'a> {
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct CapturePaymentIntent {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount_to_capture: Option<u64>,
+    pub amount_to_capture: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub application_fee_amount: Option<u64>,
+    pub application_fee_amount: Option<i64>,
 }
 
 /// The set of parameters that can be used when canceling a payment_intent object.
@@ -519,8 +1045,24 @@ pub struct CancelPaymentIntent {
     pub cancellation_reason: Option<PaymentIntentCancellationReason>,
 }
 
+/// The set of parameters that can be used when verifying microdeposits on a payment_intent or setup_intent object.
+///
+/// Set either `amounts` or `descriptor_code`, depending on the `microdeposit_type` of the intent's `verify_with_microdeposits` next action.
+///
+/// For more details see [https://stripe.com/docs/api/payment_intents/verify_microdeposits](https://stripe.com/docs/api/payment_intents/verify_microdeposits)
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct VerifyMicrodeposits<'a> {
+    /// Two positive integers, in *cents*, equal to the values of the microdeposits sent to the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amounts: Option<[u32; 2]>,
+
+    /// A six-character code starting with SM present in the microdeposit sent to the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descriptor_code: Option<&'a str>,
+}
+
 /// The parameters for `PaymentIntent::list`.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Default)]
 pub struct ListPaymentIntents<'a> {
     /// A filter on the list, based on the object `created` field.
     ///
@@ -557,6 +1099,27 @@ pub struct ListPaymentIntents<'a> {
     pub starting_after: Option<&'a PaymentIntentId>,
 }
 
+impl<'a> ListPaymentIntents<'a> {
+    pub fn new() -> Self {
+        ListPaymentIntents {
+            created: Default::default(),
+            customer: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
+/// An enum representing the possible values of an `PaymentIntentAutomaticPaymentMethods`'s `allow_redirects` field.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentIntentAutomaticPaymentMethodsAllowRedirects {
+    Always,
+    Never,
+}
+
 /// An enum representing the possible values of an `PaymentIntent`'s `cancellation_reason` field.
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -586,6 +1149,505 @@ pub enum PaymentIntentConfirmationMethod {
     Manual,
 }
 
+/// An enum representing the possible values of an `FundingInstructionsBankTransferFinancialAddress`'s `supported_networks` field.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum FundingInstructionsBankTransferFinancialAddressSupportedNetworks {
+    Ach,
+    Bacs,
+    DomesticWireUs,
+    Fps,
+    Sepa,
+    Spei,
+    Swift,
+    Zengin,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
+}
+
+impl FundingInstructionsBankTransferFinancialAddressSupportedNetworks {
+    pub fn as_str(&self) -> &str {
+        match self {
+            FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Ach => "ach",
+            FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Bacs => "bacs",
+            FundingInstructionsBankTransferFinancialAddressSupportedNetworks::DomesticWireUs => {
+                "domestic_wire_us"
+            }
+            FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Fps => "fps",
+            FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Sepa => "sepa",
+            FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Spei => "spei",
+            FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Swift => "swift",
+            FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Zengin => "zengin",
+            FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Other(value) => {
+                value.as_str()
+            }
+        }
+    }
+}
+
+impl From<String> for FundingInstructionsBankTransferFinancialAddressSupportedNetworks {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "ach" => FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Ach,
+            "bacs" => FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Bacs,
+            "domestic_wire_us" => {
+                FundingInstructionsBankTransferFinancialAddressSupportedNetworks::DomesticWireUs
+            }
+            "fps" => FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Fps,
+            "sepa" => FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Sepa,
+            "spei" => FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Spei,
+            "swift" => FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Swift,
+            "zengin" => FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Zengin,
+            _ => FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Other(value),
+        }
+    }
+}
+
+impl AsRef<str> for FundingInstructionsBankTransferFinancialAddressSupportedNetworks {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FundingInstructionsBankTransferFinancialAddressSupportedNetworks {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for FundingInstructionsBankTransferFinancialAddressSupportedNetworks {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de>
+    for FundingInstructionsBankTransferFinancialAddressSupportedNetworks
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(FundingInstructionsBankTransferFinancialAddressSupportedNetworks::from(s))
+    }
+}
+
+/// An enum representing the possible values of an `FundingInstructionsBankTransferFinancialAddress`'s `type_` field.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum FundingInstructionsBankTransferFinancialAddressType {
+    Aba,
+    Iban,
+    SortCode,
+    Spei,
+    Swift,
+    Zengin,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
+}
+
+impl FundingInstructionsBankTransferFinancialAddressType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            FundingInstructionsBankTransferFinancialAddressType::Aba => "aba",
+            FundingInstructionsBankTransferFinancialAddressType::Iban => "iban",
+            FundingInstructionsBankTransferFinancialAddressType::SortCode => "sort_code",
+            FundingInstructionsBankTransferFinancialAddressType::Spei => "spei",
+            FundingInstructionsBankTransferFinancialAddressType::Swift => "swift",
+            FundingInstructionsBankTransferFinancialAddressType::Zengin => "zengin",
+            FundingInstructionsBankTransferFinancialAddressType::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for FundingInstructionsBankTransferFinancialAddressType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "aba" => FundingInstructionsBankTransferFinancialAddressType::Aba,
+            "iban" => FundingInstructionsBankTransferFinancialAddressType::Iban,
+            "sort_code" => FundingInstructionsBankTransferFinancialAddressType::SortCode,
+            "spei" => FundingInstructionsBankTransferFinancialAddressType::Spei,
+            "swift" => FundingInstructionsBankTransferFinancialAddressType::Swift,
+            "zengin" => FundingInstructionsBankTransferFinancialAddressType::Zengin,
+            _ => FundingInstructionsBankTransferFinancialAddressType::Other(value),
+        }
+    }
+}
+
+impl AsRef<str> for FundingInstructionsBankTransferFinancialAddressType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FundingInstructionsBankTransferFinancialAddressType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for FundingInstructionsBankTransferFinancialAddressType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for FundingInstructionsBankTransferFinancialAddressType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(FundingInstructionsBankTransferFinancialAddressType::from(s))
+    }
+}
+
+/// An enum representing the possible values of an `PaymentIntentNextActionDisplayBankTransferInstructions`'s `type_` field.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PaymentIntentNextActionDisplayBankTransferInstructionsType {
+    EuBankTransfer,
+    GbBankTransfer,
+    JpBankTransfer,
+    MxBankTransfer,
+    UsBankTransfer,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
+}
+
+impl PaymentIntentNextActionDisplayBankTransferInstructionsType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PaymentIntentNextActionDisplayBankTransferInstructionsType::EuBankTransfer => {
+                "eu_bank_transfer"
+            }
+            PaymentIntentNextActionDisplayBankTransferInstructionsType::GbBankTransfer => {
+                "gb_bank_transfer"
+            }
+            PaymentIntentNextActionDisplayBankTransferInstructionsType::JpBankTransfer => {
+                "jp_bank_transfer"
+            }
+            PaymentIntentNextActionDisplayBankTransferInstructionsType::MxBankTransfer => {
+                "mx_bank_transfer"
+            }
+            PaymentIntentNextActionDisplayBankTransferInstructionsType::UsBankTransfer => {
+                "us_bank_transfer"
+            }
+            PaymentIntentNextActionDisplayBankTransferInstructionsType::Other(value) => {
+                value.as_str()
+            }
+        }
+    }
+}
+
+impl From<String> for PaymentIntentNextActionDisplayBankTransferInstructionsType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "eu_bank_transfer" => {
+                PaymentIntentNextActionDisplayBankTransferInstructionsType::EuBankTransfer
+            }
+            "gb_bank_transfer" => {
+                PaymentIntentNextActionDisplayBankTransferInstructionsType::GbBankTransfer
+            }
+            "jp_bank_transfer" => {
+                PaymentIntentNextActionDisplayBankTransferInstructionsType::JpBankTransfer
+            }
+            "mx_bank_transfer" => {
+                PaymentIntentNextActionDisplayBankTransferInstructionsType::MxBankTransfer
+            }
+            "us_bank_transfer" => {
+                PaymentIntentNextActionDisplayBankTransferInstructionsType::UsBankTransfer
+            }
+            _ => PaymentIntentNextActionDisplayBankTransferInstructionsType::Other(value),
+        }
+    }
+}
+
+impl AsRef<str> for PaymentIntentNextActionDisplayBankTransferInstructionsType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentIntentNextActionDisplayBankTransferInstructionsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for PaymentIntentNextActionDisplayBankTransferInstructionsType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for PaymentIntentNextActionDisplayBankTransferInstructionsType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(PaymentIntentNextActionDisplayBankTransferInstructionsType::from(s))
+    }
+}
+
+/// An enum representing the possible values of an `PaymentIntentNextActionVerifyWithMicrodeposits`'s `microdeposit_type` field.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentIntentNextActionVerifyWithMicrodepositsMicrodepositType {
+    Amounts,
+    DescriptorCode,
+}
+
+/// An enum representing the possible values of an `PaymentIntentPaymentMethodOptionsCardMandateOptions`'s `amount_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentIntentPaymentMethodOptionsCardMandateOptionsAmountType {
+    Fixed,
+    Maximum,
+}
+
+impl PaymentIntentPaymentMethodOptionsCardMandateOptionsAmountType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentIntentPaymentMethodOptionsCardMandateOptionsAmountType::Fixed => "fixed",
+            PaymentIntentPaymentMethodOptionsCardMandateOptionsAmountType::Maximum => "maximum",
+        }
+    }
+}
+
+impl AsRef<str> for PaymentIntentPaymentMethodOptionsCardMandateOptionsAmountType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentIntentPaymentMethodOptionsCardMandateOptionsAmountType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `PaymentIntentPaymentMethodOptionsCardMandateOptions`'s `interval` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentIntentPaymentMethodOptionsCardMandateOptionsInterval {
+    Day,
+    Month,
+    Sporadic,
+    Week,
+    Year,
+}
+
+impl PaymentIntentPaymentMethodOptionsCardMandateOptionsInterval {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentIntentPaymentMethodOptionsCardMandateOptionsInterval::Day => "day",
+            PaymentIntentPaymentMethodOptionsCardMandateOptionsInterval::Month => "month",
+            PaymentIntentPaymentMethodOptionsCardMandateOptionsInterval::Sporadic => "sporadic",
+            PaymentIntentPaymentMethodOptionsCardMandateOptionsInterval::Week => "week",
+            PaymentIntentPaymentMethodOptionsCardMandateOptionsInterval::Year => "year",
+        }
+    }
+}
+
+impl AsRef<str> for PaymentIntentPaymentMethodOptionsCardMandateOptionsInterval {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentIntentPaymentMethodOptionsCardMandateOptionsInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `PaymentIntentPaymentMethodOptionsCardMandateOptions`'s `supported_types` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentIntentPaymentMethodOptionsCardMandateOptionsSupportedTypes {
+    India,
+}
+
+impl PaymentIntentPaymentMethodOptionsCardMandateOptionsSupportedTypes {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentIntentPaymentMethodOptionsCardMandateOptionsSupportedTypes::India => "india",
+        }
+    }
+}
+
+impl AsRef<str> for PaymentIntentPaymentMethodOptionsCardMandateOptionsSupportedTypes {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentIntentPaymentMethodOptionsCardMandateOptionsSupportedTypes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `PaymentIntentPaymentMethodOptionsCard`'s `request_three_d_secure` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentIntentPaymentMethodOptionsCardRequestThreeDSecure {
+    Any,
+    Automatic,
+    ChallengeOnly,
+}
+
+impl PaymentIntentPaymentMethodOptionsCardRequestThreeDSecure {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentIntentPaymentMethodOptionsCardRequestThreeDSecure::Any => "any",
+            PaymentIntentPaymentMethodOptionsCardRequestThreeDSecure::Automatic => "automatic",
+            PaymentIntentPaymentMethodOptionsCardRequestThreeDSecure::ChallengeOnly => {
+                "challenge_only"
+            }
+        }
+    }
+}
+
+impl AsRef<str> for PaymentIntentPaymentMethodOptionsCardRequestThreeDSecure {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentIntentPaymentMethodOptionsCardRequestThreeDSecure {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebit`'s `payment_schedule` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule {
+    Combined,
+    Interval,
+    Sporadic,
+}
+
+impl PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule::Combined => {
+                "combined"
+            }
+            PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule::Interval => {
+                "interval"
+            }
+            PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule::Sporadic => {
+                "sporadic"
+            }
+        }
+    }
+}
+
+impl AsRef<str> for PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebit`'s `transaction_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebitTransactionType {
+    Business,
+    Personal,
+}
+
+impl PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebitTransactionType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebitTransactionType::Business => {
+                "business"
+            }
+            PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebitTransactionType::Personal => {
+                "personal"
+            }
+        }
+    }
+}
+
+impl AsRef<str> for PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebitTransactionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentIntentPaymentMethodOptionsMandateOptionsAcssDebitTransactionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `PaymentIntentPaymentMethodOptionsUsBankAccount`'s `verification_method` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentIntentPaymentMethodOptionsVerificationMethod {
+    Automatic,
+    Instant,
+    Microdeposits,
+}
+
+impl PaymentIntentPaymentMethodOptionsVerificationMethod {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentIntentPaymentMethodOptionsVerificationMethod::Automatic => "automatic",
+            PaymentIntentPaymentMethodOptionsVerificationMethod::Instant => "instant",
+            PaymentIntentPaymentMethodOptionsVerificationMethod::Microdeposits => "microdeposits",
+        }
+    }
+}
+
+impl AsRef<str> for PaymentIntentPaymentMethodOptionsVerificationMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentIntentPaymentMethodOptionsVerificationMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `PaymentIntent`'s `setup_future_usage` field.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentIntentSetupFutureUsage {
+    OffSession,
+    OnSession,
+}
+
 /// An enum representing the possible values of an `PaymentIntent`'s `status` field.
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -0,0 +1,194 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::config::{Client, Response};
+use crate::ids::{PaymentLinkId, PriceId};
+use crate::params::{Expand, List, Metadata, Object};
+use crate::resources::{
+    CheckoutSessionCustomField, CheckoutSessionItem, Currency, CustomFieldLabelType,
+    CustomFieldType,
+};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "PaymentLink".
+///
+/// For more details see [https://stripe.com/docs/api/payment_links/payment_links/object](https://stripe.com/docs/api/payment_links/payment_links/object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct PaymentLink {
+    /// Unique identifier for the object.
+    pub id: PaymentLinkId,
+
+    /// Whether the payment link's `url` is active.
+    ///
+    /// If `false`, customers visiting the URL will be shown a page saying that the link has been deactivated.
+    pub active: bool,
+
+    /// Whether user redeemable promotion codes are enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_promotion_codes: Option<bool>,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
+    /// Collect additional information from your customer using custom fields.
+    ///
+    /// Up to 2 fields are supported.
+    #[serde(default)]
+    pub custom_fields: Vec<CheckoutSessionCustomField>,
+
+    /// The line items representing what is being sold.
+    ///
+    /// [Expand](https://stripe.com/docs/api/expanding_objects) this field to include it in the response.
+    #[serde(default)]
+    pub line_items: List<CheckoutSessionItem>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
+    pub metadata: Metadata,
+
+    /// The public URL that can be shared with customers.
+    pub url: String,
+}
+
+impl PaymentLink {
+    /// Creates a payment link.
+    pub fn create(client: &Client, params: CreatePaymentLink<'_>) -> Response<PaymentLink> {
+        client.post_form("/payment_links", &params)
+    }
+
+    /// Retrieve a payment link.
+    pub fn retrieve(client: &Client, id: &PaymentLinkId, expand: &[&str]) -> Response<PaymentLink> {
+        client.get_query(&format!("/payment_links/{}", id), &Expand { expand })
+    }
+}
+
+impl Object for PaymentLink {
+    type Id = PaymentLinkId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "payment_link"
+    }
+}
+
+/// The parameters for `PaymentLink::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreatePaymentLink<'a> {
+    /// Enables user redeemable promotion codes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_promotion_codes: Option<bool>,
+
+    /// Collect additional information from your customer using custom fields.
+    ///
+    /// Up to 2 fields are supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CreatePaymentLinkCustomFields>>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The line items representing what is being sold.
+    ///
+    /// Each line item represents an item being sold.
+    /// Up to 20 line items are supported.
+    pub line_items: Vec<CreatePaymentLinkLineItems>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> CreatePaymentLink<'a> {
+    pub fn new(line_items: Vec<CreatePaymentLinkLineItems>) -> Self {
+        CreatePaymentLink {
+            allow_promotion_codes: Default::default(),
+            custom_fields: Default::default(),
+            expand: Default::default(),
+            line_items,
+            metadata: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CreatePaymentLinkCustomFields {
+    /// Configuration for `type=dropdown` fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dropdown: Option<CreatePaymentLinkCustomFieldsDropdown>,
+
+    /// String of your choice that your integration can use to reconcile this field.
+    ///
+    /// Must be unique to this field, alphanumeric, and up to 200 characters.
+    pub key: String,
+
+    /// The label for the field, displayed to the customer.
+    pub label: CreatePaymentLinkCustomFieldsLabel,
+
+    /// Whether the customer is required to complete the field before completing the Checkout Session.
+    ///
+    /// Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+
+    /// The type of the field.
+    #[serde(rename = "type")]
+    pub type_: CustomFieldType,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CreatePaymentLinkLineItems {
+    /// The ID of the [Price](https://stripe.com/docs/api/prices) or [Plan](https://stripe.com/docs/api/plans) object.
+    pub price: PriceId,
+
+    /// The quantity of the line item being purchased.
+    pub quantity: u64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreatePaymentLinkCustomFieldsDropdown {
+    /// The options available for the customer to select.
+    ///
+    /// Up to 200 options allowed.
+    pub options: Vec<CreatePaymentLinkCustomFieldsDropdownOptions>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CreatePaymentLinkCustomFieldsLabel {
+    /// Custom text for the label, displayed to the customer.
+    ///
+    /// Up to 50 characters.
+    pub custom: String,
+
+    /// The type of the label.
+    #[serde(rename = "type")]
+    pub type_: CustomFieldLabelType,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreatePaymentLinkCustomFieldsDropdownOptions {
+    /// The label for the option, displayed to the customer.
+    ///
+    /// Up to 100 characters.
+    pub label: String,
+
+    /// The value for this option, not displayed to the customer, used by your integration to reconcile the option selected by the customer.
+    ///
+    /// Must be unique to this option, alphanumeric, and up to 100 characters.
+    pub value: String,
+}
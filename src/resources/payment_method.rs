@@ -5,13 +5,16 @@
 use crate::config::{Client, Response};
 use crate::ids::{CustomerId, PaymentMethodId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, Timestamp};
-use crate::resources::{Address, BillingDetails, Customer, PaymentMethodDetails};
+use crate::resources::{
+    AccountHolderType, Address, BillingDetails, Customer, PaymentMethodDetails,
+};
 use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "PaymentMethod".
 ///
 /// For more details see [https://stripe.com/docs/api/payment_methods/object](https://stripe.com/docs/api/payment_methods/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct PaymentMethod {
     /// Unique identifier for the object.
     pub id: PaymentMethodId,
@@ -50,6 +53,7 @@ pub struct PaymentMethod {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -61,12 +65,15 @@ pub struct PaymentMethod {
     /// It contains additional information specific to the PaymentMethod type.
     #[serde(rename = "type")]
     pub type_: PaymentMethodType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account: Option<PaymentMethodUsBankAccount>,
 }
 
 impl PaymentMethod {
     /// Returns a list of PaymentMethods for a given Customer.
     pub fn list(client: &Client, params: ListPaymentMethods<'_>) -> Response<List<PaymentMethod>> {
-        client.get_query("/payment_methods", &params)
+        List::get_with_params(client, "/payment_methods", &params)
     }
 
     /// Creates a PaymentMethod object.
@@ -166,6 +173,10 @@ pub struct CardDetails {
     /// The last four digits of the card.
     pub last4: String,
 
+    /// Contains information about card networks that can be used to process the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub networks: Option<PaymentMethodCardNetworks>,
+
     /// Contains details on how this Card maybe be used for 3D Secure authentication.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub three_d_secure_usage: Option<ThreeDSecureUsage>,
@@ -175,6 +186,17 @@ pub struct CardDetails {
     pub wallet: Option<WalletDetails>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentMethodCardNetworks {
+    /// All available networks for the card.
+    #[serde(default)]
+    pub available: Vec<String>,
+
+    /// The preferred network for the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred: Option<String>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PaymentMethodCardChecks {
     /// If a address line1 was provided, results of the check, one of `pass`, `fail`, `unavailable`, or `unchecked`.
@@ -219,13 +241,16 @@ pub struct WalletDetails {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub google_pay: Option<WalletGooglePay>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<WalletLink>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub masterpass: Option<WalletMasterpass>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub samsung_pay: Option<WalletSamsungPay>,
 
-    /// The type of the card wallet, one of `amex_express_checkout`, `apple_pay`, `google_pay`, `masterpass`, `samsung_pay`, or `visa_checkout`.
+    /// The type of the card wallet, one of `amex_express_checkout`, `apple_pay`, `google_pay`, `link`, `masterpass`, `samsung_pay`, or `visa_checkout`.
     ///
     /// An additional hash is included on the Wallet subhash with a name matching this value.
     /// It contains additional information specific to the card wallet type.
@@ -245,6 +270,9 @@ pub struct WalletApplePay {}
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct WalletGooglePay {}
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WalletLink {}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct WalletMasterpass {
     /// Owner's verified billing address.
@@ -356,6 +384,41 @@ pub struct PaymentMethodSepaDebit {
     pub last4: Option<String>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PaymentMethodUsBankAccount {
+    /// Account holder type: individual or company.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_type: Option<AccountHolderType>,
+
+    /// Account type: checkings or savings.
+    ///
+    /// Defaults to checking if omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<PaymentMethodUsBankAccountAccountType>,
+
+    /// The name of the bank.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_name: Option<String>,
+
+    /// The ID of the Financial Connections Account used to create the payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub financial_connections_account: Option<String>,
+
+    /// Uniquely identifies this particular bank account.
+    ///
+    /// You can use this attribute to check whether two bank accounts are the same.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+
+    /// Last four digits of the bank account number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last4: Option<String>,
+
+    /// Routing number of the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_number: Option<String>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ThreeDSecureUsage {
     /// Whether 3D Secure is supported on this card.
@@ -413,6 +476,10 @@ pub struct CreatePaymentMethod<'a> {
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub type_: Option<PaymentMethodType>,
+
+    /// If this is an `us_bank_account` PaymentMethod, this hash contains details about the US bank account payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account: Option<CreatePaymentMethodUsBankAccount>,
 }
 
 impl<'a> CreatePaymentMethod<'a> {
@@ -428,6 +495,7 @@ impl<'a> CreatePaymentMethod<'a> {
             payment_method: Default::default(),
             sepa_debit: Default::default(),
             type_: Default::default(),
+            us_bank_account: Default::default(),
         }
     }
 }
@@ -543,6 +611,33 @@ pub struct CreatePaymentMethodSepaDebit {
     pub iban: String,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreatePaymentMethodUsBankAccount {
+    /// Account holder type: individual or company.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_type: Option<AccountHolderType>,
+
+    /// Account number of the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<String>,
+
+    /// Account type: checkings or savings.
+    ///
+    /// Defaults to checking if omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<PaymentMethodUsBankAccountAccountType>,
+
+    /// The ID of a Financial Connections Account to use as a payment method.
+    ///
+    /// When set, `account_number` and `routing_number` are not needed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub financial_connections_account: Option<String>,
+
+    /// Routing number of the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_number: Option<String>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdatePaymentMethodAuBecsDebit {}
 
@@ -847,6 +942,7 @@ pub enum PaymentMethodType {
     Fpx,
     Ideal,
     SepaDebit,
+    UsBankAccount,
 }
 
 impl PaymentMethodType {
@@ -857,6 +953,7 @@ impl PaymentMethodType {
             PaymentMethodType::Fpx => "fpx",
             PaymentMethodType::Ideal => "ideal",
             PaymentMethodType::SepaDebit => "sepa_debit",
+            PaymentMethodType::UsBankAccount => "us_bank_account",
         }
     }
 }
@@ -883,6 +980,7 @@ pub enum PaymentMethodTypeFilter {
     Fpx,
     Ideal,
     SepaDebit,
+    UsBankAccount,
 }
 
 impl PaymentMethodTypeFilter {
@@ -894,6 +992,7 @@ impl PaymentMethodTypeFilter {
             PaymentMethodTypeFilter::Fpx => "fpx",
             PaymentMethodTypeFilter::Ideal => "ideal",
             PaymentMethodTypeFilter::SepaDebit => "sepa_debit",
+            PaymentMethodTypeFilter::UsBankAccount => "us_bank_account",
         }
     }
 }
@@ -910,6 +1009,35 @@ impl std::fmt::Display for PaymentMethodTypeFilter {
     }
 }
 
+/// An enum representing the possible values of an `PaymentMethodUsBankAccount`'s `account_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodUsBankAccountAccountType {
+    Checking,
+    Savings,
+}
+
+impl PaymentMethodUsBankAccountAccountType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentMethodUsBankAccountAccountType::Checking => "checking",
+            PaymentMethodUsBankAccountAccountType::Savings => "savings",
+        }
+    }
+}
+
+impl AsRef<str> for PaymentMethodUsBankAccountAccountType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentMethodUsBankAccountAccountType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `WalletDetails`'s `type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -917,6 +1045,7 @@ pub enum WalletDetailsType {
     AmexExpressCheckout,
     ApplePay,
     GooglePay,
+    Link,
     Masterpass,
     SamsungPay,
     VisaCheckout,
@@ -928,6 +1057,7 @@ impl WalletDetailsType {
             WalletDetailsType::AmexExpressCheckout => "amex_express_checkout",
             WalletDetailsType::ApplePay => "apple_pay",
             WalletDetailsType::GooglePay => "google_pay",
+            WalletDetailsType::Link => "link",
             WalletDetailsType::Masterpass => "masterpass",
             WalletDetailsType::SamsungPay => "samsung_pay",
             WalletDetailsType::VisaCheckout => "visa_checkout",
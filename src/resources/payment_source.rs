@@ -1,6 +1,8 @@
 use crate::ids::{PaymentSourceId, SourceId, TokenId};
-use crate::params::Object;
-use crate::resources::{Account, AlipayAccount, BankAccount, Card, Currency, Source};
+use crate::params::{Object, Secret};
+use crate::resources::{
+    Account, AlipayAccount, BankAccount, BitcoinReceiver, Card, Currency, Source,
+};
 use serde::ser::SerializeStruct;
 use serde_derive::{Deserialize, Serialize};
 
@@ -28,15 +30,70 @@ pub enum PaymentSourceParams {
 /// Not to be confused with `Source` which represents a "generic" payment method
 /// returned by the `Source::get` (which could still be a credit card, etc)
 /// but is not necessarily attached to either a customer or charge.
-
+///
+/// Payment sources of a type the library doesn't know about deserialize as `PaymentSource::Other`.
 #[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(tag = "object", rename_all = "snake_case")]
+#[serde(remote = "Self", tag = "object", rename_all = "snake_case")]
 pub enum PaymentSource {
     Card(Card),
     Source(Source),
     Account(Account),
     BankAccount(BankAccount),
     AlipayAccount(AlipayAccount),
+    BitcoinReceiver(BitcoinReceiver),
+    /// A payment source of a type not yet supported by the library.
+    ///
+    /// `object` is the type reported by Stripe and `data` is the raw payload of the source.
+    #[serde(skip)]
+    Other {
+        object: String,
+        data: serde_json::Value,
+    },
+}
+
+impl PaymentSource {
+    /// Whether `object` is the type of one of the variants above, other than `Other`.
+    ///
+    /// This must be kept in sync with the variants.
+    fn is_known_object(object: &str) -> bool {
+        matches!(
+            object,
+            "card" | "source" | "account" | "bank_account" | "alipay_account" | "bitcoin_receiver"
+        )
+    }
+}
+
+// N.B. The derived impls (generated as inherent functions by `remote = "Self"`)
+//      are only used once the `object` type is known to be one of the variants.
+impl<'de> serde::Deserialize<'de> for PaymentSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let data: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+        let object = match data.get("object").and_then(|object| object.as_str()) {
+            Some(object) => object.to_string(),
+            None => return Err(D::Error::missing_field("object")),
+        };
+        if !PaymentSource::is_known_object(&object) {
+            return Ok(PaymentSource::Other { object, data });
+        }
+        PaymentSource::deserialize(data).map_err(D::Error::custom)
+    }
+}
+
+impl serde::Serialize for PaymentSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        match self {
+            PaymentSource::Other { data, .. } => data.serialize(serializer),
+            source => PaymentSource::serialize(source, serializer),
+        }
+    }
 }
 
 impl Object for PaymentSource {
@@ -48,6 +105,10 @@ impl Object for PaymentSource {
             PaymentSource::Account(x) => PaymentSourceId::Account(x.id()),
             PaymentSource::BankAccount(x) => PaymentSourceId::BankAccount(x.id()),
             PaymentSource::AlipayAccount(x) => PaymentSourceId::AlipayAccount(x.id()),
+            PaymentSource::BitcoinReceiver(x) => PaymentSourceId::BitcoinReceiver(x.id()),
+            PaymentSource::Other { data, .. } => PaymentSourceId::Other(
+                data.get("id").and_then(|id| id.as_str()).unwrap_or_default().to_string(),
+            ),
         }
     }
     fn object(&self) -> &'static str {
@@ -57,11 +118,14 @@ impl Object for PaymentSource {
             PaymentSource::Account(x) => x.object(),
             PaymentSource::BankAccount(x) => x.object(),
             PaymentSource::AlipayAccount(x) => x.object(),
+            PaymentSource::BitcoinReceiver(x) => x.object(),
+            // N.B. The actual type is only known at runtime, as the `object` of the variant.
+            PaymentSource::Other { .. } => "unknown",
         }
     }
 }
 
-#[derive(Clone, Debug, Default, Deserialize)]
+#[derive(Clone, Default, Deserialize)]
 pub struct BankAccountParams<'a> {
     pub country: &'a str,
     pub currency: Currency,
@@ -71,6 +135,20 @@ pub struct BankAccountParams<'a> {
     pub account_number: &'a str,
 }
 
+// N.B. The account number is redacted from `Debug` output so that it doesn't end up in logs.
+impl<'a> std::fmt::Debug for BankAccountParams<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BankAccountParams")
+            .field("country", &self.country)
+            .field("currency", &self.currency)
+            .field("account_holder_name", &self.account_holder_name)
+            .field("account_holder_type", &self.account_holder_type)
+            .field("routing_number", &self.routing_number)
+            .field("account_number", &Secret::default())
+            .finish()
+    }
+}
+
 impl<'a> serde::ser::Serialize for BankAccountParams<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -87,7 +165,7 @@ impl<'a> serde::ser::Serialize for BankAccountParams<'a> {
     }
 }
 
-#[derive(Clone, Debug, Default, Deserialize)]
+#[derive(Clone, Default, Deserialize)]
 pub struct CardParams<'a> {
     pub exp_month: &'a str, // eg. "12"
     pub exp_year: &'a str,  // eg. "17" or 2017"
@@ -97,6 +175,19 @@ pub struct CardParams<'a> {
     pub cvc: Option<&'a str>,  // card security code
 }
 
+// N.B. The card number and security code are redacted from `Debug` output as well.
+impl<'a> std::fmt::Debug for CardParams<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CardParams")
+            .field("exp_month", &self.exp_month)
+            .field("exp_year", &self.exp_year)
+            .field("number", &Secret::default())
+            .field("name", &self.name)
+            .field("cvc", &self.cvc.map(|_| Secret::default()))
+            .finish()
+    }
+}
+
 impl<'a> serde::ser::Serialize for CardParams<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -3,15 +3,16 @@
 // ======================================
 
 use crate::config::{Client, Response};
-use crate::ids::PayoutId;
+use crate::ids::{ExternalAccountId, PayoutId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
-use crate::resources::{BalanceTransaction, BankAccount, Card, Currency};
+use crate::resources::{BalanceTransaction, Currency, ExternalAccount};
 use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "Payout".
 ///
 /// For more details see [https://stripe.com/docs/api/payouts/object](https://stripe.com/docs/api/payouts/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Payout {
     /// Unique identifier for the object.
     pub id: PayoutId,
@@ -49,7 +50,7 @@ pub struct Payout {
 
     /// ID of the bank account or card the payout was sent to.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination: Option<Expandable<PayoutDestination>>,
+    pub destination: Option<Expandable<ExternalAccount>>,
 
     /// If the payout failed or was canceled, this will be the ID of the balance transaction that reversed the initial balance transaction, and puts the funds from the failed payout back in your balance.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -59,7 +60,7 @@ pub struct Payout {
     ///
     /// See [Types of payout failures](https://stripe.com/docs/api#payout_failures) for a list of failure codes.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_code: Option<String>,
+    pub failure_code: Option<PayoutFailureCode>,
 
     /// Message to user further explaining reason for payout failure if available.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -71,6 +72,7 @@ pub struct Payout {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
     /// The method used to send this payout, which can be `standard` or `instant`.
@@ -93,7 +95,7 @@ pub struct Payout {
     /// A payout is `pending` until it is submitted to the bank, when it becomes `in_transit`.
     /// The status then changes to `paid` if the transaction goes through, or to `failed` or `canceled` (within 5 business days).
     /// Some failed payouts may initially show as `paid` but then change to `failed`.
-    pub status: String,
+    pub status: PayoutStatus,
 
     /// Can be `bank_account` or `card`.
     #[serde(rename = "type")]
@@ -105,7 +107,7 @@ impl Payout {
     ///
     /// The payouts are returned in sorted order, with the most recently created payouts appearing first.
     pub fn list(client: &Client, params: ListPayouts<'_>) -> Response<List<Payout>> {
-        client.get_query("/payouts", &params)
+        List::get_with_params(client, "/payouts", &params)
     }
 
     /// To send funds to your own bank account, you create a new payout object.
@@ -159,6 +161,12 @@ pub struct CreatePayout<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
 
+    /// The ID of a bank account or a card to send the payout to.
+    ///
+    /// If no destination is supplied, the default external account for the specified currency will be used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<ExternalAccountId>,
+
     /// Specifies which fields in the response should be expanded.
     #[serde(skip_serializing_if = "Expand::is_empty")]
     pub expand: &'a [&'a str],
@@ -202,6 +210,7 @@ impl<'a> CreatePayout<'a> {
             amount,
             currency,
             description: Default::default(),
+            destination: Default::default(),
             expand: Default::default(),
             metadata: Default::default(),
             method: Default::default(),
@@ -285,11 +294,106 @@ impl<'a> UpdatePayout<'a> {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(tag = "object", rename_all = "snake_case")]
-pub enum PayoutDestination {
-    BankAccount(BankAccount),
-    Card(Card),
+/// An enum representing the possible values of an `Payout`'s `failure_code` field.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PayoutFailureCode {
+    AccountClosed,
+    AccountFrozen,
+    BankAccountRestricted,
+    BankOwnershipChanged,
+    CouldNotProcess,
+    DebitNotAuthorized,
+    Declined,
+    IncorrectAccountHolderAddress,
+    IncorrectAccountHolderName,
+    IncorrectAccountHolderTaxId,
+    InsufficientFunds,
+    InvalidAccountNumber,
+    InvalidCurrency,
+    NoAccount,
+    UnsupportedCard,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
+}
+
+impl PayoutFailureCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PayoutFailureCode::AccountClosed => "account_closed",
+            PayoutFailureCode::AccountFrozen => "account_frozen",
+            PayoutFailureCode::BankAccountRestricted => "bank_account_restricted",
+            PayoutFailureCode::BankOwnershipChanged => "bank_ownership_changed",
+            PayoutFailureCode::CouldNotProcess => "could_not_process",
+            PayoutFailureCode::DebitNotAuthorized => "debit_not_authorized",
+            PayoutFailureCode::Declined => "declined",
+            PayoutFailureCode::IncorrectAccountHolderAddress => "incorrect_account_holder_address",
+            PayoutFailureCode::IncorrectAccountHolderName => "incorrect_account_holder_name",
+            PayoutFailureCode::IncorrectAccountHolderTaxId => "incorrect_account_holder_tax_id",
+            PayoutFailureCode::InsufficientFunds => "insufficient_funds",
+            PayoutFailureCode::InvalidAccountNumber => "invalid_account_number",
+            PayoutFailureCode::InvalidCurrency => "invalid_currency",
+            PayoutFailureCode::NoAccount => "no_account",
+            PayoutFailureCode::UnsupportedCard => "unsupported_card",
+            PayoutFailureCode::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for PayoutFailureCode {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "account_closed" => PayoutFailureCode::AccountClosed,
+            "account_frozen" => PayoutFailureCode::AccountFrozen,
+            "bank_account_restricted" => PayoutFailureCode::BankAccountRestricted,
+            "bank_ownership_changed" => PayoutFailureCode::BankOwnershipChanged,
+            "could_not_process" => PayoutFailureCode::CouldNotProcess,
+            "debit_not_authorized" => PayoutFailureCode::DebitNotAuthorized,
+            "declined" => PayoutFailureCode::Declined,
+            "incorrect_account_holder_address" => PayoutFailureCode::IncorrectAccountHolderAddress,
+            "incorrect_account_holder_name" => PayoutFailureCode::IncorrectAccountHolderName,
+            "incorrect_account_holder_tax_id" => PayoutFailureCode::IncorrectAccountHolderTaxId,
+            "insufficient_funds" => PayoutFailureCode::InsufficientFunds,
+            "invalid_account_number" => PayoutFailureCode::InvalidAccountNumber,
+            "invalid_currency" => PayoutFailureCode::InvalidCurrency,
+            "no_account" => PayoutFailureCode::NoAccount,
+            "unsupported_card" => PayoutFailureCode::UnsupportedCard,
+            _ => PayoutFailureCode::Other(value),
+        }
+    }
+}
+
+impl AsRef<str> for PayoutFailureCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PayoutFailureCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for PayoutFailureCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for PayoutFailureCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(PayoutFailureCode::from(s))
+    }
 }
 
 /// An enum representing the possible values of an `CreatePayout`'s `method` field.
@@ -352,6 +456,78 @@ impl std::fmt::Display for PayoutSourceType {
     }
 }
 
+/// An enum representing the possible values of an `Payout`'s `status` field.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PayoutStatus {
+    Canceled,
+    Failed,
+    InTransit,
+    Paid,
+    Pending,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
+}
+
+impl PayoutStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PayoutStatus::Canceled => "canceled",
+            PayoutStatus::Failed => "failed",
+            PayoutStatus::InTransit => "in_transit",
+            PayoutStatus::Paid => "paid",
+            PayoutStatus::Pending => "pending",
+            PayoutStatus::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for PayoutStatus {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "canceled" => PayoutStatus::Canceled,
+            "failed" => PayoutStatus::Failed,
+            "in_transit" => PayoutStatus::InTransit,
+            "paid" => PayoutStatus::Paid,
+            "pending" => PayoutStatus::Pending,
+            _ => PayoutStatus::Other(value),
+        }
+    }
+}
+
+impl AsRef<str> for PayoutStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PayoutStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for PayoutStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for PayoutStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(PayoutStatus::from(s))
+    }
+}
+
 /// An enum representing the possible values of an `Payout`'s `type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -1,7 +1,6 @@
 use crate::config::{Client, Response};
-use crate::ids::{PayoutDestinationId, PayoutId};
-use crate::params::Object;
-use crate::resources::{Payout, PayoutDestination};
+use crate::ids::PayoutId;
+use crate::resources::Payout;
 
 impl Payout {
     /// Cancels the payout.
@@ -11,19 +10,3 @@ impl Payout {
         client.post(&format!("/payouts/{}/cancel", id))
     }
 }
-
-impl Object for PayoutDestination {
-    type Id = PayoutDestinationId;
-    fn id(&self) -> Self::Id {
-        match self {
-            PayoutDestination::BankAccount(x) => PayoutDestinationId::BankAccount(x.id()),
-            PayoutDestination::Card(x) => PayoutDestinationId::Card(x.id()),
-        }
-    }
-    fn object(&self) -> &'static str {
-        match self {
-            PayoutDestination::BankAccount(x) => x.object(),
-            PayoutDestination::Card(x) => x.object(),
-        }
-    }
-}
@@ -11,6 +11,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/persons/object](https://stripe.com/docs/api/persons/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Person {
     /// Unique identifier for the object.
     pub id: PersonId,
@@ -191,25 +192,30 @@ pub struct PersonRequirements {
     /// Fields that need to be collected to keep the person's account enabled.
     ///
     /// If not collected by the account's `current_deadline`, these fields appear in `past_due` as well, and the account is disabled.
+    #[serde(default)]
     pub currently_due: Vec<String>,
 
     /// The fields that need to be collected again because validation or verification failed for some reason.
+    #[serde(default)]
     pub errors: Vec<AccountRequirementsError>,
 
     /// Fields that need to be collected assuming all volume thresholds are reached.
     ///
     /// As fields are needed, they are moved to `currently_due` and the account's `current_deadline` is set.
+    #[serde(default)]
     pub eventually_due: Vec<String>,
 
     /// Fields that weren't collected by the account's `current_deadline`.
     ///
     /// These fields need to be collected to enable payouts for the person's account.
+    #[serde(default)]
     pub past_due: Vec<String>,
 
     /// Fields that may become required depending on the results of verification or review.
     ///
     /// An empty array unless an asynchronous verification is pending.
     /// If verification fails, the fields in this array become required and move to `currently_due` or `past_due`.
+    #[serde(default)]
     pub pending_verification: Vec<String>,
 }
 
@@ -4,6 +4,7 @@ use serde_derive::{Deserialize, Serialize};
 
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Account {
     pub id: AccountId,
 }
@@ -21,6 +22,7 @@ impl Object for Account {
 
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Application {
     pub id: (),
 }
@@ -38,6 +40,7 @@ impl Object for Application {
 
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct ApplicationFee {
     pub id: ApplicationFeeId,
 }
@@ -55,6 +58,7 @@ impl Object for ApplicationFee {
 
 #[cfg(not(feature = "checkout"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct CheckoutSession {
     pub id: CheckoutSessionId,
 }
@@ -72,6 +76,7 @@ impl Object for CheckoutSession {
 
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct ConnectCollectionTransfer {
     pub id: (),
 }
@@ -89,6 +94,7 @@ impl Object for ConnectCollectionTransfer {
 
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Coupon {
     pub id: CouponId,
 }
@@ -106,15 +112,17 @@ impl Object for Coupon {
 
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Discount {
-    pub id: (),
+    #[serde(default)]
+    pub id: Option<DiscountId>,
 }
 
 #[cfg(not(feature = "billing"))]
 impl Object for Discount {
-    type Id = ();
+    type Id = DiscountId;
     fn id(&self) -> Self::Id {
-        self.id.clone()
+        self.id.clone().unwrap_or_else(DiscountId::none)
     }
     fn object(&self) -> &'static str {
         "discount"
@@ -123,6 +131,7 @@ impl Object for Discount {
 
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct ApplicationFeeRefund {
     pub id: ApplicationFeeRefundId,
 }
@@ -140,6 +149,7 @@ impl Object for ApplicationFeeRefund {
 
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Invoice {
     pub id: InvoiceId,
 }
@@ -157,6 +167,7 @@ impl Object for Invoice {
 
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct InvoiceItem {
     pub id: InvoiceItemId,
 }
@@ -174,6 +185,7 @@ impl Object for InvoiceItem {
 
 #[cfg(not(feature = "issuing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct IssuingAuthorization {
     pub id: IssuingAuthorizationId,
 }
@@ -191,6 +203,7 @@ impl Object for IssuingAuthorization {
 
 #[cfg(not(feature = "issuing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct IssuingCard {
     pub id: IssuingCardId,
 }
@@ -208,6 +221,7 @@ impl Object for IssuingCard {
 
 #[cfg(not(feature = "issuing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct IssuingCardholder {
     pub id: IssuingCardholderId,
 }
@@ -225,6 +239,7 @@ impl Object for IssuingCardholder {
 
 #[cfg(not(feature = "issuing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct IssuingDispute {
     pub id: IssuingDisputeId,
 }
@@ -242,6 +257,7 @@ impl Object for IssuingDispute {
 
 #[cfg(not(feature = "issuing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct IssuingTransaction {
     pub id: IssuingTransactionId,
 }
@@ -259,6 +275,7 @@ impl Object for IssuingTransaction {
 
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct InvoiceLineItem {
     pub id: InvoiceLineItemId,
 }
@@ -276,6 +293,7 @@ impl Object for InvoiceLineItem {
 
 #[cfg(not(feature = "orders"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Order {
     pub id: OrderId,
 }
@@ -293,6 +311,7 @@ impl Object for Order {
 
 #[cfg(not(feature = "orders"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct OrderItem {
     pub id: (),
 }
@@ -310,6 +329,7 @@ impl Object for OrderItem {
 
 #[cfg(not(feature = "orders"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct OrderReturn {
     pub id: OrderReturnId,
 }
@@ -327,6 +347,7 @@ impl Object for OrderReturn {
 
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Person {
     pub id: PersonId,
 }
@@ -344,6 +365,7 @@ impl Object for Person {
 
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Plan {
     pub id: PlanId,
 }
@@ -359,8 +381,36 @@ impl Object for Plan {
     }
 }
 
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Price {
+    pub id: PriceId,
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for Price {
+    type Id = PriceId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "price"
+    }
+}
+
+#[cfg(not(feature = "billing"))]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceTaxBehavior {
+    Exclusive,
+    Inclusive,
+    Unspecified,
+}
+
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Recipient {
     pub id: RecipientId,
 }
@@ -378,6 +428,7 @@ impl Object for Recipient {
 
 #[cfg(not(feature = "fraud"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Review {
     pub id: ReviewId,
 }
@@ -395,6 +446,7 @@ impl Object for Review {
 
 #[cfg(not(feature = "sigma"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct ScheduledQueryRun {
     pub id: ScheduledQueryRunId,
 }
@@ -412,6 +464,7 @@ impl Object for ScheduledQueryRun {
 
 #[cfg(not(feature = "orders"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Sku {
     pub id: SkuId,
 }
@@ -429,6 +482,7 @@ impl Object for Sku {
 
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Subscription {
     pub id: SubscriptionId,
 }
@@ -446,6 +500,7 @@ impl Object for Subscription {
 
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct SubscriptionItem {
     pub id: SubscriptionItemId,
 }
@@ -463,6 +518,7 @@ impl Object for SubscriptionItem {
 
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct SubscriptionSchedule {
     pub id: SubscriptionScheduleId,
 }
@@ -480,6 +536,7 @@ impl Object for SubscriptionSchedule {
 
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct SubscriptionScheduleRevision {
     pub id: (),
 }
@@ -497,6 +554,7 @@ impl Object for SubscriptionScheduleRevision {
 
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct TaxId {
     pub id: TaxIdId,
 }
@@ -514,6 +572,7 @@ impl Object for TaxId {
 
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct TaxRate {
     pub id: TaxRateId,
 }
@@ -531,6 +590,7 @@ impl Object for TaxRate {
 
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Topup {
     pub id: TopupId,
 }
@@ -548,6 +608,7 @@ impl Object for Topup {
 
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Transfer {
     pub id: TransferId,
 }
@@ -565,6 +626,7 @@ impl Object for Transfer {
 
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct TransferReversal {
     pub id: TransferReversalId,
 }
@@ -582,6 +644,7 @@ impl Object for TransferReversal {
 
 #[cfg(not(feature = "webhook-endpoints"))]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct WebhookEndpoint {
     pub id: WebhookEndpointId,
 }
@@ -14,6 +14,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/plans/object](https://stripe.com/docs/api/plans/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Plan {
     /// Unique identifier for the object.
     pub id: PlanId,
@@ -128,7 +129,7 @@ pub struct Plan {
 impl Plan {
     /// Returns a list of your plans.
     pub fn list(client: &Client, params: ListPlans<'_>) -> Response<List<Plan>> {
-        client.get_query("/plans", &params)
+        List::get_with_params(client, "/plans", &params)
     }
 
     /// You can create plans using the API, or in the Stripe [Dashboard](https://dashboard.stripe.com/subscriptions/products).
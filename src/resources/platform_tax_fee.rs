@@ -7,6 +7,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "PlatformTax".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct PlatformTaxFee {
     /// The Connected account that incurred this charge.
     pub account: String,
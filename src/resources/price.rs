@@ -9,11 +9,13 @@ use crate::params::{
 };
 use crate::resources::{CreateProduct, Currency, Product, UpTo};
 use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// The resource representing a Stripe "Price".
 ///
 /// For more details see [https://stripe.com/docs/api/prices/object](https://stripe.com/docs/api/prices/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Price {
     /// Unique identifier for the object.
     pub id: PriceId,
@@ -42,6 +44,12 @@ pub struct Price {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
 
+    /// Prices defined in each available currency option.
+    ///
+    /// Each key must be a three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html) and a [supported currency](https://stripe.com/docs/currencies).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_options: Option<HashMap<Currency, CurrencyOption>>,
+
     // Always true for a deleted object
     #[serde(default)]
     pub deleted: bool,
@@ -72,6 +80,13 @@ pub struct Price {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recurring: Option<Recurring>,
 
+    /// Specifies whether the price is considered inclusive of taxes or exclusive of taxes.
+    ///
+    /// One of `inclusive`, `exclusive`, or `unspecified`.
+    /// Once specified as either `inclusive` or `exclusive`, it cannot be changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<PriceTaxBehavior>,
+
     /// Each element represents a pricing tier.
     ///
     /// This parameter requires `billing_scheme` to be set to `tiered`.
@@ -109,7 +124,7 @@ pub struct Price {
 impl Price {
     /// Returns a list of your prices.
     pub fn list(client: &Client, params: ListPrices<'_>) -> Response<List<Price>> {
-        client.get_query("/prices", &params)
+        List::get_with_params(client, "/prices", &params)
     }
 
     /// Creates a new price for an existing product.
@@ -142,6 +157,35 @@ impl Object for Price {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CurrencyOption {
+    /// Only required if a [default tax behavior](https://stripe.com/docs/tax/products-prices-tax-categories-tax-behavior#setting-a-default-tax-behavior-(recommended)) was not provided in the Stripe Tax settings.
+    ///
+    /// Specifies whether the price is considered inclusive of taxes or exclusive of taxes.
+    /// One of `inclusive`, `exclusive`, or `unspecified`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<PriceTaxBehavior>,
+
+    /// Each element represents a pricing tier.
+    ///
+    /// This parameter requires `billing_scheme` to be set to `tiered`.
+    /// See also the documentation for `billing_scheme`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers: Option<Vec<PriceTier>>,
+
+    /// The unit amount in cents to be charged, represented as a whole integer if possible.
+    ///
+    /// Only set if `billing_scheme=per_unit`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+
+    /// The unit amount in cents to be charged, represented as a decimal string with at most 12 decimal places.
+    ///
+    /// Only set if `billing_scheme=per_unit`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount_decimal: Option<String>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PriceTier {
     /// Price for the entire tier.
@@ -228,6 +272,12 @@ pub struct CreatePrice<'a> {
     /// Must be a [supported currency](https://stripe.com/docs/currencies).
     pub currency: Currency,
 
+    /// Prices defined in each available currency option.
+    ///
+    /// Each key must be a three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html) and a [supported currency](https://stripe.com/docs/currencies).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_options: Option<HashMap<Currency, CreatePriceCurrencyOptions>>,
+
     /// Specifies which fields in the response should be expanded.
     #[serde(skip_serializing_if = "Expand::is_empty")]
     pub expand: &'a [&'a str],
@@ -260,6 +310,13 @@ pub struct CreatePrice<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recurring: Option<CreatePriceRecurring>,
 
+    /// Specifies whether the price is considered inclusive of taxes or exclusive of taxes.
+    ///
+    /// One of `inclusive`, `exclusive`, or `unspecified`.
+    /// Once specified as either `inclusive` or `exclusive`, it cannot be changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<PriceTaxBehavior>,
+
     /// Each element represents a pricing tier.
     ///
     /// This parameter requires `billing_scheme` to be set to `tiered`.
@@ -300,6 +357,7 @@ impl<'a> CreatePrice<'a> {
             active: Default::default(),
             billing_scheme: Default::default(),
             currency,
+            currency_options: Default::default(),
             expand: Default::default(),
             lookup_key: Default::default(),
             metadata: Default::default(),
@@ -307,6 +365,7 @@ impl<'a> CreatePrice<'a> {
             product: Default::default(),
             product_data: Default::default(),
             recurring: Default::default(),
+            tax_behavior: Default::default(),
             tiers: Default::default(),
             tiers_mode: Default::default(),
             transfer_lookup_key: Default::default(),
@@ -403,6 +462,12 @@ pub struct UpdatePrice<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
 
+    /// Prices defined in each available currency option.
+    ///
+    /// Each key must be a three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html) and a [supported currency](https://stripe.com/docs/currencies).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_options: Option<HashMap<Currency, CreatePriceCurrencyOptions>>,
+
     /// Specifies which fields in the response should be expanded.
     #[serde(skip_serializing_if = "Expand::is_empty")]
     pub expand: &'a [&'a str],
@@ -427,6 +492,13 @@ pub struct UpdatePrice<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recurring: Option<UpdatePriceRecurring>,
 
+    /// Specifies whether the price is considered inclusive of taxes or exclusive of taxes.
+    ///
+    /// One of `inclusive`, `exclusive`, or `unspecified`.
+    /// Once specified as either `inclusive` or `exclusive`, it cannot be changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<PriceTaxBehavior>,
+
     /// If set to true, will atomically remove the lookup key from the existing price, and assign it to this price.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transfer_lookup_key: Option<bool>,
@@ -436,16 +508,33 @@ impl<'a> UpdatePrice<'a> {
     pub fn new() -> Self {
         UpdatePrice {
             active: Default::default(),
+            currency_options: Default::default(),
             expand: Default::default(),
             lookup_key: Default::default(),
             metadata: Default::default(),
             nickname: Default::default(),
             recurring: Default::default(),
+            tax_behavior: Default::default(),
             transfer_lookup_key: Default::default(),
         }
     }
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreatePriceCurrencyOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<PriceTaxBehavior>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers: Option<Vec<CreatePriceTiers>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount_decimal: Option<String>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreatePriceProductData {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -737,6 +826,37 @@ impl std::fmt::Display for PriceBillingScheme {
     }
 }
 
+/// An enum representing the possible values of an `Price`'s `tax_behavior` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceTaxBehavior {
+    Exclusive,
+    Inclusive,
+    Unspecified,
+}
+
+impl PriceTaxBehavior {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PriceTaxBehavior::Exclusive => "exclusive",
+            PriceTaxBehavior::Inclusive => "inclusive",
+            PriceTaxBehavior::Unspecified => "unspecified",
+        }
+    }
+}
+
+impl AsRef<str> for PriceTaxBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PriceTaxBehavior {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `Price`'s `tiers_mode` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
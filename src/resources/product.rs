@@ -3,15 +3,16 @@
 // ======================================
 
 use crate::config::{Client, Response};
-use crate::ids::ProductId;
-use crate::params::{Deleted, Expand, List, Metadata, Object, RangeQuery, Timestamp};
-use crate::resources::PackageDimensions;
+use crate::ids::{PriceId, ProductId, TaxCodeId};
+use crate::params::{Deleted, Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
+use crate::resources::{Currency, PackageDimensions, Price, PriceTaxBehavior, TaxCode};
 use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "Product".
 ///
 /// For more details see [https://stripe.com/docs/api/products/object](https://stripe.com/docs/api/products/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Product {
     /// Unique identifier for the object.
     pub id: ProductId,
@@ -42,6 +43,10 @@ pub struct Product {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deactivate_on: Option<Vec<String>>,
 
+    /// The ID of the [Price](https://stripe.com/docs/api/prices) object that is the default price for this product.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_price: Option<Expandable<Price>>,
+
     // Always true for a deleted object
     #[serde(default)]
     pub deleted: bool,
@@ -52,6 +57,12 @@ pub struct Product {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
+    /// A list of up to 15 features for this product.
+    ///
+    /// These are displayed in [pricing tables](https://stripe.com/docs/payments/checkout/pricing-table).
+    #[serde(default)]
+    pub features: Vec<ProductFeature>,
+
     /// A list of up to 8 URLs of images for this product, meant to be displayable to the customer.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub images: Option<Vec<String>>,
@@ -91,6 +102,10 @@ pub struct Product {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub statement_descriptor: Option<String>,
 
+    /// A [tax code](https://stripe.com/docs/tax/tax-categories) ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<Expandable<TaxCode>>,
+
     /// The type of the product.
     ///
     /// The product is either of type `good`, which is eligible for use with Orders and SKUs, or `service`, which is eligible for use with Subscriptions and Plans.
@@ -122,7 +137,7 @@ impl Product {
     ///
     /// The products are returned sorted by creation date, with the most recently created products appearing first.
     pub fn list(client: &Client, params: ListProducts<'_>) -> Response<List<Product>> {
-        client.get_query("/products", &params)
+        List::get_with_params(client, "/products", &params)
     }
 
     /// Creates a new product object.
@@ -188,6 +203,12 @@ pub struct CreateProduct<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deactivate_on: Option<Vec<String>>,
 
+    /// Data used to generate a new [Price](https://stripe.com/docs/api/prices) object.
+    ///
+    /// This Price will be set as the default price for this product.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_price_data: Option<CreateProductDefaultPriceData>,
+
     /// The product's description, meant to be displayable to the customer.
     ///
     /// Use this field to optionally store a long form explanation of the product being sold for your own rendering purposes.
@@ -198,6 +219,12 @@ pub struct CreateProduct<'a> {
     #[serde(skip_serializing_if = "Expand::is_empty")]
     pub expand: &'a [&'a str],
 
+    /// A list of up to 15 features for this product.
+    ///
+    /// These are displayed in [pricing tables](https://stripe.com/docs/payments/checkout/pricing-table).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<Vec<ProductFeature>>,
+
     /// An identifier will be randomly generated by Stripe.
     ///
     /// You can optionally override this ID, but the ID must be unique across all products in your Stripe account.
@@ -243,6 +270,10 @@ pub struct CreateProduct<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub statement_descriptor: Option<&'a str>,
 
+    /// A [tax code](https://stripe.com/docs/tax/tax-categories) ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<TaxCodeId>,
+
     /// The type of the product.
     ///
     /// Defaults to `service` if not explicitly specified, enabling use of this product with Subscriptions and Plans.
@@ -272,8 +303,10 @@ impl<'a> CreateProduct<'a> {
             attributes: Default::default(),
             caption: Default::default(),
             deactivate_on: Default::default(),
+            default_price_data: Default::default(),
             description: Default::default(),
             expand: Default::default(),
+            features: Default::default(),
             id: Default::default(),
             images: Default::default(),
             metadata: Default::default(),
@@ -281,6 +314,7 @@ impl<'a> CreateProduct<'a> {
             package_dimensions: Default::default(),
             shippable: Default::default(),
             statement_descriptor: Default::default(),
+            tax_code: Default::default(),
             type_: Default::default(),
             unit_label: Default::default(),
             url: Default::default(),
@@ -384,6 +418,10 @@ pub struct UpdateProduct<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deactivate_on: Option<Vec<String>>,
 
+    /// The ID of the [Price](https://stripe.com/docs/api/prices) object that is the default price for this product.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_price: Option<PriceId>,
+
     /// The product's description, meant to be displayable to the customer.
     ///
     /// Use this field to optionally store a long form explanation of the product being sold for your own rendering purposes.
@@ -394,6 +432,12 @@ pub struct UpdateProduct<'a> {
     #[serde(skip_serializing_if = "Expand::is_empty")]
     pub expand: &'a [&'a str],
 
+    /// A list of up to 15 features for this product.
+    ///
+    /// Entries using `features` will replace the list of existing features.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<Vec<ProductFeature>>,
+
     /// A list of up to 8 URLs of images for this product, meant to be displayable to the customer.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub images: Option<Vec<String>>,
@@ -435,6 +479,10 @@ pub struct UpdateProduct<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub statement_descriptor: Option<&'a str>,
 
+    /// A [tax code](https://stripe.com/docs/tax/tax-categories) ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<TaxCodeId>,
+
     /// A label that represents units of this product in Stripe and on customers’ receipts and invoices.
     ///
     /// When set, this will be included in associated invoice line item descriptions.
@@ -456,20 +504,89 @@ impl<'a> UpdateProduct<'a> {
             attributes: Default::default(),
             caption: Default::default(),
             deactivate_on: Default::default(),
+            default_price: Default::default(),
             description: Default::default(),
             expand: Default::default(),
+            features: Default::default(),
             images: Default::default(),
             metadata: Default::default(),
             name: Default::default(),
             package_dimensions: Default::default(),
             shippable: Default::default(),
             statement_descriptor: Default::default(),
+            tax_code: Default::default(),
             unit_label: Default::default(),
             url: Default::default(),
         }
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CreateProductDefaultPriceData {
+    pub currency: Currency,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<CreateProductDefaultPriceDataRecurring>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<PriceTaxBehavior>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount_decimal: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProductFeature {
+    /// The feature's name.
+    ///
+    /// Up to 80 characters long.
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CreateProductDefaultPriceDataRecurring {
+    pub interval: CreateProductDefaultPriceDataRecurringInterval,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_count: Option<u64>,
+}
+
+/// An enum representing the possible values of an `CreateProductDefaultPriceDataRecurring`'s `interval` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateProductDefaultPriceDataRecurringInterval {
+    Day,
+    Month,
+    Week,
+    Year,
+}
+
+impl CreateProductDefaultPriceDataRecurringInterval {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CreateProductDefaultPriceDataRecurringInterval::Day => "day",
+            CreateProductDefaultPriceDataRecurringInterval::Month => "month",
+            CreateProductDefaultPriceDataRecurringInterval::Week => "week",
+            CreateProductDefaultPriceDataRecurringInterval::Year => "year",
+        }
+    }
+}
+
+impl AsRef<str> for CreateProductDefaultPriceDataRecurringInterval {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CreateProductDefaultPriceDataRecurringInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `Product`'s `type` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -0,0 +1,423 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::config::{Client, Response};
+use crate::ids::{CustomerId, PriceId, QuoteId};
+use crate::params::{Expand, Expandable, List, Metadata, Object, Timestamp};
+use crate::resources::{Currency, Customer, Invoice, Subscription};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "Quote".
+///
+/// For more details see [https://stripe.com/docs/api/quotes/object](https://stripe.com/docs/api/quotes/object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Quote {
+    /// Unique identifier for the object.
+    pub id: QuoteId,
+
+    /// Total before any discounts or taxes are applied.
+    pub amount_subtotal: i64,
+
+    /// Total after discounts and taxes are applied.
+    pub amount_total: i64,
+
+    /// Time at which the object was created.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub created: Timestamp,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
+    /// The customer which this quote belongs to.
+    ///
+    /// A customer is required before finalizing the quote.
+    /// Once specified, it cannot be changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Expandable<Customer>>,
+
+    /// A description that will be displayed on the quote PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The date on which the quote will be canceled if in `open` or `draft` status.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub expires_at: Timestamp,
+
+    /// A footer that will be displayed on the quote PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+
+    /// A header that will be displayed on the quote PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+
+    /// The invoice that was created from this quote.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<Expandable<Invoice>>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
+    pub metadata: Metadata,
+
+    /// A unique number that identifies this particular quote.
+    ///
+    /// This number is assigned once the quote is [finalized](https://stripe.com/docs/quotes/overview#finalize).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+
+    /// The status of the quote.
+    pub status: QuoteStatus,
+
+    /// The subscription that was created or updated from this quote.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<Expandable<Subscription>>,
+
+    pub subscription_data: QuoteSubscriptionData,
+}
+
+impl Quote {
+    /// Returns a list of your quotes.
+    pub fn list(client: &Client, params: ListQuotes<'_>) -> Response<List<Quote>> {
+        List::get_with_params(client, "/quotes", &params)
+    }
+
+    /// A quote models prices and services for a customer.
+    ///
+    /// Default options for `header`, `description`, `footer`, and `expires_at` can be set in the dashboard via the [quote template](https://dashboard.stripe.com/settings/billing/quote).
+    pub fn create(client: &Client, params: CreateQuote<'_>) -> Response<Quote> {
+        client.post_form("/quotes", &params)
+    }
+
+    /// Retrieves the quote with the given ID.
+    pub fn retrieve(client: &Client, id: &QuoteId, expand: &[&str]) -> Response<Quote> {
+        client.get_query(&format!("/quotes/{}", id), &Expand { expand })
+    }
+
+    /// A quote models prices and services for a customer.
+    pub fn update(client: &Client, id: &QuoteId, params: UpdateQuote<'_>) -> Response<Quote> {
+        client.post_form(&format!("/quotes/{}", id), &params)
+    }
+
+    /// Finalizes the quote.
+    pub fn finalize(client: &Client, id: &QuoteId) -> Response<Quote> {
+        client.post(&format!("/quotes/{}/finalize", id))
+    }
+
+    /// Accepts the specified quote.
+    pub fn accept(client: &Client, id: &QuoteId) -> Response<Quote> {
+        client.post(&format!("/quotes/{}/accept", id))
+    }
+
+    /// Cancels the quote.
+    pub fn cancel(client: &Client, id: &QuoteId) -> Response<Quote> {
+        client.post(&format!("/quotes/{}/cancel", id))
+    }
+}
+
+impl Object for Quote {
+    type Id = QuoteId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "quote"
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct QuoteSubscriptionData {
+    /// The subscription's description, meant to be displayable to the customer.
+    ///
+    /// Use this field to optionally store an explanation of the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// When creating a new subscription, the date of which the subscription schedule will start after the quote is accepted.
+    ///
+    /// This date is ignored if it is in the past when the quote is accepted.
+    /// Measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_date: Option<Timestamp>,
+
+    /// Set of key-value pairs that will be set as metadata on the subscription or subscription schedule when the quote is accepted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Integer representing the number of trial period days before the customer is charged for the first time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_period_days: Option<u32>,
+}
+
+/// The parameters for `Quote::create`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CreateQuote<'a> {
+    /// The customer for which this quote belongs to.
+    ///
+    /// A customer is required before finalizing the quote.
+    /// Once specified, it cannot be changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+
+    /// A description that will be displayed on the quote PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A future timestamp on which the quote will be canceled if in `open` or `draft` status.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// A footer that will be displayed on the quote PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<&'a str>,
+
+    /// A header that will be displayed on the quote PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<&'a str>,
+
+    /// A list of line items the customer is being quoted for.
+    ///
+    /// Each line item includes information about the product, the quantity, and the resulting cost.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<QuoteLineItemParams>>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// When creating a subscription or subscription schedule, the specified configuration data will be used.
+    ///
+    /// There must be at least one line item with a recurring price for a subscription or subscription schedule to be created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_data: Option<QuoteSubscriptionDataParams>,
+}
+
+impl<'a> CreateQuote<'a> {
+    pub fn new() -> Self {
+        CreateQuote {
+            customer: Default::default(),
+            description: Default::default(),
+            expand: Default::default(),
+            expires_at: Default::default(),
+            footer: Default::default(),
+            header: Default::default(),
+            line_items: Default::default(),
+            metadata: Default::default(),
+            subscription_data: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `Quote::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListQuotes<'a> {
+    /// The ID of the customer whose quotes will be retrieved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<QuoteId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<QuoteId>,
+
+    /// The status of the quote.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<QuoteStatus>,
+}
+
+impl<'a> ListQuotes<'a> {
+    pub fn new() -> Self {
+        ListQuotes {
+            customer: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `Quote::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateQuote<'a> {
+    /// A description that will be displayed on the quote PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A future timestamp on which the quote will be canceled if in `open` or `draft` status.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// A footer that will be displayed on the quote PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<&'a str>,
+
+    /// A header that will be displayed on the quote PDF.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<&'a str>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    /// Individual keys can be unset by posting an empty value to them.
+    /// All keys can be unset by posting an empty value to `metadata`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// When creating a subscription or subscription schedule, the specified configuration data will be used.
+    ///
+    /// There must be at least one line item with a recurring price for a subscription or subscription schedule to be created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_data: Option<QuoteSubscriptionDataParams>,
+}
+
+impl<'a> UpdateQuote<'a> {
+    pub fn new() -> Self {
+        UpdateQuote {
+            description: Default::default(),
+            expand: Default::default(),
+            expires_at: Default::default(),
+            footer: Default::default(),
+            header: Default::default(),
+            metadata: Default::default(),
+            subscription_data: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct QuoteLineItemParams {
+    /// The ID of the price object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<PriceId>,
+
+    /// The quantity of the line item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct QuoteSubscriptionDataParams {
+    /// The subscription's description, meant to be displayable to the customer.
+    ///
+    /// Use this field to optionally store an explanation of the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// When creating a new subscription, the date of which the subscription schedule will start after the quote is accepted.
+    ///
+    /// When updating a subscription, the date of which the subscription will be updated using a subscription schedule.
+    /// The special value `current_period_end` can be provided to update a subscription at the end of its current period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_date: Option<QuoteEffectiveDate>,
+
+    /// Set of key-value pairs that will be set as metadata on the subscription or subscription schedule when the quote is accepted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Integer representing the number of trial period days before the customer is charged for the first time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_period_days: Option<u32>,
+}
+
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum QuoteEffectiveDate {
+    Timestamp(Timestamp),
+    Other(QuoteEffectiveDateOther),
+}
+
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuoteEffectiveDateOther {
+    CurrentPeriodEnd,
+}
+
+impl QuoteEffectiveDate {
+    pub fn at(ts: Timestamp) -> Self {
+        QuoteEffectiveDate::Timestamp(ts)
+    }
+    pub fn current_period_end() -> Self {
+        QuoteEffectiveDate::Other(QuoteEffectiveDateOther::CurrentPeriodEnd)
+    }
+}
+
+/// An enum representing the possible values of an `Quote`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuoteStatus {
+    Accepted,
+    Canceled,
+    Draft,
+    Open,
+}
+
+impl QuoteStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            QuoteStatus::Accepted => "accepted",
+            QuoteStatus::Canceled => "canceled",
+            QuoteStatus::Draft => "draft",
+            QuoteStatus::Open => "open",
+        }
+    }
+}
+
+impl AsRef<str> for QuoteStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for QuoteStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -10,6 +10,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "TransferRecipient".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Recipient {
     /// Unique identifier for the object.
     pub id: RecipientId,
@@ -78,7 +79,7 @@ impl Recipient {
     ///
     /// The recipients are returned sorted by creation date, with the most recently created recipients appearing first.
     pub fn list(client: &Client, params: ListRecipients<'_>) -> Response<List<Recipient>> {
-        client.get_query("/recipients", &params)
+        List::get_with_params(client, "/recipients", &params)
     }
 
     /// Creates a new `Recipient` object and verifies the recipient’s identity.
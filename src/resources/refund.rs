@@ -12,6 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/refunds/object](https://stripe.com/docs/api/refunds/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Refund {
     /// Unique identifier for the object.
     pub id: RefundId,
@@ -52,13 +53,22 @@ pub struct Refund {
     ///
     /// Possible values are `lost_or_stolen_card`, `expired_or_canceled_card`, or `unknown`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_reason: Option<String>,
+    pub failure_reason: Option<RefundFailureReason>,
+
+    /// Email to which refund instructions, if required, are sent to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions_email: Option<String>,
 
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
+    /// If the refund requires an action from the customer, this describes what they need to do.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_action: Option<RefundNextAction>,
+
     /// ID of the PaymentIntent that was refunded.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_intent: Option<Expandable<PaymentIntent>>,
@@ -84,7 +94,7 @@ pub struct Refund {
     /// For other types of refunds, it can be `pending`, `succeeded`, `failed`, or `canceled`.
     /// Refer to our [refunds](https://stripe.com/docs/refunds#failed-refunds) documentation for more details.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RefundStatus>,
 
     /// If the accompanying transfer was reversed, the transfer reversal object.
     ///
@@ -99,7 +109,7 @@ impl Refund {
     /// The refunds are returned in sorted order, with the most recent refunds appearing first.
     /// For convenience, the 10 most recent refunds are always available by default on the charge object.
     pub fn list(client: &Client, params: ListRefunds<'_>) -> Response<List<Refund>> {
-        client.get_query("/refunds", &params)
+        List::get_with_params(client, "/refunds", &params)
     }
 
     /// Create a refund.
@@ -130,6 +140,34 @@ impl Object for Refund {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RefundNextAction {
+    /// Contains the refund details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_details: Option<RefundNextActionDisplayDetails>,
+
+    /// Type of the next action to perform.
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RefundNextActionDisplayDetails {
+    pub email_sent: EmailSent,
+
+    /// The expiry timestamp.
+    pub expires_at: Timestamp,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EmailSent {
+    /// The timestamp when the email was sent.
+    pub email_sent_at: Timestamp,
+
+    /// The recipient's email address.
+    pub email_sent_to: String,
+}
+
 /// The parameters for `Refund::create`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct CreateRefund<'a> {
@@ -254,6 +292,88 @@ impl<'a> UpdateRefund<'a> {
     }
 }
 
+/// An enum representing the possible values of an `Refund`'s `failure_reason` field.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum RefundFailureReason {
+    ChargeForPendingRefundDisputed,
+    Declined,
+    ExpiredOrCanceledCard,
+    InsufficientFunds,
+    LostOrStolenCard,
+    MerchantRequest,
+    Unknown,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
+}
+
+impl RefundFailureReason {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RefundFailureReason::ChargeForPendingRefundDisputed => {
+                "charge_for_pending_refund_disputed"
+            }
+            RefundFailureReason::Declined => "declined",
+            RefundFailureReason::ExpiredOrCanceledCard => "expired_or_canceled_card",
+            RefundFailureReason::InsufficientFunds => "insufficient_funds",
+            RefundFailureReason::LostOrStolenCard => "lost_or_stolen_card",
+            RefundFailureReason::MerchantRequest => "merchant_request",
+            RefundFailureReason::Unknown => "unknown",
+            RefundFailureReason::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for RefundFailureReason {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "charge_for_pending_refund_disputed" => {
+                RefundFailureReason::ChargeForPendingRefundDisputed
+            }
+            "declined" => RefundFailureReason::Declined,
+            "expired_or_canceled_card" => RefundFailureReason::ExpiredOrCanceledCard,
+            "insufficient_funds" => RefundFailureReason::InsufficientFunds,
+            "lost_or_stolen_card" => RefundFailureReason::LostOrStolenCard,
+            "merchant_request" => RefundFailureReason::MerchantRequest,
+            "unknown" => RefundFailureReason::Unknown,
+            _ => RefundFailureReason::Other(value),
+        }
+    }
+}
+
+impl AsRef<str> for RefundFailureReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for RefundFailureReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for RefundFailureReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for RefundFailureReason {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(RefundFailureReason::from(s))
+    }
+}
+
 /// An enum representing the possible values of an `CreateRefund`'s `reason` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -284,3 +404,75 @@ impl std::fmt::Display for RefundReason {
         self.as_str().fmt(f)
     }
 }
+
+/// An enum representing the possible values of an `Refund`'s `status` field.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum RefundStatus {
+    Canceled,
+    Failed,
+    Pending,
+    RequiresAction,
+    Succeeded,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
+}
+
+impl RefundStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RefundStatus::Canceled => "canceled",
+            RefundStatus::Failed => "failed",
+            RefundStatus::Pending => "pending",
+            RefundStatus::RequiresAction => "requires_action",
+            RefundStatus::Succeeded => "succeeded",
+            RefundStatus::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for RefundStatus {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "canceled" => RefundStatus::Canceled,
+            "failed" => RefundStatus::Failed,
+            "pending" => RefundStatus::Pending,
+            "requires_action" => RefundStatus::RequiresAction,
+            "succeeded" => RefundStatus::Succeeded,
+            _ => RefundStatus::Other(value),
+        }
+    }
+}
+
+impl AsRef<str> for RefundStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for RefundStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for RefundStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for RefundStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(RefundStatus::from(s))
+    }
+}
@@ -0,0 +1,78 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::config::{Client, Response};
+use crate::ids::ReportTypeId;
+use crate::params::{List, Object, Timestamp};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "reporting.report_type".
+///
+/// For more details see [https://stripe.com/docs/api/reporting/report_type/object](https://stripe.com/docs/api/reporting/report_type/object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ReportType {
+    /// The [ID of the Report Type](https://stripe.com/docs/reporting/statements/api#available-report-types), such as `balance.summary.1`.
+    pub id: ReportTypeId,
+
+    /// Most recent time for which this Report Type is available.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub data_available_end: Timestamp,
+
+    /// Earliest time for which this Report Type is available.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub data_available_start: Timestamp,
+
+    /// List of column names that are included by default when this Report Type gets run.
+    ///
+    /// (If the Report Type doesn't support the `columns` parameter, this will be null.).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_columns: Option<Vec<String>>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// Human-readable name of the Report Type.
+    pub name: String,
+
+    /// The namespace the Report Type belongs to, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+
+    /// When this Report Type was latest updated.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    pub updated: Timestamp,
+
+    /// Version of the Report Type.
+    ///
+    /// Different versions report with the same ID will have the same purpose, but may take different run parameters or have different result schemas.
+    pub version: i64,
+}
+
+impl ReportType {
+    /// Returns a full list of Report Types.
+    pub fn list(client: &Client) -> Response<List<ReportType>> {
+        client.get("/reporting/report_types")
+    }
+
+    /// Retrieves the details of a Report Type.
+    ///
+    /// (Certain report types require a [live-mode API key](https://stripe.com/docs/keys#test-live-modes).).
+    pub fn retrieve(client: &Client, id: &ReportTypeId) -> Response<ReportType> {
+        client.get(&format!("/reporting/report_types/{}", id))
+    }
+}
+
+impl Object for ReportType {
+    type Id = ReportTypeId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "reporting.report_type"
+    }
+}
@@ -8,6 +8,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "ReserveTransaction".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct ReserveTransaction {
     pub amount: i64,
 
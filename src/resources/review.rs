@@ -10,6 +10,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "RadarReview".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Review {
     /// Unique identifier for the object.
     pub id: ReviewId,
@@ -73,7 +74,7 @@ impl Review {
     ///
     /// The objects are sorted in descending order by creation date, with the most recently created object appearing first.
     pub fn list(client: &Client, params: ListReviews<'_>) -> Response<List<Review>> {
-        client.get_query("/reviews", &params)
+        List::get_with_params(client, "/reviews", &params)
     }
 
     /// Retrieves a `Review` object.
@@ -4,9 +4,10 @@
 
 use crate::config::{Client, Response};
 use crate::ids::{CustomerId, PaymentMethodId, SetupIntentId};
-use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
+use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Secret, Timestamp};
 use crate::resources::{
-    Account, ApiErrors, Application, Currency, Customer, Mandate, PaymentMethod,
+    Account, ApiErrors, Application, Currency, Customer, Mandate,
+    PaymentIntentNextActionVerifyWithMicrodeposits, PaymentMethod,
 };
 use serde_derive::{Deserialize, Serialize};
 
@@ -14,6 +15,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/setup_intents/object](https://stripe.com/docs/api/setup_intents/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct SetupIntent {
     /// Unique identifier for the object.
     pub id: SetupIntentId,
@@ -32,7 +34,7 @@ pub struct SetupIntent {
     /// It should not be stored, logged, embedded in URLs, or exposed to anyone other than the customer.
     /// Make sure that you have TLS enabled on any page that includes the client secret.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_secret: Option<String>,
+    pub client_secret: Option<Secret>,
 
     /// Time at which the object was created.
     ///
@@ -89,6 +91,7 @@ pub struct SetupIntent {
     /// The list of payment method types (e.g.
     ///
     /// card) that this SetupIntent is allowed to set up.
+    #[serde(default)]
     pub payment_method_types: Vec<String>,
 
     /// ID of the single_use Mandate generated by the SetupIntent.
@@ -110,7 +113,7 @@ pub struct SetupIntent {
 impl SetupIntent {
     /// Returns a list of SetupIntents.
     pub fn list(client: &Client, params: ListSetupIntents<'_>) -> Response<List<SetupIntent>> {
-        client.get_query("/setup_intents", &params)
+        List::get_with_params(client, "/setup_intents", &params)
     }
 
     /// Creates a SetupIntent object.
@@ -164,6 +167,9 @@ pub struct SetupIntentNextAction {
     /// The shape of the contents is subject to change and is only intended to be used by Stripe.js.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_stripe_sdk: Option<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verify_with_microdeposits: Option<PaymentIntentNextActionVerifyWithMicrodeposits>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -179,12 +185,45 @@ pub struct SetupIntentNextActionRedirectToUrl {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetupIntentPaymentMethodOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acss_debit: Option<SetupIntentPaymentMethodOptionsAcssDebit>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub card: Option<SetupIntentPaymentMethodOptionsCard>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sepa_debit: Option<SetupIntentPaymentMethodOptionsSepaDebit>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account: Option<SetupIntentPaymentMethodOptionsUsBankAccount>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SetupIntentPaymentMethodOptionsAcssDebit {
+    /// Currency supported by the bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_options: Option<SetupIntentPaymentMethodOptionsMandateOptionsAcssDebit>,
+
+    /// Bank account verification method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_method: Option<SetupIntentPaymentMethodOptionsVerificationMethod>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetupIntentPaymentMethodOptionsCard {
+    /// Configuration options for setting up an eMandate for cards issued in India.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_options: Option<SetupIntentPaymentMethodOptionsCardMandateOptions>,
+
+    /// Selected network to process this SetupIntent on.
+    ///
+    /// Depends on the available networks of the card attached to the setup intent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+
     /// We strongly recommend that you rely on our SCA Engine to automatically prompt your customers for authentication based on risk level and [other requirements](https://stripe.com/docs/strong-customer-authentication).
     ///
     /// However, if you wish to request 3D Secure based on logic from your own fraud engine, provide this option.
@@ -195,6 +234,117 @@ pub struct SetupIntentPaymentMethodOptionsCard {
     pub request_three_d_secure: Option<SetupIntentPaymentMethodOptionsCardRequestThreeDSecure>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SetupIntentPaymentMethodOptionsCardMandateOptions {
+    /// Amount to be charged for future payments.
+    pub amount: i64,
+
+    /// One of `fixed` or `maximum`.
+    ///
+    /// If `fixed`, the `amount` param refers to the exact amount to be charged in future payments.
+    /// If `maximum`, the amount charged can be up to the value passed for the `amount` param.
+    pub amount_type: SetupIntentPaymentMethodOptionsCardMandateOptionsAmountType,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    pub currency: Currency,
+
+    /// A description of the mandate or subscription that is meant to be displayed to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// End date of the mandate or subscription.
+    ///
+    /// If not provided, the mandate will be active until canceled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<Timestamp>,
+
+    /// Specifies payment frequency.
+    ///
+    /// One of `day`, `week`, `month`, `year`, or `sporadic`.
+    pub interval: SetupIntentPaymentMethodOptionsCardMandateOptionsInterval,
+
+    /// The number of intervals between payments.
+    ///
+    /// For example, `interval=month` and `interval_count=3` indicates one payment every three months.
+    /// This parameter is optional when `interval=sporadic`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_count: Option<u64>,
+
+    /// Unique identifier for the mandate or subscription.
+    pub reference: String,
+
+    /// Start date of the mandate or subscription.
+    pub start_date: Timestamp,
+
+    /// Specifies the type of mandates supported.
+    ///
+    /// Possible values are `india`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supported_types:
+        Option<Vec<SetupIntentPaymentMethodOptionsCardMandateOptionsSupportedTypes>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SetupIntentPaymentMethodOptionsMandateOptionsAcssDebit {
+    /// A URL for custom mandate text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_mandate_url: Option<String>,
+
+    /// List of Stripe products where this mandate can be selected automatically.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_for: Option<Vec<SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitDefaultFor>>,
+
+    /// Description of the interval.
+    ///
+    /// Only required if the 'payment_schedule' parameter is 'interval' or 'combined'.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_description: Option<String>,
+
+    /// Payment schedule for the mandate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_schedule:
+        Option<SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule>,
+
+    /// Transaction type of the mandate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_type:
+        Option<SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitTransactionType>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SetupIntentPaymentMethodOptionsSepaDebit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_options: Option<SetupIntentPaymentMethodOptionsMandateOptionsSepaDebit>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SetupIntentPaymentMethodOptionsMandateOptionsSepaDebit {}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SetupIntentPaymentMethodOptionsUsBankAccount {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub financial_connections: Option<LinkedAccountOptionsUsBankAccount>,
+
+    /// Bank account verification method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_method: Option<SetupIntentPaymentMethodOptionsVerificationMethod>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LinkedAccountOptionsUsBankAccount {
+    /// The list of permissions to request.
+    ///
+    /// The `payment_method` permission must be included.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<LinkedAccountOptionsUsBankAccountPermissions>>,
+
+    /// For webview integrations only.
+    ///
+    /// Upon completing OAuth login in the native browser, the user will be redirected to this URL to return to your app.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<String>,
+}
+
 /// The parameters for `SetupIntent::create`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct CreateSetupIntent<'a> {
@@ -407,10 +557,19 @@ pub struct CreateSetupIntentMandateData {
     pub customer_acceptance: CreateSetupIntentMandateDataCustomerAcceptance,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateSetupIntentPaymentMethodOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acss_debit: Option<SetupIntentPaymentMethodOptionsAcssDebit>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub card: Option<CreateSetupIntentPaymentMethodOptionsCard>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sepa_debit: Option<SetupIntentPaymentMethodOptionsSepaDebit>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account: Option<SetupIntentPaymentMethodOptionsUsBankAccount>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -420,10 +579,19 @@ pub struct CreateSetupIntentSingleUse {
     pub currency: Currency,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct UpdateSetupIntentPaymentMethodOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acss_debit: Option<SetupIntentPaymentMethodOptionsAcssDebit>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub card: Option<UpdateSetupIntentPaymentMethodOptionsCard>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sepa_debit: Option<SetupIntentPaymentMethodOptionsSepaDebit>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account: Option<SetupIntentPaymentMethodOptionsUsBankAccount>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -441,15 +609,27 @@ pub struct CreateSetupIntentMandateDataCustomerAcceptance {
     pub type_: CreateSetupIntentMandateDataCustomerAcceptanceType,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreateSetupIntentPaymentMethodOptionsCard {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_options: Option<SetupIntentPaymentMethodOptionsCardMandateOptions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_three_d_secure:
         Option<CreateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct UpdateSetupIntentPaymentMethodOptionsCard {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_options: Option<SetupIntentPaymentMethodOptionsCardMandateOptions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_three_d_secure:
         Option<UpdateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure>,
@@ -523,6 +703,39 @@ impl std::fmt::Display for CreateSetupIntentPaymentMethodOptionsCardRequestThree
     }
 }
 
+/// An enum representing the possible values of an `LinkedAccountOptionsUsBankAccount`'s `permissions` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkedAccountOptionsUsBankAccountPermissions {
+    Balances,
+    Ownership,
+    PaymentMethod,
+    Transactions,
+}
+
+impl LinkedAccountOptionsUsBankAccountPermissions {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LinkedAccountOptionsUsBankAccountPermissions::Balances => "balances",
+            LinkedAccountOptionsUsBankAccountPermissions::Ownership => "ownership",
+            LinkedAccountOptionsUsBankAccountPermissions::PaymentMethod => "payment_method",
+            LinkedAccountOptionsUsBankAccountPermissions::Transactions => "transactions",
+        }
+    }
+}
+
+impl AsRef<str> for LinkedAccountOptionsUsBankAccountPermissions {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for LinkedAccountOptionsUsBankAccountPermissions {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `SetupIntent`'s `cancellation_reason` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -554,6 +767,97 @@ impl std::fmt::Display for SetupIntentCancellationReason {
     }
 }
 
+/// An enum representing the possible values of an `SetupIntentPaymentMethodOptionsCardMandateOptions`'s `amount_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupIntentPaymentMethodOptionsCardMandateOptionsAmountType {
+    Fixed,
+    Maximum,
+}
+
+impl SetupIntentPaymentMethodOptionsCardMandateOptionsAmountType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SetupIntentPaymentMethodOptionsCardMandateOptionsAmountType::Fixed => "fixed",
+            SetupIntentPaymentMethodOptionsCardMandateOptionsAmountType::Maximum => "maximum",
+        }
+    }
+}
+
+impl AsRef<str> for SetupIntentPaymentMethodOptionsCardMandateOptionsAmountType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SetupIntentPaymentMethodOptionsCardMandateOptionsAmountType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `SetupIntentPaymentMethodOptionsCardMandateOptions`'s `interval` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupIntentPaymentMethodOptionsCardMandateOptionsInterval {
+    Day,
+    Month,
+    Sporadic,
+    Week,
+    Year,
+}
+
+impl SetupIntentPaymentMethodOptionsCardMandateOptionsInterval {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SetupIntentPaymentMethodOptionsCardMandateOptionsInterval::Day => "day",
+            SetupIntentPaymentMethodOptionsCardMandateOptionsInterval::Month => "month",
+            SetupIntentPaymentMethodOptionsCardMandateOptionsInterval::Sporadic => "sporadic",
+            SetupIntentPaymentMethodOptionsCardMandateOptionsInterval::Week => "week",
+            SetupIntentPaymentMethodOptionsCardMandateOptionsInterval::Year => "year",
+        }
+    }
+}
+
+impl AsRef<str> for SetupIntentPaymentMethodOptionsCardMandateOptionsInterval {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SetupIntentPaymentMethodOptionsCardMandateOptionsInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `SetupIntentPaymentMethodOptionsCardMandateOptions`'s `supported_types` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupIntentPaymentMethodOptionsCardMandateOptionsSupportedTypes {
+    India,
+}
+
+impl SetupIntentPaymentMethodOptionsCardMandateOptionsSupportedTypes {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SetupIntentPaymentMethodOptionsCardMandateOptionsSupportedTypes::India => "india",
+        }
+    }
+}
+
+impl AsRef<str> for SetupIntentPaymentMethodOptionsCardMandateOptionsSupportedTypes {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SetupIntentPaymentMethodOptionsCardMandateOptionsSupportedTypes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `SetupIntentPaymentMethodOptionsCard`'s `request_three_d_secure` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -587,6 +891,138 @@ impl std::fmt::Display for SetupIntentPaymentMethodOptionsCardRequestThreeDSecur
     }
 }
 
+/// An enum representing the possible values of an `SetupIntentPaymentMethodOptionsMandateOptionsAcssDebit`'s `default_for` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitDefaultFor {
+    Invoice,
+    Subscription,
+}
+
+impl SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitDefaultFor {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitDefaultFor::Invoice => "invoice",
+            SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitDefaultFor::Subscription => {
+                "subscription"
+            }
+        }
+    }
+}
+
+impl AsRef<str> for SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitDefaultFor {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitDefaultFor {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `SetupIntentPaymentMethodOptionsMandateOptionsAcssDebit`'s `payment_schedule` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule {
+    Combined,
+    Interval,
+    Sporadic,
+}
+
+impl SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule::Combined => {
+                "combined"
+            }
+            SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule::Interval => {
+                "interval"
+            }
+            SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule::Sporadic => {
+                "sporadic"
+            }
+        }
+    }
+}
+
+impl AsRef<str> for SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitPaymentSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `SetupIntentPaymentMethodOptionsMandateOptionsAcssDebit`'s `transaction_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitTransactionType {
+    Business,
+    Personal,
+}
+
+impl SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitTransactionType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitTransactionType::Business => {
+                "business"
+            }
+            SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitTransactionType::Personal => {
+                "personal"
+            }
+        }
+    }
+}
+
+impl AsRef<str> for SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitTransactionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SetupIntentPaymentMethodOptionsMandateOptionsAcssDebitTransactionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `SetupIntentPaymentMethodOptionsAcssDebit`'s `verification_method` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupIntentPaymentMethodOptionsVerificationMethod {
+    Automatic,
+    Instant,
+    Microdeposits,
+}
+
+impl SetupIntentPaymentMethodOptionsVerificationMethod {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SetupIntentPaymentMethodOptionsVerificationMethod::Automatic => "automatic",
+            SetupIntentPaymentMethodOptionsVerificationMethod::Instant => "instant",
+            SetupIntentPaymentMethodOptionsVerificationMethod::Microdeposits => "microdeposits",
+        }
+    }
+}
+
+impl AsRef<str> for SetupIntentPaymentMethodOptionsVerificationMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SetupIntentPaymentMethodOptionsVerificationMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `SetupIntent`'s `status` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
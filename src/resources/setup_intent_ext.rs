@@ -0,0 +1,70 @@
+use crate::config::{Client, Response};
+use crate::ids::{PaymentMethodId, SetupIntentId};
+use crate::params::Expand;
+use crate::resources::{
+    CreateSetupIntentMandateData, CreateSetupIntentPaymentMethodOptions, SetupIntent,
+    VerifyMicrodeposits,
+};
+use serde_derive::Serialize;
+
+impl SetupIntent {
+    /// Confirm that your customer intends to set up the current or provided payment method.
+    ///
+    /// For more details see [https://stripe.com/docs/api/setup_intents/confirm](https://stripe.com/docs/api/setup_intents/confirm).
+    pub fn confirm(
+        client: &Client,
+        id: &SetupIntentId,
+        params: ConfirmSetupIntent<'_>,
+    ) -> Response<SetupIntent> {
+        client.post_form(&format!("/setup_intents/{}/confirm", id), &params)
+    }
+
+    /// Verifies microdeposits on a SetupIntent object.
+    ///
+    /// For more details see [https://stripe.com/docs/api/setup_intents/verify_microdeposits](https://stripe.com/docs/api/setup_intents/verify_microdeposits).
+    pub fn verify_microdeposits(
+        client: &Client,
+        id: &SetupIntentId,
+        params: VerifyMicrodeposits<'_>,
+    ) -> Response<SetupIntent> {
+        client.post_form(&format!("/setup_intents/{}/verify_microdeposits", id), &params)
+    }
+}
+
+/// The parameters for `SetupIntent::confirm`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ConfirmSetupIntent<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// This hash contains details about the Mandate to create.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_data: Option<CreateSetupIntentMandateData>,
+
+    /// ID of the payment method (a PaymentMethod, Card, or saved Source object) to attach to this SetupIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<PaymentMethodId>,
+
+    /// Payment-method-specific configuration for this SetupIntent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_options: Option<CreateSetupIntentPaymentMethodOptions>,
+
+    /// The URL to redirect your customer back to after they authenticate on the payment method's app or site.
+    ///
+    /// If you'd prefer to redirect to a mobile application, you can alternatively supply an application URI scheme.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
+}
+
+impl<'a> ConfirmSetupIntent<'a> {
+    pub fn new() -> Self {
+        ConfirmSetupIntent {
+            expand: Default::default(),
+            mandate_data: Default::default(),
+            payment_method: Default::default(),
+            payment_method_options: Default::default(),
+            return_url: Default::default(),
+        }
+    }
+}
@@ -12,6 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/skus/object](https://stripe.com/docs/api/skus/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Sku {
     /// Unique identifier for the object.
     pub id: SkuId,
@@ -85,7 +86,7 @@ impl Sku {
     ///
     /// The SKUs are returned sorted by creation date, with the most recently created SKUs appearing first.
     pub fn list(client: &Client, params: ListSkus<'_>) -> Response<List<Sku>> {
-        client.get_query("/skus", &params)
+        List::get_with_params(client, "/skus", &params)
     }
 
     /// Creates a new SKU associated with a product.
@@ -4,7 +4,7 @@
 
 use crate::config::{Client, Response};
 use crate::ids::{CustomerId, SourceId, TokenId};
-use crate::params::{Expand, Metadata, Object, Timestamp};
+use crate::params::{Expand, Metadata, Object, Secret, Timestamp};
 use crate::resources::{
     Address, BillingDetails, Currency, Shipping, SourceRedirectFlowFailureReason,
     SourceRedirectFlowStatus, SourceStatus, SourceUsage,
@@ -15,6 +15,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/sources/object](https://stripe.com/docs/api/sources/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Source {
     /// Unique identifier for the object.
     pub id: SourceId,
@@ -50,7 +51,7 @@ pub struct Source {
     /// The client secret of the source.
     ///
     /// Used for client-side retrieval using a publishable key.
-    pub client_secret: String,
+    pub client_secret: Secret,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code_verification: Option<SourceCodeVerificationFlow>,
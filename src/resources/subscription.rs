@@ -7,7 +7,7 @@ use crate::ids::{CouponId, CustomerId, PlanId, PriceId, SubscriptionId};
 use crate::params::{Deleted, Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
     CollectionMethod, Currency, Customer, Discount, Invoice, PaymentMethod, PaymentSource, Plan,
-    Scheduled, SetupIntent, SubscriptionBillingThresholds, SubscriptionItem,
+    PlanInterval, Scheduled, SetupIntent, SubscriptionBillingThresholds, SubscriptionItem,
     SubscriptionItemBillingThresholds, SubscriptionSchedule, TaxRate,
 };
 use serde_derive::{Deserialize, Serialize};
@@ -16,6 +16,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/subscriptions/object](https://stripe.com/docs/api/subscriptions/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Subscription {
     /// Unique identifier for the object.
     pub id: SubscriptionId,
@@ -63,10 +64,12 @@ pub struct Subscription {
     /// End of the current period that the subscription has been invoiced for.
     ///
     /// At the end of this period, a new invoice will be created.
-    pub current_period_end: Timestamp,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_period_end: Option<Timestamp>,
 
     /// Start of the current period that the subscription has been invoiced for.
-    pub current_period_start: Timestamp,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_period_start: Option<Timestamp>,
 
     /// ID of the customer who owns the subscription.
     pub customer: Expandable<Customer>,
@@ -120,6 +123,7 @@ pub struct Subscription {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
     /// Specifies the approximate timestamp on which any pending invoice items will be billed according to the schedule provided at `pending_invoice_item_interval`.
@@ -130,6 +134,10 @@ pub struct Subscription {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pause_collection: Option<SubscriptionsResourcePauseCollection>,
 
+    /// Payment settings passed on to invoices created by the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_settings: Option<SubscriptionsResourcePaymentSettings>,
+
     /// Specifies an interval for how often to bill for any pending invoice items.
     ///
     /// It is analogous to calling [Create an invoice](https://stripe.com/docs/api#create_invoice) for the given subscription at the specified interval.
@@ -190,6 +198,10 @@ pub struct Subscription {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_end: Option<Timestamp>,
 
+    /// Settings related to subscription trials.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_settings: Option<SubscriptionTrialSettings>,
+
     /// If the subscription has a trial, the beginning of that trial.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_start: Option<Timestamp>,
@@ -200,7 +212,7 @@ impl Subscription {
     ///
     /// In order to list canceled subscriptions, specify `status=canceled`.
     pub fn list(client: &Client, params: ListSubscriptions<'_>) -> Response<List<Subscription>> {
-        client.get_query("/subscriptions", &params)
+        List::get_with_params(client, "/subscriptions", &params)
     }
 
     /// Creates a new subscription on an existing customer.
@@ -280,6 +292,80 @@ pub struct SubscriptionsResourcePauseCollection {
     pub resumes_at: Option<Timestamp>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubscriptionsResourcePaymentSettings {
+    /// Payment-method-specific configuration to provide to invoices created by the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_options: Option<SubscriptionPaymentMethodOptions>,
+
+    /// The list of payment method types to provide to every invoice created by the subscription.
+    ///
+    /// If not set, Stripe attempts to automatically determine the types to use by looking at the invoice’s default payment method, the subscription’s default payment method, the customer’s default payment method, and your [invoice template settings](https://dashboard.stripe.com/settings/billing/invoice).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_types: Option<Vec<SubscriptionPaymentMethodType>>,
+
+    /// Either `off`, or `on_subscription`.
+    ///
+    /// With `on_subscription` Stripe updates `subscription.default_payment_method` when a subscription payment succeeds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub save_default_payment_method: Option<SubscriptionSaveDefaultPaymentMethod>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionPaymentMethodOptions {
+    /// This sub-hash contains details about the Canadian pre-authorized debit payment method options to pass to invoices created by the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acss_debit: Option<SubscriptionPaymentMethodOptionsAcssDebit>,
+
+    /// This sub-hash contains details about the Bancontact payment method options to pass to invoices created by the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bancontact: Option<SubscriptionPaymentMethodOptionsBancontact>,
+
+    /// This sub-hash contains details about the Card payment method options to pass to invoices created by the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<SubscriptionPaymentMethodOptionsCard>,
+
+    /// This sub-hash contains details about the ACH direct debit payment method options to pass to invoices created by the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account: Option<SubscriptionPaymentMethodOptionsUsBankAccount>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionPaymentMethodOptionsAcssDebit {
+    /// Bank account verification method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_method: Option<SubscriptionPaymentMethodOptionsVerificationMethod>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionPaymentMethodOptionsBancontact {
+    /// Preferred language of the Bancontact authorization page that the customer is redirected to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_language: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionPaymentMethodOptionsUsBankAccount {
+    /// Bank account verification method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_method: Option<SubscriptionPaymentMethodOptionsVerificationMethod>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionPaymentMethodOptionsCard {
+    /// Selected network to process this Subscription on.
+    ///
+    /// Depends on the available networks of the card attached to the Subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+
+    /// We strongly recommend that you rely on our SCA Engine to automatically prompt your customers for authentication based on risk level and [other requirements](https://stripe.com/docs/strong-customer-authentication).
+    ///
+    /// However, if you wish to request 3D Secure based on logic from your own fraud engine, provide this option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_three_d_secure: Option<SubscriptionPaymentMethodOptionsCardRequestThreeDSecure>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SubscriptionsResourcePendingUpdate {
     /// If the update is applied, determines the date of the first full invoice, and, for plans with `month` or `year` intervals, the day of the month for subsequent invoices.
@@ -305,6 +391,18 @@ pub struct SubscriptionsResourcePendingUpdate {
     pub trial_from_plan: Option<bool>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubscriptionTrialSettings {
+    /// Defines how the subscription should behave when the user's free trial ends.
+    pub end_behavior: SubscriptionTrialSettingsEndBehavior,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubscriptionTrialSettingsEndBehavior {
+    /// Indicates how the subscription should change when the trial ends if the user did not provide a payment method.
+    pub missing_payment_method: SubscriptionTrialSettingsEndBehaviorMissingPaymentMethod,
+}
+
 /// The parameters for `Subscription::create`.
 #[derive(Clone, Debug, Serialize)]
 pub struct CreateSubscription<'a> {
@@ -427,6 +525,10 @@ pub struct CreateSubscription<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_behavior: Option<SubscriptionPaymentBehavior>,
 
+    /// Payment settings to pass to invoices created by the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_settings: Option<SubscriptionPaymentSettingsParams>,
+
     /// Specifies an interval for how often to bill for any pending invoice items.
     ///
     /// It is analogous to calling [Create an invoice](https://stripe.com/docs/api#create_invoice) for the given subscription at the specified interval.
@@ -477,6 +579,10 @@ pub struct CreateSubscription<'a> {
     /// This will always overwrite any trials that might apply via a subscribed plan.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_period_days: Option<u32>,
+
+    /// Settings related to subscription trials.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_settings: Option<SubscriptionTrialSettings>,
 }
 
 impl<'a> CreateSubscription<'a> {
@@ -501,6 +607,7 @@ impl<'a> CreateSubscription<'a> {
             metadata: Default::default(),
             off_session: Default::default(),
             payment_behavior: Default::default(),
+            payment_settings: Default::default(),
             pending_invoice_item_interval: Default::default(),
             prorate: Default::default(),
             proration_behavior: Default::default(),
@@ -508,6 +615,7 @@ impl<'a> CreateSubscription<'a> {
             trial_end: Default::default(),
             trial_from_plan: Default::default(),
             trial_period_days: Default::default(),
+            trial_settings: Default::default(),
         }
     }
 }
@@ -713,6 +821,10 @@ pub struct UpdateSubscription<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_behavior: Option<SubscriptionPaymentBehavior>,
 
+    /// Payment settings to pass to invoices created by the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_settings: Option<SubscriptionPaymentSettingsParams>,
+
     /// Specifies an interval for how often to bill for any pending invoice items.
     ///
     /// It is analogous to calling [Create an invoice](https://stripe.com/docs/api#create_invoice) for the given subscription at the specified interval.
@@ -764,6 +876,10 @@ pub struct UpdateSubscription<'a> {
     /// Setting this flag to `true` together with `trial_end` is not allowed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_from_plan: Option<bool>,
+
+    /// Settings related to subscription trials.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_settings: Option<SubscriptionTrialSettings>,
 }
 
 impl<'a> UpdateSubscription<'a> {
@@ -787,6 +903,7 @@ impl<'a> UpdateSubscription<'a> {
             off_session: Default::default(),
             pause_collection: Default::default(),
             payment_behavior: Default::default(),
+            payment_settings: Default::default(),
             pending_invoice_item_interval: Default::default(),
             prorate: Default::default(),
             proration_behavior: Default::default(),
@@ -794,6 +911,7 @@ impl<'a> UpdateSubscription<'a> {
             tax_percent: Default::default(),
             trial_end: Default::default(),
             trial_from_plan: Default::default(),
+            trial_settings: Default::default(),
         }
     }
 }
@@ -842,6 +960,18 @@ pub struct CreateSubscriptionPendingInvoiceItemInterval {
     pub interval_count: Option<u64>,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionPaymentSettingsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_options: Option<SubscriptionPaymentMethodOptions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_types: Option<Vec<SubscriptionPaymentMethodType>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub save_default_payment_method: Option<SubscriptionSaveDefaultPaymentMethod>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdateSubscriptionItems {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -932,39 +1062,6 @@ pub struct SubscriptionItemPriceDataRecurring {
     pub interval_count: Option<u64>,
 }
 
-/// An enum representing the possible values of an `SubscriptionPendingInvoiceItemInterval`'s `interval` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum PlanInterval {
-    Day,
-    Month,
-    Week,
-    Year,
-}
-
-impl PlanInterval {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            PlanInterval::Day => "day",
-            PlanInterval::Month => "month",
-            PlanInterval::Week => "week",
-            PlanInterval::Year => "year",
-        }
-    }
-}
-
-impl AsRef<str> for PlanInterval {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
-
-impl std::fmt::Display for PlanInterval {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-
 /// An enum representing the possible values of an `UpdateSubscription`'s `billing_cycle_anchor` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -999,6 +1096,7 @@ impl std::fmt::Display for SubscriptionBillingCycleAnchor {
 #[serde(rename_all = "snake_case")]
 pub enum SubscriptionPaymentBehavior {
     AllowIncomplete,
+    DefaultIncomplete,
     ErrorIfIncomplete,
     PendingIfIncomplete,
 }
@@ -1007,6 +1105,7 @@ impl SubscriptionPaymentBehavior {
     pub fn as_str(self) -> &'static str {
         match self {
             SubscriptionPaymentBehavior::AllowIncomplete => "allow_incomplete",
+            SubscriptionPaymentBehavior::DefaultIncomplete => "default_incomplete",
             SubscriptionPaymentBehavior::ErrorIfIncomplete => "error_if_incomplete",
             SubscriptionPaymentBehavior::PendingIfIncomplete => "pending_if_incomplete",
         }
@@ -1025,6 +1124,186 @@ impl std::fmt::Display for SubscriptionPaymentBehavior {
     }
 }
 
+/// An enum representing the possible values of an `SubscriptionPaymentMethodOptionsCard`'s `request_three_d_secure` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionPaymentMethodOptionsCardRequestThreeDSecure {
+    Any,
+    Automatic,
+}
+
+impl SubscriptionPaymentMethodOptionsCardRequestThreeDSecure {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SubscriptionPaymentMethodOptionsCardRequestThreeDSecure::Any => "any",
+            SubscriptionPaymentMethodOptionsCardRequestThreeDSecure::Automatic => "automatic",
+        }
+    }
+}
+
+impl AsRef<str> for SubscriptionPaymentMethodOptionsCardRequestThreeDSecure {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SubscriptionPaymentMethodOptionsCardRequestThreeDSecure {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `SubscriptionPaymentMethodOptionsAcssDebit`'s `verification_method` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionPaymentMethodOptionsVerificationMethod {
+    Automatic,
+    Instant,
+    Microdeposits,
+}
+
+impl SubscriptionPaymentMethodOptionsVerificationMethod {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SubscriptionPaymentMethodOptionsVerificationMethod::Automatic => "automatic",
+            SubscriptionPaymentMethodOptionsVerificationMethod::Instant => "instant",
+            SubscriptionPaymentMethodOptionsVerificationMethod::Microdeposits => "microdeposits",
+        }
+    }
+}
+
+impl AsRef<str> for SubscriptionPaymentMethodOptionsVerificationMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SubscriptionPaymentMethodOptionsVerificationMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `SubscriptionsResourcePaymentSettings`'s `payment_method_types` field.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SubscriptionPaymentMethodType {
+    AchCreditTransfer,
+    AchDebit,
+    AcssDebit,
+    AuBecsDebit,
+    BacsDebit,
+    Bancontact,
+    Boleto,
+    Card,
+    CustomerBalance,
+    Fpx,
+    Giropay,
+    Grabpay,
+    Ideal,
+    Konbini,
+    Link,
+    Paynow,
+    Promptpay,
+    SepaDebit,
+    Sofort,
+    UsBankAccount,
+    WechatPay,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
+}
+
+impl SubscriptionPaymentMethodType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SubscriptionPaymentMethodType::AchCreditTransfer => "ach_credit_transfer",
+            SubscriptionPaymentMethodType::AchDebit => "ach_debit",
+            SubscriptionPaymentMethodType::AcssDebit => "acss_debit",
+            SubscriptionPaymentMethodType::AuBecsDebit => "au_becs_debit",
+            SubscriptionPaymentMethodType::BacsDebit => "bacs_debit",
+            SubscriptionPaymentMethodType::Bancontact => "bancontact",
+            SubscriptionPaymentMethodType::Boleto => "boleto",
+            SubscriptionPaymentMethodType::Card => "card",
+            SubscriptionPaymentMethodType::CustomerBalance => "customer_balance",
+            SubscriptionPaymentMethodType::Fpx => "fpx",
+            SubscriptionPaymentMethodType::Giropay => "giropay",
+            SubscriptionPaymentMethodType::Grabpay => "grabpay",
+            SubscriptionPaymentMethodType::Ideal => "ideal",
+            SubscriptionPaymentMethodType::Konbini => "konbini",
+            SubscriptionPaymentMethodType::Link => "link",
+            SubscriptionPaymentMethodType::Paynow => "paynow",
+            SubscriptionPaymentMethodType::Promptpay => "promptpay",
+            SubscriptionPaymentMethodType::SepaDebit => "sepa_debit",
+            SubscriptionPaymentMethodType::Sofort => "sofort",
+            SubscriptionPaymentMethodType::UsBankAccount => "us_bank_account",
+            SubscriptionPaymentMethodType::WechatPay => "wechat_pay",
+            SubscriptionPaymentMethodType::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for SubscriptionPaymentMethodType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "ach_credit_transfer" => SubscriptionPaymentMethodType::AchCreditTransfer,
+            "ach_debit" => SubscriptionPaymentMethodType::AchDebit,
+            "acss_debit" => SubscriptionPaymentMethodType::AcssDebit,
+            "au_becs_debit" => SubscriptionPaymentMethodType::AuBecsDebit,
+            "bacs_debit" => SubscriptionPaymentMethodType::BacsDebit,
+            "bancontact" => SubscriptionPaymentMethodType::Bancontact,
+            "boleto" => SubscriptionPaymentMethodType::Boleto,
+            "card" => SubscriptionPaymentMethodType::Card,
+            "customer_balance" => SubscriptionPaymentMethodType::CustomerBalance,
+            "fpx" => SubscriptionPaymentMethodType::Fpx,
+            "giropay" => SubscriptionPaymentMethodType::Giropay,
+            "grabpay" => SubscriptionPaymentMethodType::Grabpay,
+            "ideal" => SubscriptionPaymentMethodType::Ideal,
+            "konbini" => SubscriptionPaymentMethodType::Konbini,
+            "link" => SubscriptionPaymentMethodType::Link,
+            "paynow" => SubscriptionPaymentMethodType::Paynow,
+            "promptpay" => SubscriptionPaymentMethodType::Promptpay,
+            "sepa_debit" => SubscriptionPaymentMethodType::SepaDebit,
+            "sofort" => SubscriptionPaymentMethodType::Sofort,
+            "us_bank_account" => SubscriptionPaymentMethodType::UsBankAccount,
+            "wechat_pay" => SubscriptionPaymentMethodType::WechatPay,
+            _ => SubscriptionPaymentMethodType::Other(value),
+        }
+    }
+}
+
+impl AsRef<str> for SubscriptionPaymentMethodType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SubscriptionPaymentMethodType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for SubscriptionPaymentMethodType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SubscriptionPaymentMethodType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(SubscriptionPaymentMethodType::from(s))
+    }
+}
+
 /// An enum representing the possible values of an `CreateSubscription`'s `proration_behavior` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -1056,6 +1335,35 @@ impl std::fmt::Display for SubscriptionProrationBehavior {
     }
 }
 
+/// An enum representing the possible values of an `SubscriptionsResourcePaymentSettings`'s `save_default_payment_method` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionSaveDefaultPaymentMethod {
+    Off,
+    OnSubscription,
+}
+
+impl SubscriptionSaveDefaultPaymentMethod {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SubscriptionSaveDefaultPaymentMethod::Off => "off",
+            SubscriptionSaveDefaultPaymentMethod::OnSubscription => "on_subscription",
+        }
+    }
+}
+
+impl AsRef<str> for SubscriptionSaveDefaultPaymentMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SubscriptionSaveDefaultPaymentMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `Subscription`'s `status` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -1138,6 +1446,39 @@ impl std::fmt::Display for SubscriptionStatusFilter {
     }
 }
 
+/// An enum representing the possible values of an `SubscriptionTrialSettingsEndBehavior`'s `missing_payment_method` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionTrialSettingsEndBehaviorMissingPaymentMethod {
+    Cancel,
+    CreateInvoice,
+    Pause,
+}
+
+impl SubscriptionTrialSettingsEndBehaviorMissingPaymentMethod {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SubscriptionTrialSettingsEndBehaviorMissingPaymentMethod::Cancel => "cancel",
+            SubscriptionTrialSettingsEndBehaviorMissingPaymentMethod::CreateInvoice => {
+                "create_invoice"
+            }
+            SubscriptionTrialSettingsEndBehaviorMissingPaymentMethod::Pause => "pause",
+        }
+    }
+}
+
+impl AsRef<str> for SubscriptionTrialSettingsEndBehaviorMissingPaymentMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SubscriptionTrialSettingsEndBehaviorMissingPaymentMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
 /// An enum representing the possible values of an `SubscriptionsResourcePauseCollection`'s `behavior` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -1,10 +1,16 @@
 use crate::config::{Client, Response};
-use crate::ids::SubscriptionId;
-use crate::resources::{CreateSubscriptionItems, Subscription};
+use crate::ids::{DiscountId, SubscriptionId};
+use crate::params::{Deleted, Timestamp};
+use crate::resources::{CreateSubscriptionItems, Scheduled, Subscription, UpdateSubscription};
 use serde_derive::Serialize;
 
+/// The parameters for `Subscription::cancel`.
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct CancelSubscription {
+    /// Keep the subscription active until the end of the current period, instead of canceling it now.
+    ///
+    /// Stripe no longer accepts this on the cancel endpoint, so it is sent as `cancel_at_period_end`
+    /// to `Subscription::update`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub at_period_end: Option<bool>,
 }
@@ -18,13 +24,54 @@ impl CancelSubscription {
 impl Subscription {
     /// Cancels a subscription.
     ///
+    /// With `at_period_end` set, this updates the subscription with `cancel_at_period_end=true`
+    /// and returns it still active; otherwise it is canceled immediately.
+    ///
     /// For more details see https://stripe.com/docs/api#cancel_subscription.
     pub fn cancel(
         client: &Client,
         subscription_id: &SubscriptionId,
         params: CancelSubscription,
     ) -> Response<Subscription> {
-        client.delete_query(&format!("/subscriptions/{}", subscription_id), params)
+        if params.at_period_end == Some(true) {
+            let mut update = UpdateSubscription::new();
+            update.cancel_at_period_end = Some(true);
+            return Subscription::update(client, subscription_id, update);
+        }
+        client.delete(&format!("/subscriptions/{}", subscription_id))
+    }
+
+    /// Removes the currently applied discount on a subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api/discounts/subscription_delete.
+    /// The id is missing from the response on API versions before 2020-08-27.
+    pub fn delete_discount(
+        client: &Client,
+        subscription_id: &SubscriptionId,
+    ) -> Response<Deleted<Option<DiscountId>>> {
+        client.delete(&format!("/subscriptions/{}/discount", subscription_id))
+    }
+
+    /// Moves the end of the subscription's trial to `trial_end`.
+    ///
+    /// This is a shorthand for `Subscription::update` with `trial_end` set; the subscription stays `trialing` until then.
+    pub fn extend_trial(
+        client: &Client,
+        subscription_id: &SubscriptionId,
+        trial_end: Timestamp,
+    ) -> Response<Subscription> {
+        let mut params = UpdateSubscription::new();
+        params.trial_end = Some(Scheduled::at(trial_end));
+        Subscription::update(client, subscription_id, params)
+    }
+
+    /// Ends the subscription's trial immediately and starts billing.
+    ///
+    /// This is a shorthand for `Subscription::update` with `trial_end=now`.
+    pub fn end_trial(client: &Client, subscription_id: &SubscriptionId) -> Response<Subscription> {
+        let mut params = UpdateSubscription::new();
+        params.trial_end = Some(Scheduled::now());
+        Subscription::update(client, subscription_id, params)
     }
 }
 
@@ -5,13 +5,16 @@
 use crate::config::{Client, Response};
 use crate::ids::{PlanId, PriceId, SubscriptionId, SubscriptionItemId};
 use crate::params::{Deleted, Expand, List, Metadata, Object, Timestamp};
-use crate::resources::{Currency, Plan, Price, SubscriptionItemBillingThresholds, TaxRate};
+use crate::resources::{
+    Currency, Plan, PlanInterval, Price, SubscriptionItemBillingThresholds, TaxRate,
+};
 use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "SubscriptionItem".
 ///
 /// For more details see [https://stripe.com/docs/api/subscription_items/object](https://stripe.com/docs/api/subscription_items/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct SubscriptionItem {
     /// Unique identifier for the object.
     pub id: SubscriptionItemId,
@@ -63,7 +66,7 @@ impl SubscriptionItem {
         client: &Client,
         params: ListSubscriptionItems<'_>,
     ) -> Response<List<SubscriptionItem>> {
-        client.get_query("/subscription_items", &params)
+        List::get_with_params(client, "/subscription_items", &params)
     }
 
     /// Adds a new item to an existing subscription.
@@ -384,39 +387,6 @@ pub struct SubscriptionItemPriceDataRecurring {
     pub interval_count: Option<u64>,
 }
 
-/// An enum representing the possible values of an `SubscriptionItemPriceDataRecurring`'s `interval` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum PlanInterval {
-    Day,
-    Month,
-    Week,
-    Year,
-}
-
-impl PlanInterval {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            PlanInterval::Day => "day",
-            PlanInterval::Month => "month",
-            PlanInterval::Week => "week",
-            PlanInterval::Year => "year",
-        }
-    }
-}
-
-impl AsRef<str> for PlanInterval {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
-
-impl std::fmt::Display for PlanInterval {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-
 /// An enum representing the possible values of an `CreateSubscriptionItem`'s `payment_behavior` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -1,6 +1,7 @@
 use crate::config::{Client, Response};
 use crate::ids::{InvoiceId, SubscriptionItemId, UsageRecordId, UsageRecordSummaryId};
 use crate::params::{Expand, List, Object, Timestamp};
+use crate::resources::Scheduled;
 use crate::OpenPeriod;
 use serde_derive::{Deserialize, Serialize};
 
@@ -31,7 +32,10 @@ pub struct CreateUsageRecord {
     pub quantity: u64,
 
     /// The timestamp when this usage occurred.
-    pub timestamp: Timestamp,
+    ///
+    /// Use `Scheduled::now()` to let Stripe use the time the request is received,
+    /// which avoids rejections from clocks that run slightly ahead.
+    pub timestamp: Scheduled,
 
     /// Valid values are `Increment` (default) or `Set`.
     /// When using `Increment` the specified quantity will be added to the usage at the specified timestamp.
@@ -43,7 +47,12 @@ pub struct CreateUsageRecord {
 
 impl CreateUsageRecord {
     pub fn new(quantity: u64, timestamp: Timestamp) -> Self {
-        CreateUsageRecord { quantity, timestamp, action: None }
+        CreateUsageRecord { quantity, timestamp: Scheduled::at(timestamp), action: None }
+    }
+
+    /// Creates a usage record timestamped by Stripe when the request is received.
+    pub fn now(quantity: u64) -> Self {
+        CreateUsageRecord { quantity, timestamp: Scheduled::now(), action: None }
     }
 }
 
@@ -75,6 +84,7 @@ impl UsageRecord {
 ///
 /// For more details see [https://stripe.com/docs/api/usage_records/subscription_item_summary_list](https://stripe.com/docs/api/usage_records/subscription_item_summary_list).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct UsageRecordSummary {
     /// Unique identifier for the object.
     pub id: UsageRecordSummaryId,
@@ -104,7 +114,7 @@ impl UsageRecordSummary {
         // This is a bit of a strange API since params.subscription_item needs to go into the URL,
         // but the rest of the parameters (except subscription_item) need to be passed via query params.
         let url = format!("/subscription_items/{}/usage_record_summaries", &id);
-        client.get_query(&url, &params)
+        List::get_with_params(client, &url, &params)
     }
 }
 
@@ -3,11 +3,12 @@
 // ======================================
 
 use crate::config::{Client, Response};
-use crate::ids::{CustomerId, SubscriptionScheduleId};
+use crate::ids::{CustomerId, SubscriptionId, SubscriptionScheduleId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{
-    CollectionMethod, Coupon, Currency, Customer, PaymentMethod, Plan, Price, Scheduled,
-    Subscription, SubscriptionBillingThresholds, SubscriptionItemBillingThresholds, TaxRate,
+    CollectionMethod, Coupon, Currency, Customer, PaymentMethod, Plan, PlanInterval, Price,
+    Scheduled, Subscription, SubscriptionBillingThresholds, SubscriptionItemBillingThresholds,
+    TaxRate,
 };
 use serde_derive::{Deserialize, Serialize};
 
@@ -15,6 +16,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/subscription_schedules/object](https://stripe.com/docs/api/subscription_schedules/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct SubscriptionSchedule {
     /// Unique identifier for the object.
     pub id: SubscriptionScheduleId,
@@ -58,6 +60,7 @@ pub struct SubscriptionSchedule {
     pub metadata: Metadata,
 
     /// Configuration for the subscription schedule's phases.
+    #[serde(default)]
     pub phases: Vec<SubscriptionSchedulePhaseConfiguration>,
 
     /// Time at which the subscription schedule was released.
@@ -87,7 +90,7 @@ impl SubscriptionSchedule {
         client: &Client,
         params: ListSubscriptionSchedules<'_>,
     ) -> Response<List<SubscriptionSchedule>> {
-        client.get_query("/subscription_schedules", &params)
+        List::get_with_params(client, "/subscription_schedules", &params)
     }
 
     /// Creates a new subscription schedule object.
@@ -185,6 +188,7 @@ pub struct SubscriptionSchedulePhaseConfiguration {
     pub invoice_settings: Option<SubscriptionScheduleInvoiceSettings>,
 
     /// Plans to subscribe during this phase of the subscription schedule.
+    #[serde(default)]
     pub plans: Vec<SubscriptionScheduleConfigurationItem>,
 
     /// Controls whether or not the subscription schedule will prorate when transitioning to this phase.
@@ -249,7 +253,7 @@ pub struct SubscriptionScheduleDefaultSettings {
     /// When charging automatically, Stripe will attempt to pay the underlying subscription at the end of each billing cycle using the default source attached to the customer.
     /// When sending an invoice, Stripe will email your customer an invoice with payment instructions.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub collection_method: Option<SubscriptionScheduleDefaultSettingsCollectionMethod>,
+    pub collection_method: Option<CollectionMethod>,
 
     /// ID of the default payment method for the subscription schedule.
     ///
@@ -290,7 +294,7 @@ pub struct CreateSubscriptionSchedule<'a> {
     /// When using this parameter, other parameters (such as phase values) cannot be set.
     /// To create a subscription schedule with other modifications, we recommend making two separate API calls.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub from_subscription: Option<&'a str>,
+    pub from_subscription: Option<SubscriptionId>,
 
     /// Set of key-value pairs that you can attach to an object.
     ///
@@ -328,12 +332,20 @@ impl<'a> CreateSubscriptionSchedule<'a> {
             start_date: Default::default(),
         }
     }
+
+    /// Parameters that migrate an existing subscription to be managed by a new subscription schedule.
+    ///
+    /// Stripe rejects the other parameters (such as `phases`) in this request, so only `expand`
+    /// should be set on the result; make the changes with `SubscriptionSchedule::update` afterwards.
+    pub fn from_subscription(subscription: SubscriptionId) -> Self {
+        CreateSubscriptionSchedule { from_subscription: Some(subscription), ..Self::new() }
+    }
 }
 
 /// The parameters for `SubscriptionSchedule::list`.
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct ListSubscriptionSchedules<'a> {
-    /// Only return subscription schedules that were created canceled the given date interval.
+    /// Only return subscription schedules that were canceled during the given date interval.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub canceled_at: Option<RangeQuery<Timestamp>>,
 
@@ -656,39 +668,6 @@ pub struct SubscriptionItemPriceDataRecurring {
     pub interval_count: Option<u64>,
 }
 
-/// An enum representing the possible values of an `SubscriptionItemPriceDataRecurring`'s `interval` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum PlanInterval {
-    Day,
-    Month,
-    Week,
-    Year,
-}
-
-impl PlanInterval {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            PlanInterval::Day => "day",
-            PlanInterval::Month => "month",
-            PlanInterval::Week => "week",
-            PlanInterval::Year => "year",
-        }
-    }
-}
-
-impl AsRef<str> for PlanInterval {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
-
-impl std::fmt::Display for PlanInterval {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-
 /// An enum representing the possible values of an `SubscriptionSchedulePhaseConfiguration`'s `proration_behavior` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -720,37 +699,6 @@ impl std::fmt::Display for SubscriptionProrationBehavior {
     }
 }
 
-/// An enum representing the possible values of an `SubscriptionScheduleDefaultSettings`'s `collection_method` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-pub enum SubscriptionScheduleDefaultSettingsCollectionMethod {
-    ChargeAutomatically,
-    SendInvoice,
-}
-
-impl SubscriptionScheduleDefaultSettingsCollectionMethod {
-    pub fn as_str(self) -> &'static str {
-        match self {
-            SubscriptionScheduleDefaultSettingsCollectionMethod::ChargeAutomatically => {
-                "charge_automatically"
-            }
-            SubscriptionScheduleDefaultSettingsCollectionMethod::SendInvoice => "send_invoice",
-        }
-    }
-}
-
-impl AsRef<str> for SubscriptionScheduleDefaultSettingsCollectionMethod {
-    fn as_ref(&self) -> &str {
-        self.as_str()
-    }
-}
-
-impl std::fmt::Display for SubscriptionScheduleDefaultSettingsCollectionMethod {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.as_str().fmt(f)
-    }
-}
-
 /// An enum representing the possible values of an `SubscriptionSchedule`'s `end_behavior` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
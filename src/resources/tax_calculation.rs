@@ -0,0 +1,623 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::config::{Client, Response};
+use crate::ids::{CustomerId, TaxCalculationId, TaxCalculationLineItemId};
+use crate::params::{Expand, List, Object, Timestamp};
+use crate::resources::Currency;
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "TaxProductResourceTaxCalculation".
+///
+/// For more details see [https://stripe.com/docs/api/tax/calculations/object](https://stripe.com/docs/api/tax/calculations/object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TaxCalculation {
+    /// Unique identifier for the calculation.
+    pub id: TaxCalculationId,
+
+    /// Total after taxes.
+    pub amount_total: i64,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// The ID of an existing [Customer](https://stripe.com/docs/api/customers/object) used for the resource.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+
+    /// Timestamp of date at which the tax calculation will expire.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+
+    /// The list of items the customer is purchasing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<List<TaxCalculationLineItem>>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// The amount of tax to be collected on top of the line item prices.
+    pub tax_amount_exclusive: i64,
+
+    /// The amount of tax already included in the line item prices.
+    pub tax_amount_inclusive: i64,
+
+    /// Timestamp of date at which the tax rules and rates in effect applies for the calculation.
+    pub tax_date: Timestamp,
+}
+
+impl TaxCalculation {
+    /// Calculates tax based on input and returns a Tax `Calculation` object.
+    pub fn create(client: &Client, params: CreateTaxCalculation<'_>) -> Response<TaxCalculation> {
+        client.post_form("/tax/calculations", &params)
+    }
+
+    /// Retrieves a Tax `Calculation` object, if the calculation hasn't expired.
+    pub fn retrieve(
+        client: &Client,
+        id: &TaxCalculationId,
+        expand: &[&str],
+    ) -> Response<TaxCalculation> {
+        client.get_query(&format!("/tax/calculations/{}", id), &Expand { expand })
+    }
+
+    /// Retrieves the line items of a tax calculation as a collection, if the calculation hasn't expired.
+    pub fn list_line_items(
+        client: &Client,
+        id: &TaxCalculationId,
+        params: ListTaxCalculationLineItems<'_>,
+    ) -> Response<List<TaxCalculationLineItem>> {
+        List::get_with_params(client, &format!("/tax/calculations/{}/line_items", id), &params)
+    }
+}
+
+impl Object for TaxCalculation {
+    type Id = TaxCalculationId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "tax.calculation"
+    }
+}
+
+/// The resource representing a Stripe "TaxProductResourceTaxCalculationLineItem".
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TaxCalculationLineItem {
+    /// Unique identifier for the object.
+    pub id: TaxCalculationLineItemId,
+
+    /// The line item amount in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal).
+    ///
+    /// If `tax_behavior=inclusive`, then this amount includes taxes.
+    /// Otherwise, taxes were calculated on top of this amount.
+    pub amount: i64,
+
+    /// The amount of tax calculated for this line item, in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal).
+    pub amount_tax: i64,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// The ID of an existing [Product](https://stripe.com/docs/api/products/object).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<String>,
+
+    /// The number of units of the item being purchased.
+    ///
+    /// For reversals, this is the quantity reversed.
+    pub quantity: u64,
+
+    /// A custom identifier for this line item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    /// Specifies whether the `amount` includes taxes.
+    ///
+    /// If `tax_behavior=inclusive`, then the amount includes taxes.
+    pub tax_behavior: TaxCalculationLineItemTaxBehavior,
+
+    /// Detailed account of taxes relevant to this line item.
+    ///
+    /// Only present when `tax_breakdown` was expanded or the line items were listed.
+    #[serde(default)]
+    pub tax_breakdown: Vec<TaxBreakdownItem>,
+
+    /// The [tax code](https://stripe.com/docs/tax/tax-categories) ID used for this resource.
+    pub tax_code: String,
+
+    /// The amount of a single unit of the item, in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_cost: Option<i64>,
+}
+
+impl Object for TaxCalculationLineItem {
+    type Id = TaxCalculationLineItemId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "tax.calculation_line_item"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TaxBreakdownItem {
+    /// The amount of tax, in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal).
+    pub amount: i64,
+
+    pub jurisdiction: TaxJurisdiction,
+
+    /// Indicates whether the jurisdiction was determined by the origin (merchant's address) or destination (customer's address).
+    pub sourcing: TaxBreakdownItemSourcing,
+
+    /// Details regarding the rate for this tax.
+    ///
+    /// This field will be `null` when the tax is not imposed, for example if the product is exempt from tax.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rate_details: Option<TaxBreakdownItemTaxRateDetails>,
+
+    /// The reasoning behind this tax, for example, if the product is tax exempt.
+    pub taxability_reason: TaxBreakdownItemTaxabilityReason,
+
+    /// The amount on which tax is calculated, in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal).
+    pub taxable_amount: i64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TaxJurisdiction {
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
+    pub country: String,
+
+    /// A human-readable name for the jurisdiction imposing the tax.
+    pub display_name: String,
+
+    /// Indicates the level of the jurisdiction imposing the tax.
+    pub level: TaxJurisdictionLevel,
+
+    /// [ISO 3166-2 subdivision code](https://en.wikipedia.org/wiki/ISO_3166-2:US), without country prefix.
+    ///
+    /// For example, "NY" for New York, United States.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TaxBreakdownItemTaxRateDetails {
+    /// A localized display name for tax type, intended to be human-readable.
+    ///
+    /// For example, "Local Sales and Use Tax", "Value-added tax (VAT)", or "Umsatzsteuer (USt.)".
+    pub display_name: String,
+
+    /// The tax rate percentage as a string.
+    ///
+    /// For example, 8.5% is represented as "8.5".
+    pub percentage_decimal: String,
+
+    /// The tax type, such as `vat` or `sales_tax`.
+    pub tax_type: String,
+}
+
+/// The parameters for `TaxCalculation::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTaxCalculation<'a> {
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// The ID of an existing customer to use for this calculation.
+    ///
+    /// If provided, the customer's address and tax IDs are copied to `customer_details`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+
+    /// Details about the customer, including address and tax IDs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_details: Option<CreateTaxCalculationCustomerDetails>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A list of items the customer is purchasing.
+    pub line_items: Vec<CreateTaxCalculationLineItems>,
+
+    /// Timestamp of date at which the tax rules and rates in effect applies for the calculation.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_date: Option<Timestamp>,
+}
+
+impl<'a> CreateTaxCalculation<'a> {
+    pub fn new(currency: Currency, line_items: Vec<CreateTaxCalculationLineItems>) -> Self {
+        CreateTaxCalculation {
+            currency,
+            customer: Default::default(),
+            customer_details: Default::default(),
+            expand: Default::default(),
+            line_items,
+            tax_date: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TaxCalculation::list_line_items`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTaxCalculationLineItems<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TaxCalculationLineItemId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TaxCalculationLineItemId>,
+}
+
+impl<'a> ListTaxCalculationLineItems<'a> {
+    pub fn new() -> Self {
+        ListTaxCalculationLineItems {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            starting_after: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTaxCalculationCustomerDetails {
+    /// The customer's postal address (for example, home or business location).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<CreateTaxCalculationCustomerDetailsAddress>,
+
+    /// The type of customer address provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_source: Option<CreateTaxCalculationCustomerDetailsAddressSource>,
+
+    /// The customer's IP address (IPv4 or IPv6).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTaxCalculationCustomerDetailsAddress {
+    /// City, district, suburb, town, or village.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
+    pub country: String,
+
+    /// Address line 1 (e.g., street, PO Box, or company name).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line1: Option<String>,
+
+    /// Address line 2 (e.g., apartment, suite, unit, or building).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line2: Option<String>,
+
+    /// ZIP or postal code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+
+    /// State, county, province, or region.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreateTaxCalculationLineItems {
+    /// A positive integer representing the line item's total price in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal).
+    ///
+    /// If `tax_behavior=inclusive`, then this amount includes taxes.
+    /// Otherwise, taxes are calculated on top of this amount.
+    pub amount: i64,
+
+    /// If provided, the product's `tax_code` will be used as the line item's `tax_code`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<String>,
+
+    /// The number of units of the item being purchased.
+    ///
+    /// Used to calculate the per-unit price from the total `amount` for the line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+
+    /// A custom identifier for this line item, which must be unique across the line items in the calculation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    /// Specifies whether the `amount` includes taxes.
+    ///
+    /// Defaults to `exclusive`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<TaxCalculationLineItemTaxBehavior>,
+
+    /// A [tax code](https://stripe.com/docs/tax/tax-categories) ID to use for this line item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<String>,
+}
+
+/// An enum representing the possible values of an `CreateTaxCalculationCustomerDetails`'s `address_source` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CreateTaxCalculationCustomerDetailsAddressSource {
+    Billing,
+    Shipping,
+}
+
+impl CreateTaxCalculationCustomerDetailsAddressSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CreateTaxCalculationCustomerDetailsAddressSource::Billing => "billing",
+            CreateTaxCalculationCustomerDetailsAddressSource::Shipping => "shipping",
+        }
+    }
+}
+
+impl AsRef<str> for CreateTaxCalculationCustomerDetailsAddressSource {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CreateTaxCalculationCustomerDetailsAddressSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `TaxBreakdownItem`'s `sourcing` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxBreakdownItemSourcing {
+    Destination,
+    Origin,
+}
+
+impl TaxBreakdownItemSourcing {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaxBreakdownItemSourcing::Destination => "destination",
+            TaxBreakdownItemSourcing::Origin => "origin",
+        }
+    }
+}
+
+impl AsRef<str> for TaxBreakdownItemSourcing {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TaxBreakdownItemSourcing {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `TaxBreakdownItem`'s `taxability_reason` field.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TaxBreakdownItemTaxabilityReason {
+    CustomerExempt,
+    NotCollecting,
+    NotSubjectToTax,
+    NotSupported,
+    PortionProductExempt,
+    PortionReducedRated,
+    PortionStandardRated,
+    ProductExempt,
+    ProductExemptHoliday,
+    ProportionallyRated,
+    ReducedRated,
+    ReverseCharge,
+    StandardRated,
+    TaxableBasisReduced,
+    ZeroRated,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
+}
+
+impl TaxBreakdownItemTaxabilityReason {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TaxBreakdownItemTaxabilityReason::CustomerExempt => "customer_exempt",
+            TaxBreakdownItemTaxabilityReason::NotCollecting => "not_collecting",
+            TaxBreakdownItemTaxabilityReason::NotSubjectToTax => "not_subject_to_tax",
+            TaxBreakdownItemTaxabilityReason::NotSupported => "not_supported",
+            TaxBreakdownItemTaxabilityReason::PortionProductExempt => "portion_product_exempt",
+            TaxBreakdownItemTaxabilityReason::PortionReducedRated => "portion_reduced_rated",
+            TaxBreakdownItemTaxabilityReason::PortionStandardRated => "portion_standard_rated",
+            TaxBreakdownItemTaxabilityReason::ProductExempt => "product_exempt",
+            TaxBreakdownItemTaxabilityReason::ProductExemptHoliday => "product_exempt_holiday",
+            TaxBreakdownItemTaxabilityReason::ProportionallyRated => "proportionally_rated",
+            TaxBreakdownItemTaxabilityReason::ReducedRated => "reduced_rated",
+            TaxBreakdownItemTaxabilityReason::ReverseCharge => "reverse_charge",
+            TaxBreakdownItemTaxabilityReason::StandardRated => "standard_rated",
+            TaxBreakdownItemTaxabilityReason::TaxableBasisReduced => "taxable_basis_reduced",
+            TaxBreakdownItemTaxabilityReason::ZeroRated => "zero_rated",
+            TaxBreakdownItemTaxabilityReason::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for TaxBreakdownItemTaxabilityReason {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "customer_exempt" => TaxBreakdownItemTaxabilityReason::CustomerExempt,
+            "not_collecting" => TaxBreakdownItemTaxabilityReason::NotCollecting,
+            "not_subject_to_tax" => TaxBreakdownItemTaxabilityReason::NotSubjectToTax,
+            "not_supported" => TaxBreakdownItemTaxabilityReason::NotSupported,
+            "portion_product_exempt" => TaxBreakdownItemTaxabilityReason::PortionProductExempt,
+            "portion_reduced_rated" => TaxBreakdownItemTaxabilityReason::PortionReducedRated,
+            "portion_standard_rated" => TaxBreakdownItemTaxabilityReason::PortionStandardRated,
+            "product_exempt" => TaxBreakdownItemTaxabilityReason::ProductExempt,
+            "product_exempt_holiday" => TaxBreakdownItemTaxabilityReason::ProductExemptHoliday,
+            "proportionally_rated" => TaxBreakdownItemTaxabilityReason::ProportionallyRated,
+            "reduced_rated" => TaxBreakdownItemTaxabilityReason::ReducedRated,
+            "reverse_charge" => TaxBreakdownItemTaxabilityReason::ReverseCharge,
+            "standard_rated" => TaxBreakdownItemTaxabilityReason::StandardRated,
+            "taxable_basis_reduced" => TaxBreakdownItemTaxabilityReason::TaxableBasisReduced,
+            "zero_rated" => TaxBreakdownItemTaxabilityReason::ZeroRated,
+            _ => TaxBreakdownItemTaxabilityReason::Other(value),
+        }
+    }
+}
+
+impl AsRef<str> for TaxBreakdownItemTaxabilityReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TaxBreakdownItemTaxabilityReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for TaxBreakdownItemTaxabilityReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TaxBreakdownItemTaxabilityReason {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(TaxBreakdownItemTaxabilityReason::from(s))
+    }
+}
+
+/// An enum representing the possible values of an `TaxCalculationLineItem`'s `tax_behavior` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxCalculationLineItemTaxBehavior {
+    Exclusive,
+    Inclusive,
+}
+
+impl TaxCalculationLineItemTaxBehavior {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaxCalculationLineItemTaxBehavior::Exclusive => "exclusive",
+            TaxCalculationLineItemTaxBehavior::Inclusive => "inclusive",
+        }
+    }
+}
+
+impl AsRef<str> for TaxCalculationLineItemTaxBehavior {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TaxCalculationLineItemTaxBehavior {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `TaxJurisdiction`'s `level` field.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TaxJurisdictionLevel {
+    City,
+    Country,
+    County,
+    District,
+    State,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
+}
+
+impl TaxJurisdictionLevel {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TaxJurisdictionLevel::City => "city",
+            TaxJurisdictionLevel::Country => "country",
+            TaxJurisdictionLevel::County => "county",
+            TaxJurisdictionLevel::District => "district",
+            TaxJurisdictionLevel::State => "state",
+            TaxJurisdictionLevel::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for TaxJurisdictionLevel {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "city" => TaxJurisdictionLevel::City,
+            "country" => TaxJurisdictionLevel::Country,
+            "county" => TaxJurisdictionLevel::County,
+            "district" => TaxJurisdictionLevel::District,
+            "state" => TaxJurisdictionLevel::State,
+            _ => TaxJurisdictionLevel::Other(value),
+        }
+    }
+}
+
+impl AsRef<str> for TaxJurisdictionLevel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TaxJurisdictionLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for TaxJurisdictionLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TaxJurisdictionLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(TaxJurisdictionLevel::from(s))
+    }
+}
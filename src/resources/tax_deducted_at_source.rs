@@ -7,6 +7,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "TaxDeductedAtSource".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct TaxDeductedAtSource {
     /// The end of the invoicing period.
     ///
@@ -9,6 +9,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "tax_id".
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct TaxId {
     /// Unique identifier for the object.
     pub id: TaxIdId,
@@ -75,8 +76,7 @@ pub struct TaxIdVerification {
 }
 
 /// An enum representing the possible values of an `TaxId`'s `type` field.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum TaxIdType {
     AuAbn,
     BrCnpj,
@@ -104,10 +104,15 @@ pub enum TaxIdType {
     Unknown,
     UsEin,
     ZaVat,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
 }
 
 impl TaxIdType {
-    pub fn as_str(self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             TaxIdType::AuAbn => "au_abn",
             TaxIdType::BrCnpj => "br_cnpj",
@@ -135,6 +140,41 @@ impl TaxIdType {
             TaxIdType::Unknown => "unknown",
             TaxIdType::UsEin => "us_ein",
             TaxIdType::ZaVat => "za_vat",
+            TaxIdType::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for TaxIdType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "au_abn" => TaxIdType::AuAbn,
+            "br_cnpj" => TaxIdType::BrCnpj,
+            "br_cpf" => TaxIdType::BrCpf,
+            "ca_bn" => TaxIdType::CaBn,
+            "ca_qst" => TaxIdType::CaQst,
+            "ch_vat" => TaxIdType::ChVat,
+            "es_cif" => TaxIdType::EsCif,
+            "eu_vat" => TaxIdType::EuVat,
+            "hk_br" => TaxIdType::HkBr,
+            "in_gst" => TaxIdType::InGst,
+            "jp_cn" => TaxIdType::JpCn,
+            "kr_brn" => TaxIdType::KrBrn,
+            "li_uid" => TaxIdType::LiUid,
+            "mx_rfc" => TaxIdType::MxRfc,
+            "my_itn" => TaxIdType::MyItn,
+            "my_sst" => TaxIdType::MySst,
+            "no_vat" => TaxIdType::NoVat,
+            "nz_gst" => TaxIdType::NzGst,
+            "ru_inn" => TaxIdType::RuInn,
+            "sg_gst" => TaxIdType::SgGst,
+            "sg_uen" => TaxIdType::SgUen,
+            "th_vat" => TaxIdType::ThVat,
+            "tw_vat" => TaxIdType::TwVat,
+            "unknown" => TaxIdType::Unknown,
+            "us_ein" => TaxIdType::UsEin,
+            "za_vat" => TaxIdType::ZaVat,
+            _ => TaxIdType::Other(value),
         }
     }
 }
@@ -151,6 +191,25 @@ impl std::fmt::Display for TaxIdType {
     }
 }
 
+impl serde::Serialize for TaxIdType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TaxIdType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(TaxIdType::from(s))
+    }
+}
+
 /// An enum representing the possible values of an `TaxIdVerification`'s `status` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -11,6 +11,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/tax_rates/object](https://stripe.com/docs/api/tax_rates/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct TaxRate {
     /// Unique identifier for the object.
     pub id: TaxRateId,
@@ -47,6 +48,7 @@ pub struct TaxRate {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
     /// This represents the tax rate percent out of 100.
@@ -58,7 +60,7 @@ impl TaxRate {
     ///
     /// Tax rates are returned sorted by creation date, with the most recently created tax rates appearing first.
     pub fn list(client: &Client, params: ListTaxRates<'_>) -> Response<List<TaxRate>> {
-        client.get_query("/tax_rates", &params)
+        List::get_with_params(client, "/tax_rates", &params)
     }
 
     /// Creates a new tax rate.
@@ -0,0 +1,324 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::ids::TerminalReaderId;
+use crate::params::{Expandable, Metadata, Object};
+use crate::resources::{PaymentIntent, SetupIntent};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "TerminalReaderReader".
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TerminalReader {
+    /// Unique identifier for the object.
+    pub id: TerminalReaderId,
+
+    /// The most recent action performed by the reader.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<TerminalReaderAction>,
+
+    // Always true for a deleted object
+    #[serde(default)]
+    pub deleted: bool,
+
+    /// The current software version of the reader.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_sw_version: Option<String>,
+
+    /// Type of reader, one of `bbpos_wisepad3`, `stripe_m2`, `bbpos_chipper2x`, `bbpos_wisepos_e`, `verifone_P400`, `simulated_wisepos_e`, or `mobile_phone_reader`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_type: Option<TerminalReaderDeviceType>,
+
+    /// The local IP address of the reader.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+
+    /// Custom label given to the reader for easier identification.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub livemode: Option<bool>,
+
+    /// The location identifier of the reader.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+
+    /// Set of key-value pairs that you can attach to an object.
+    ///
+    /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
+    pub metadata: Metadata,
+
+    /// Serial number of the reader.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial_number: Option<String>,
+
+    /// The networking status of the reader.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TerminalReaderStatus>,
+}
+
+impl Object for TerminalReader {
+    type Id = TerminalReaderId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "terminal.reader"
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TerminalReaderAction {
+    /// Failure code, only set if status is `failed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_code: Option<String>,
+
+    /// Detailed failure message, only set if status is `failed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_message: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process_payment_intent: Option<TerminalReaderProcessPaymentIntentAction>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process_setup_intent: Option<TerminalReaderProcessSetupIntentAction>,
+
+    /// Status of the action performed by the reader.
+    pub status: TerminalReaderActionStatus,
+
+    /// Type of action performed by the reader.
+    #[serde(rename = "type")]
+    pub type_: TerminalReaderActionType,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TerminalReaderProcessPaymentIntentAction {
+    /// Most recent PaymentIntent processed by the reader.
+    pub payment_intent: Expandable<PaymentIntent>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TerminalReaderProcessSetupIntentAction {
+    /// ID of a card PaymentMethod generated from the card_present PaymentMethod that may be attached to a Customer for future transactions.
+    ///
+    /// Only present if it was possible to generate a card PaymentMethod.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generated_card: Option<String>,
+
+    /// Most recent SetupIntent processed by the reader.
+    pub setup_intent: Expandable<SetupIntent>,
+}
+
+/// An enum representing the possible values of an `TerminalReaderAction`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TerminalReaderActionStatus {
+    Failed,
+    InProgress,
+    Succeeded,
+}
+
+impl TerminalReaderActionStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TerminalReaderActionStatus::Failed => "failed",
+            TerminalReaderActionStatus::InProgress => "in_progress",
+            TerminalReaderActionStatus::Succeeded => "succeeded",
+        }
+    }
+}
+
+impl AsRef<str> for TerminalReaderActionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TerminalReaderActionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// An enum representing the possible values of an `TerminalReaderAction`'s `type` field.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TerminalReaderActionType {
+    CollectInputs,
+    ProcessPaymentIntent,
+    ProcessSetupIntent,
+    RefundPayment,
+    SetReaderDisplay,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
+}
+
+impl TerminalReaderActionType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TerminalReaderActionType::CollectInputs => "collect_inputs",
+            TerminalReaderActionType::ProcessPaymentIntent => "process_payment_intent",
+            TerminalReaderActionType::ProcessSetupIntent => "process_setup_intent",
+            TerminalReaderActionType::RefundPayment => "refund_payment",
+            TerminalReaderActionType::SetReaderDisplay => "set_reader_display",
+            TerminalReaderActionType::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for TerminalReaderActionType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "collect_inputs" => TerminalReaderActionType::CollectInputs,
+            "process_payment_intent" => TerminalReaderActionType::ProcessPaymentIntent,
+            "process_setup_intent" => TerminalReaderActionType::ProcessSetupIntent,
+            "refund_payment" => TerminalReaderActionType::RefundPayment,
+            "set_reader_display" => TerminalReaderActionType::SetReaderDisplay,
+            _ => TerminalReaderActionType::Other(value),
+        }
+    }
+}
+
+impl AsRef<str> for TerminalReaderActionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TerminalReaderActionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for TerminalReaderActionType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TerminalReaderActionType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(TerminalReaderActionType::from(s))
+    }
+}
+
+/// An enum representing the possible values of an `TerminalReader`'s `device_type` field.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TerminalReaderDeviceType {
+    BbposChipper2x,
+    BbposWisepad3,
+    BbposWiseposE,
+    MobilePhoneReader,
+    SimulatedWiseposE,
+    StripeM2,
+    VerifoneP400,
+
+    /// A value not yet supported by the library.
+    ///
+    /// Holds the raw value returned by Stripe.
+    Other(String),
+}
+
+impl TerminalReaderDeviceType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TerminalReaderDeviceType::BbposChipper2x => "bbpos_chipper2x",
+            TerminalReaderDeviceType::BbposWisepad3 => "bbpos_wisepad3",
+            TerminalReaderDeviceType::BbposWiseposE => "bbpos_wisepos_e",
+            TerminalReaderDeviceType::MobilePhoneReader => "mobile_phone_reader",
+            TerminalReaderDeviceType::SimulatedWiseposE => "simulated_wisepos_e",
+            TerminalReaderDeviceType::StripeM2 => "stripe_m2",
+            TerminalReaderDeviceType::VerifoneP400 => "verifone_P400",
+            TerminalReaderDeviceType::Other(value) => value.as_str(),
+        }
+    }
+}
+
+impl From<String> for TerminalReaderDeviceType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "bbpos_chipper2x" => TerminalReaderDeviceType::BbposChipper2x,
+            "bbpos_wisepad3" => TerminalReaderDeviceType::BbposWisepad3,
+            "bbpos_wisepos_e" => TerminalReaderDeviceType::BbposWiseposE,
+            "mobile_phone_reader" => TerminalReaderDeviceType::MobilePhoneReader,
+            "simulated_wisepos_e" => TerminalReaderDeviceType::SimulatedWiseposE,
+            "stripe_m2" => TerminalReaderDeviceType::StripeM2,
+            "verifone_P400" => TerminalReaderDeviceType::VerifoneP400,
+            _ => TerminalReaderDeviceType::Other(value),
+        }
+    }
+}
+
+impl AsRef<str> for TerminalReaderDeviceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TerminalReaderDeviceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl serde::Serialize for TerminalReaderDeviceType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TerminalReaderDeviceType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(TerminalReaderDeviceType::from(s))
+    }
+}
+
+/// An enum representing the possible values of an `TerminalReader`'s `status` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TerminalReaderStatus {
+    Offline,
+    Online,
+}
+
+impl TerminalReaderStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TerminalReaderStatus::Offline => "offline",
+            TerminalReaderStatus::Online => "online",
+        }
+    }
+}
+
+impl AsRef<str> for TerminalReaderStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TerminalReaderStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
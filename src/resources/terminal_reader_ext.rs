@@ -0,0 +1,111 @@
+use crate::config::{Client, Response};
+use crate::ids::{PaymentIntentId, SetupIntentId, TerminalReaderId};
+use crate::params::Expand;
+use crate::resources::TerminalReader;
+use serde_derive::{Deserialize, Serialize};
+
+impl TerminalReader {
+    /// Retrieves a `Reader` object.
+    pub fn retrieve(
+        client: &Client,
+        id: &TerminalReaderId,
+        expand: &[&str],
+    ) -> Response<TerminalReader> {
+        client.get_query(&format!("/terminal/readers/{}", id), &Expand { expand })
+    }
+
+    /// Initiates a payment flow on a Reader.
+    pub fn process_payment_intent(
+        client: &Client,
+        id: &TerminalReaderId,
+        params: ProcessPaymentIntentParams<'_>,
+    ) -> Response<TerminalReader> {
+        client.post_form(&format!("/terminal/readers/{}/process_payment_intent", id), &params)
+    }
+
+    /// Initiates a setup intent flow on a Reader.
+    pub fn process_setup_intent(
+        client: &Client,
+        id: &TerminalReaderId,
+        params: ProcessSetupIntentParams<'_>,
+    ) -> Response<TerminalReader> {
+        client.post_form(&format!("/terminal/readers/{}/process_setup_intent", id), &params)
+    }
+
+    /// Cancels the current reader action.
+    pub fn cancel_action(client: &Client, id: &TerminalReaderId) -> Response<TerminalReader> {
+        client.post(&format!("/terminal/readers/{}/cancel_action", id))
+    }
+}
+
+/// The parameters for `TerminalReader::process_payment_intent`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ProcessPaymentIntentParams<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// PaymentIntent ID.
+    pub payment_intent: PaymentIntentId,
+
+    /// Configuration overrides.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process_config: Option<ProcessPaymentIntentConfig>,
+}
+
+impl<'a> ProcessPaymentIntentParams<'a> {
+    pub fn new(payment_intent: PaymentIntentId) -> Self {
+        ProcessPaymentIntentParams {
+            expand: Default::default(),
+            payment_intent,
+            process_config: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TerminalReader::process_setup_intent`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ProcessSetupIntentParams<'a> {
+    /// Customer Consent Collected.
+    pub customer_consent_collected: bool,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Configuration overrides.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process_config: Option<ProcessSetupIntentConfig>,
+
+    /// SetupIntent ID.
+    pub setup_intent: SetupIntentId,
+}
+
+impl<'a> ProcessSetupIntentParams<'a> {
+    pub fn new(setup_intent: SetupIntentId, customer_consent_collected: bool) -> Self {
+        ProcessSetupIntentParams {
+            customer_consent_collected,
+            expand: Default::default(),
+            process_config: Default::default(),
+            setup_intent,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProcessPaymentIntentConfig {
+    /// Enables cancel button on transaction screens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_customer_cancellation: Option<bool>,
+
+    /// Override showing a tipping selection screen on this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_tipping: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProcessSetupIntentConfig {
+    /// Enables cancel button on transaction screens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_customer_cancellation: Option<bool>,
+}
@@ -12,6 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/topups/object](https://stripe.com/docs/api/topups/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Topup {
     /// Unique identifier for the object.
     pub id: TopupId,
@@ -62,6 +63,7 @@ pub struct Topup {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
     pub source: Source,
@@ -84,7 +86,7 @@ pub struct Topup {
 impl Topup {
     /// Returns a list of top-ups.
     pub fn list(client: &Client, params: ListTopups<'_>) -> Response<List<Topup>> {
-        client.get_query("/topups", &params)
+        List::get_with_params(client, "/topups", &params)
     }
 
     /// Retrieves the details of a top-up that has previously been created.
@@ -12,6 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/transfers/object](https://stripe.com/docs/api/transfers/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct Transfer {
     /// Unique identifier for the object.
     pub id: TransferId,
@@ -54,6 +55,7 @@ pub struct Transfer {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
     /// A list of reversals that have been applied to the transfer.
@@ -88,7 +90,7 @@ impl Transfer {
     ///
     /// The transfers are returned in sorted order, with the most recently created transfers appearing first.
     pub fn list(client: &Client, params: ListTransfers<'_>) -> Response<List<Transfer>> {
-        client.get_query("/transfers", &params)
+        List::get_with_params(client, "/transfers", &params)
     }
 
     /// To send funds from your Stripe account to a connected account, you create a new transfer object.
@@ -11,6 +11,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/transfer_reversals/object](https://stripe.com/docs/api/transfer_reversals/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct TransferReversal {
     /// Unique identifier for the object.
     pub id: TransferReversalId,
@@ -39,6 +40,7 @@ pub struct TransferReversal {
     /// Set of key-value pairs that you can attach to an object.
     ///
     /// This can be useful for storing additional information about the object in a structured format.
+    #[serde(default)]
     pub metadata: Metadata,
 
     /// ID of the refund responsible for the transfer reversal.
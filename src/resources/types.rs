@@ -186,6 +186,64 @@ pub enum ApiVersion {
     V2019_08_14,
     #[serde(rename = "2019-09-09")]
     V2019_09_09,
+    #[serde(rename = "2019-10-08")]
+    V2019_10_08,
+    #[serde(rename = "2019-10-17")]
+    V2019_10_17,
+    #[serde(rename = "2019-11-05")]
+    V2019_11_05,
+    #[serde(rename = "2019-12-03")]
+    V2019_12_03,
+    #[serde(rename = "2020-03-02")]
+    V2020_03_02,
+    #[serde(rename = "2020-08-27")]
+    V2020_08_27,
+    #[serde(rename = "2022-08-01")]
+    V2022_08_01,
+    #[serde(rename = "2022-11-15")]
+    V2022_11_15,
+    #[serde(rename = "2023-08-16")]
+    V2023_08_16,
+    #[serde(rename = "2023-10-16")]
+    V2023_10_16,
+    #[serde(rename = "2024-04-10")]
+    V2024_04_10,
+    #[serde(rename = "2024-06-20")]
+    V2024_06_20,
+    #[serde(rename = "2024-09-30.acacia")]
+    V2024_09_30Acacia,
+    #[serde(rename = "2024-10-28.acacia")]
+    V2024_10_28Acacia,
+    #[serde(rename = "2024-11-20.acacia")]
+    V2024_11_20Acacia,
+    #[serde(rename = "2024-12-18.acacia")]
+    V2024_12_18Acacia,
+    #[serde(rename = "2025-01-27.acacia")]
+    V2025_01_27Acacia,
+    #[serde(rename = "2025-02-24.acacia")]
+    V2025_02_24Acacia,
+    #[serde(rename = "2025-03-31.basil")]
+    V2025_03_31Basil,
+    #[serde(rename = "2025-04-30.basil")]
+    V2025_04_30Basil,
+    #[serde(rename = "2025-05-28.basil")]
+    V2025_05_28Basil,
+    #[serde(rename = "2025-06-30.basil")]
+    V2025_06_30Basil,
+    #[serde(rename = "2025-07-30.basil")]
+    V2025_07_30Basil,
+    #[serde(rename = "2025-08-27.basil")]
+    V2025_08_27Basil,
+    #[serde(rename = "2025-09-30.clover")]
+    V2025_09_30Clover,
+    #[serde(rename = "2025-10-29.clover")]
+    V2025_10_29Clover,
+    #[serde(rename = "2025-11-17.clover")]
+    V2025_11_17Clover,
+    #[serde(rename = "2025-12-15.clover")]
+    V2025_12_15Clover,
+    #[serde(rename = "2026-01-28.clover")]
+    V2026_01_28Clover,
 }
 
 impl ApiVersion {
@@ -281,6 +339,35 @@ impl ApiVersion {
             ApiVersion::V2019_05_16 => "2019-05-16",
             ApiVersion::V2019_08_14 => "2019-08-14",
             ApiVersion::V2019_09_09 => "2019-09-09",
+            ApiVersion::V2019_10_08 => "2019-10-08",
+            ApiVersion::V2019_10_17 => "2019-10-17",
+            ApiVersion::V2019_11_05 => "2019-11-05",
+            ApiVersion::V2019_12_03 => "2019-12-03",
+            ApiVersion::V2020_03_02 => "2020-03-02",
+            ApiVersion::V2020_08_27 => "2020-08-27",
+            ApiVersion::V2022_08_01 => "2022-08-01",
+            ApiVersion::V2022_11_15 => "2022-11-15",
+            ApiVersion::V2023_08_16 => "2023-08-16",
+            ApiVersion::V2023_10_16 => "2023-10-16",
+            ApiVersion::V2024_04_10 => "2024-04-10",
+            ApiVersion::V2024_06_20 => "2024-06-20",
+            ApiVersion::V2024_09_30Acacia => "2024-09-30.acacia",
+            ApiVersion::V2024_10_28Acacia => "2024-10-28.acacia",
+            ApiVersion::V2024_11_20Acacia => "2024-11-20.acacia",
+            ApiVersion::V2024_12_18Acacia => "2024-12-18.acacia",
+            ApiVersion::V2025_01_27Acacia => "2025-01-27.acacia",
+            ApiVersion::V2025_02_24Acacia => "2025-02-24.acacia",
+            ApiVersion::V2025_03_31Basil => "2025-03-31.basil",
+            ApiVersion::V2025_04_30Basil => "2025-04-30.basil",
+            ApiVersion::V2025_05_28Basil => "2025-05-28.basil",
+            ApiVersion::V2025_06_30Basil => "2025-06-30.basil",
+            ApiVersion::V2025_07_30Basil => "2025-07-30.basil",
+            ApiVersion::V2025_08_27Basil => "2025-08-27.basil",
+            ApiVersion::V2025_09_30Clover => "2025-09-30.clover",
+            ApiVersion::V2025_10_29Clover => "2025-10-29.clover",
+            ApiVersion::V2025_11_17Clover => "2025-11-17.clover",
+            ApiVersion::V2025_12_15Clover => "2025-12-15.clover",
+            ApiVersion::V2026_01_28Clover => "2026-01-28.clover",
         }
     }
 }
@@ -307,7 +394,7 @@ pub enum AccountHolderType {
     Company,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Address {
     /// Address line 1 or block/building number (e.g. Street address/PO Box/Company name)
     pub line1: Option<String>,
@@ -530,6 +617,47 @@ pub struct Shipping {
     pub tracking_number: Option<String>,
 }
 
+/// The shipping details sent when creating or updating a charge or payment intent.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChargeShippingParams {
+    pub address: Address,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carrier: Option<String>, // eg. Fedex, UPS, USPS
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_number: Option<String>,
+}
+
+impl ChargeShippingParams {
+    pub fn new(name: impl Into<String>, address: Address) -> Self {
+        ChargeShippingParams {
+            address,
+            carrier: None,
+            name: name.into(),
+            phone: None,
+            tracking_number: None,
+        }
+    }
+}
+
+impl From<Shipping> for ChargeShippingParams {
+    fn from(shipping: Shipping) -> Self {
+        ChargeShippingParams {
+            address: shipping.address,
+            carrier: shipping.carrier,
+            name: shipping.name,
+            phone: shipping.phone,
+            tracking_number: shipping.tracking_number,
+        }
+    }
+}
+
+/// The shipping details sent when creating or updating a customer or order.
+///
+/// Unlike `ChargeShippingParams` this has no `carrier` or `tracking_number`, which customers
+/// and orders reject.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ShippingParams {
     pub address: Address,
@@ -538,6 +666,19 @@ pub struct ShippingParams {
     pub phone: Option<String>,
 }
 
+impl ShippingParams {
+    pub fn new(name: impl Into<String>, address: Address) -> Self {
+        ShippingParams { address, name: name.into(), phone: None }
+    }
+}
+
+/// Drops `carrier` and `tracking_number`, which only charges and payment intents accept.
+impl From<Shipping> for ShippingParams {
+    fn from(shipping: Shipping) -> Self {
+        ShippingParams { address: shipping.address, name: shipping.name, phone: shipping.phone }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SpendingLimit {
     /// Maximum amount allowed to spend per time interval.
@@ -4,7 +4,7 @@
 
 use crate::config::{Client, Response};
 use crate::ids::WebhookEndpointId;
-use crate::params::{Deleted, Expand, List, Metadata, Object, Timestamp};
+use crate::params::{Deleted, Expand, List, Metadata, Object, Secret, Timestamp};
 use crate::resources::{ApiVersion, WebhookEndpointStatus};
 use serde_derive::{Deserialize, Serialize};
 
@@ -12,6 +12,7 @@ use serde_derive::{Deserialize, Serialize};
 ///
 /// For more details see [https://stripe.com/docs/api/webhook_endpoints/object](https://stripe.com/docs/api/webhook_endpoints/object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct WebhookEndpoint {
     /// Unique identifier for the object.
     pub id: WebhookEndpointId,
@@ -58,7 +59,7 @@ pub struct WebhookEndpoint {
     ///
     /// Only returned at creation.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub secret: Option<String>,
+    pub secret: Option<Secret>,
 
     /// The status of the webhook.
     ///
@@ -77,7 +78,7 @@ impl WebhookEndpoint {
         client: &Client,
         params: ListWebhookEndpoints<'_>,
     ) -> Response<List<WebhookEndpoint>> {
-        client.get_query("/webhook_endpoints", &params)
+        List::get_with_params(client, "/webhook_endpoints", &params)
     }
 
     /// A webhook endpoint must have a `url` and a list of `enabled_events`.
@@ -1,5 +1,39 @@
+mod local;
 mod mock;
 
+#[cfg(feature = "connect")]
+fn own_account() -> String {
+    local::ok(&serde_json::json!({
+        "id": "acct_1MiN3hLkdIwHu7ix",
+        "object": "account",
+        "charges_enabled": true,
+        "country": "US",
+        "metadata": {}
+    }))
+}
+
+#[cfg(all(feature = "connect", feature = "blocking"))]
+#[test]
+fn retrieve_self() {
+    let (url, requests) = local::serve(vec![own_account()]);
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let account = stripe::Account::retrieve_self(&client).unwrap();
+    assert_eq!(account.id.as_str(), "acct_1MiN3hLkdIwHu7ix");
+    assert_eq!(requests.lock().unwrap()[0].line, "GET /v1/account HTTP/1.1");
+}
+
+#[cfg(all(feature = "connect", not(feature = "blocking")))]
+#[tokio::test]
+async fn retrieve_self() {
+    let (url, requests) = local::serve(vec![own_account()]);
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let account = stripe::Account::retrieve_self(&client).await.unwrap();
+    assert_eq!(account.id.as_str(), "acct_1MiN3hLkdIwHu7ix");
+    assert_eq!(requests.lock().unwrap()[0].line, "GET /v1/account HTTP/1.1");
+}
+
 #[cfg(feature = "connect")]
 #[test]
 fn is_account_listable() {
@@ -0,0 +1,40 @@
+//! The blocking client must also work when called from a thread that is already running tokio.
+#![cfg(feature = "blocking")]
+
+fn unreachable_client() -> stripe::Client {
+    // Nothing listens on port 1, so requests fail fast without needing network access.
+    stripe::Client::from_url("http://127.0.0.1:1/", "sk_test_123")
+}
+
+#[tokio::test]
+async fn blocking_client_in_current_thread_runtime() {
+    let client = unreachable_client();
+    let result = client.get::<serde_json::Value>("/v1/account");
+    assert!(matches!(result, Err(stripe::Error::NetworkFailure { .. })), "{:?}", result);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn blocking_client_in_multi_thread_runtime() {
+    let client = unreachable_client();
+    let result = client.get::<serde_json::Value>("/v1/account");
+    assert!(matches!(result, Err(stripe::Error::NetworkFailure { .. })), "{:?}", result);
+}
+
+#[test]
+fn blocking_client_outside_runtime() {
+    let client = unreachable_client();
+    let result = client.get::<serde_json::Value>("/v1/account");
+    assert!(matches!(result, Err(stripe::Error::NetworkFailure { .. })), "{:?}", result);
+}
+
+#[test]
+fn blocking_client_with_runtime_handle() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let client = unreachable_client().with_runtime_handle(runtime.handle().clone());
+    let result = client.get::<serde_json::Value>("/v1/account");
+    assert!(matches!(result, Err(stripe::Error::NetworkFailure { .. })), "{:?}", result);
+
+    // The handle's runtime may also be the one the client is called from.
+    let result = runtime.block_on(async { client.get::<serde_json::Value>("/v1/account") });
+    assert!(matches!(result, Err(stripe::Error::NetworkFailure { .. })), "{:?}", result);
+}
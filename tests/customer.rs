@@ -12,6 +12,21 @@ fn customer_create_and_delete(client: &stripe::Client) {
     }
 }
 
+#[test]
+fn customer_create_with_default_payment_method() {
+    mock::with_client(|client| {
+        let payment_method: stripe::PaymentMethodId = "pm_card_visa".parse().unwrap();
+        let mut params = stripe::CreateCustomer::new();
+        params.payment_method = Some(payment_method.clone());
+        params.invoice_settings = Some(stripe::CustomerInvoiceSettings {
+            default_payment_method: Some(payment_method.to_string()),
+            ..Default::default()
+        });
+        let customer = stripe::Customer::create(client, params).unwrap();
+        assert!(customer.invoice_settings.is_some());
+    });
+}
+
 #[test]
 fn customer_create_and_delete_without_account() {
     mock::with_client(|client| {
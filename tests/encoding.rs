@@ -62,6 +62,179 @@ fn serialize_range_query() {
     assert_eq!(encoded, "created[gte]=1501598702&created[lt]=1504233902&limit=3");
 }
 
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_subscription_payment_settings() {
+    use stripe::{
+        CreateSubscription, SubscriptionPaymentBehavior, SubscriptionPaymentMethodType,
+        SubscriptionPaymentSettingsParams, SubscriptionSaveDefaultPaymentMethod,
+    };
+
+    let mut params = CreateSubscription::new("cus_123".parse().unwrap());
+    params.expand = &["latest_invoice.payment_intent"];
+    params.payment_behavior = Some(SubscriptionPaymentBehavior::DefaultIncomplete);
+    params.payment_settings = Some(SubscriptionPaymentSettingsParams {
+        payment_method_types: Some(vec![SubscriptionPaymentMethodType::Card]),
+        save_default_payment_method: Some(SubscriptionSaveDefaultPaymentMethod::OnSubscription),
+        ..Default::default()
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "customer=cus_123&expand[0]=latest_invoice.payment_intent&payment_behavior=default_incomplete\
+         &payment_settings[payment_method_types][0]=card\
+         &payment_settings[save_default_payment_method]=on_subscription"
+    );
+
+    let mut params = stripe::UpdateSubscription::new();
+    params.payment_settings = Some(SubscriptionPaymentSettingsParams {
+        payment_method_types: Some(vec![SubscriptionPaymentMethodType::SepaDebit]),
+        ..Default::default()
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "payment_settings[payment_method_types][0]=sepa_debit"
+    );
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn deserialize_subscription_payment_settings() {
+    use stripe::{Subscription, SubscriptionPaymentMethodType};
+
+    let example = json!({
+        "id": "sub_1MiN3hLkdIwHu7ixzHtqI7bV",
+        "object": "subscription",
+        "billing_cycle_anchor": 1678037688,
+        "cancel_at_period_end": false,
+        "created": 1678037688,
+        "customer": "cus_4QFJOjw2pOmAGJ",
+        "items": {
+            "object": "list",
+            "data": [],
+            "has_more": false,
+            "url": "/v1/subscription_items?subscription=sub_1MiN3hLkdIwHu7ixzHtqI7bV"
+        },
+        "livemode": false,
+        "payment_settings": {
+            "payment_method_options": null,
+            "payment_method_types": ["card", "twint"],
+            "save_default_payment_method": "off"
+        },
+        "start_date": 1678037688,
+        "status": "active"
+    });
+    let subscription = serde_json::from_value::<Subscription>(example).unwrap();
+    let types = subscription.payment_settings.unwrap().payment_method_types.unwrap();
+    assert_eq!(
+        types,
+        [SubscriptionPaymentMethodType::Card, SubscriptionPaymentMethodType::Other("twint".into())]
+    );
+    assert_eq!(types[1].as_str(), "twint");
+    assert_eq!(serde_json::to_value(&types).unwrap(), json!(["card", "twint"]));
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_subscription_trial_and_proration() {
+    use stripe::{Scheduled, SubscriptionProrationBehavior, UpdateSubscription};
+
+    let mut params = UpdateSubscription::new();
+    params.proration_behavior = Some(SubscriptionProrationBehavior::AlwaysInvoice);
+    params.proration_date = Some(1501598702);
+    params.trial_end = Some(Scheduled::now());
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "proration_behavior=always_invoice&proration_date=1501598702&trial_end=now"
+    );
+
+    let mut params = UpdateSubscription::new();
+    params.trial_end = Some(Scheduled::at(1504233902));
+    params.trial_from_plan = Some(false);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "trial_end=1504233902&trial_from_plan=false"
+    );
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_subscription_trial_settings() {
+    use stripe::{
+        Scheduled, SubscriptionTrialSettings, SubscriptionTrialSettingsEndBehavior,
+        SubscriptionTrialSettingsEndBehaviorMissingPaymentMethod, UpdateSubscription,
+    };
+
+    let mut params = UpdateSubscription::new();
+    params.trial_end = Some(Scheduled::at(1504233902));
+    params.trial_settings = Some(SubscriptionTrialSettings {
+        end_behavior: SubscriptionTrialSettingsEndBehavior {
+            missing_payment_method: SubscriptionTrialSettingsEndBehaviorMissingPaymentMethod::Pause,
+        },
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "trial_end=1504233902&trial_settings[end_behavior][missing_payment_method]=pause"
+    );
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_subscription_billing_cycle_anchor() {
+    use stripe::{CreateSubscription, SubscriptionBillingCycleAnchor, UpdateSubscription};
+
+    let mut params = CreateSubscription::new("cus_123".parse().unwrap());
+    params.billing_cycle_anchor = Some(1672531200);
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert!(encoded.starts_with("billing_cycle_anchor=1672531200&"), "{}", encoded);
+
+    let mut params = UpdateSubscription::new();
+    params.billing_cycle_anchor = Some(SubscriptionBillingCycleAnchor::Unchanged);
+    assert_eq!(urldecode(serde_qs::to_string(&params).unwrap()), "billing_cycle_anchor=unchanged");
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_subscription_trial_from_plan() {
+    use stripe::{CreateSubscription, SubscriptionPaymentBehavior};
+
+    let mut params = CreateSubscription::new("cus_123".parse().unwrap());
+    params.payment_behavior = Some(SubscriptionPaymentBehavior::DefaultIncomplete);
+    params.trial_from_plan = Some(true);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "customer=cus_123&payment_behavior=default_incomplete&trial_from_plan=true"
+    );
+}
+
+#[test]
+fn serialize_payment_intent_off_session() {
+    use stripe::{
+        CreatePaymentIntent, Currency, OffSessionOther, PaymentIntentOffSession,
+        PaymentIntentSetupFutureUsage,
+    };
+
+    let mut params = CreatePaymentIntent::new(1000, Currency::USD);
+    params.confirm = Some(true);
+    params.off_session = Some(PaymentIntentOffSession::exists(true));
+    params.setup_future_usage = Some(PaymentIntentSetupFutureUsage::OffSession);
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert!(encoded.contains("&off_session=true&"), "{}", encoded);
+    assert!(encoded.contains("&setup_future_usage=off_session"), "{}", encoded);
+
+    params.off_session = Some(PaymentIntentOffSession::frequency(OffSessionOther::Recurring));
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert!(encoded.contains("&off_session=recurring&"), "{}", encoded);
+
+    assert_eq!(
+        serde_json::from_value::<PaymentIntentOffSession>(json!(false)).unwrap(),
+        PaymentIntentOffSession::exists(false)
+    );
+    assert_eq!(
+        serde_json::from_value::<PaymentIntentOffSession>(json!("one_off")).unwrap(),
+        PaymentIntentOffSession::frequency(OffSessionOther::OneOff)
+    );
+}
+
 fn urldecode(input: String) -> String {
     input.replace("%5B", "[").replace("%5D", "]")
 }
@@ -90,6 +263,21 @@ fn deserialize_payment_source_params() {
     }
 }
 
+#[test]
+fn serialize_payment_intent_expand() {
+    use stripe::{CreatePaymentIntent, Currency, PaymentIntentConfirmParams};
+
+    let mut params = CreatePaymentIntent::new(1000, Currency::USD);
+    params.expand = &["customer", "payment_method"];
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "amount=1000&currency=usd&expand[0]=customer&expand[1]=payment_method");
+
+    let mut params = PaymentIntentConfirmParams::default();
+    params.expand = &["latest_charge"];
+    let encoded = urldecode(serde_qs::to_string(&params).unwrap());
+    assert_eq!(encoded, "expand[0]=latest_charge");
+}
+
 #[test]
 fn serialize_payment_source_params() {
     use stripe::{PaymentSourceParams, SourceId, TokenId};
@@ -111,6 +299,42 @@ fn serialize_payment_source_params() {
     }
 }
 
+#[test]
+fn debug_redacts_secrets() {
+    use stripe::{CardParams, Source};
+
+    let example = json!({
+        "id": "src_19Y9NLGjNBy5qT5CZ1gRGxYh",
+        "object": "source",
+        "client_secret": "src_client_secret_9s6XKzkNRiz8i3",
+        "created": 1483565364,
+        "flow": "receiver",
+        "livemode": false,
+        "metadata": {},
+        "status": "pending",
+        "type": "ach_credit_transfer",
+        "usage": "reusable"
+    });
+    let source = serde_json::from_value::<Source>(example).unwrap();
+    assert_eq!(source.client_secret.as_str(), "src_client_secret_9s6XKzkNRiz8i3");
+    let debug = format!("{:?}", source);
+    assert!(debug.contains("client_secret: [redacted]"), "{}", debug);
+    assert!(!debug.contains("src_client_secret_9s6XKzkNRiz8i3"), "{}", debug);
+    let value = serde_json::to_value(&source).unwrap();
+    assert_eq!(value["client_secret"], "src_client_secret_9s6XKzkNRiz8i3");
+
+    let card = CardParams {
+        exp_month: "12",
+        exp_year: "2030",
+        number: "4242424242424242",
+        name: Some("Jenny Rosen"),
+        cvc: Some("314"),
+    };
+    let debug = format!("{:?}", card);
+    assert!(!debug.contains("4242424242424242") && !debug.contains("314"), "{}", debug);
+    assert!(debug.contains("Jenny Rosen"), "{}", debug);
+}
+
 #[test]
 fn deserialize_customer_with_card() {
     use stripe::Customer;
@@ -177,6 +401,54 @@ fn deserialize_customer_with_card() {
     assert!(result.is_ok(), "expected ok; was {:?}", result);
 }
 
+#[test]
+#[cfg(feature = "billing")]
+fn deserialize_discount_without_id() {
+    use stripe::{Customer, Deleted, DiscountId, Object};
+
+    // On the default API version (2019-09-09) discounts carry neither `id` nor `start`.
+    let example = json!({
+      "id": "cus_1234",
+      "object": "customer",
+      "created": 1542631579,
+      "delinquent": false,
+      "discount": {
+        "object": "discount",
+        "coupon": {
+          "id": "SUMMER",
+          "object": "coupon",
+          "duration": "once",
+          "percent_off": 20.0,
+          "valid": true
+        },
+        "customer": "cus_1234",
+        "end": null,
+        "subscription": null
+      },
+      "livemode": false,
+      "metadata": {}
+    });
+    let customer = serde_json::from_value::<Customer>(example).unwrap();
+    let discount = customer.discount.unwrap();
+    assert_eq!(discount.id, None);
+    assert_eq!(discount.start, None);
+    assert!(discount.id().is_none());
+    assert_eq!(discount.coupon.id.as_str(), "SUMMER");
+
+    let deleted: Deleted<Option<DiscountId>> =
+        serde_json::from_value(json!({ "object": "discount", "deleted": true })).unwrap();
+    assert!(deleted.deleted);
+    assert_eq!(deleted.id, None);
+
+    let deleted: Deleted<Option<DiscountId>> = serde_json::from_value(json!({
+        "id": "di_1MiN3hLkdIwHu7ixw2CRdZuJ",
+        "object": "discount",
+        "deleted": true
+    }))
+    .unwrap();
+    assert_eq!(deleted.id.unwrap().as_str(), "di_1MiN3hLkdIwHu7ixw2CRdZuJ");
+}
+
 #[test]
 fn deserialize_customer_with_source() {
     use stripe::Customer;
@@ -311,3 +583,2248 @@ fn deserialize_checkout_event() {
     let result = serde_json::from_value::<Event>(example);
     assert!(result.is_ok(), "expected ok; was {:?}", result);
 }
+
+#[cfg(all(feature = "events", feature = "checkout"))]
+#[test]
+fn deserialize_checkout_session_completed_event() {
+    use stripe::{
+        CheckoutSessionPaymentStatus, CheckoutSessionStatus, Currency, CustomFieldType,
+        CustomerTaxExempt, Event, EventObject, TaxIdType,
+    };
+
+    let example = json!({
+      "id": "evt_1MtL0wLkdIwHu7ixYvwyOw2s",
+      "object": "event",
+      "api_version": "2022-11-15",
+      "created": 1680657670,
+      "data": {
+        "object": {
+          "id": "cs_test_a1YS1URlnyQCN5fUUduORoQ7Pw41PJqDWkIVQCpJPqkfIhd6tVY8XB1OLY",
+          "object": "checkout.session",
+          "amount_subtotal": 2198,
+          "amount_total": 2198,
+          "billing_address_collection": null,
+          "cancel_url": "https://example.com/cancel",
+          "client_reference_id": "order_1234",
+          "currency": "usd",
+          "custom_fields": [
+            {
+              "dropdown": {
+                "options": [
+                  { "label": "Small", "value": "s" },
+                  { "label": "Large", "value": "l" }
+                ],
+                "value": "l"
+              },
+              "key": "tshirt_size",
+              "label": { "custom": "T-shirt size", "type": "custom" },
+              "numeric": null,
+              "optional": false,
+              "text": null,
+              "type": "dropdown"
+            },
+            {
+              "dropdown": null,
+              "key": "referral",
+              "label": { "custom": "Referral code", "type": "custom" },
+              "numeric": null,
+              "optional": true,
+              "text": { "value": null },
+              "type": "text"
+            }
+          ],
+          "custom_text": {
+            "shipping_address": { "message": "Your item will ship in 5-7 days." },
+            "submit": null,
+            "terms_of_service_acceptance": null
+          },
+          "customer": "cus_NeZwdNtLEOXuvB",
+          "customer_details": {
+            "address": {
+              "city": "South San Francisco",
+              "country": "US",
+              "line1": "354 Oyster Point Blvd",
+              "line2": null,
+              "postal_code": "94080",
+              "state": "CA"
+            },
+            "email": "jenny.rosen@example.com",
+            "name": "Jenny Rosen",
+            "phone": null,
+            "tax_exempt": "none",
+            "tax_ids": [
+                { "type": "eu_vat", "value": "DE123456789" },
+                { "type": "gb_vat", "value": "GB123456789" }
+            ]
+          },
+          "customer_email": null,
+          "livemode": false,
+          "locale": null,
+          "metadata": {},
+          "mode": "payment",
+          "payment_intent": "pi_3MtL0uLkdIwHu7ix0UTLyR2d",
+          "payment_method_types": ["card"],
+          "payment_status": "paid",
+          "setup_intent": null,
+          "shipping": null,
+          "shipping_address_collection": null,
+          "status": "complete",
+          "submit_type": null,
+          "subscription": null,
+          "success_url": "https://example.com/success",
+          "url": null
+        }
+      },
+      "livemode": false,
+      "pending_webhooks": 1,
+      "request": { "id": null, "idempotency_key": null },
+      "type": "checkout.session.completed"
+    });
+    let event = serde_json::from_value::<Event>(example).unwrap();
+    let session = match event.data.object {
+        EventObject::CheckoutSession(session) => session,
+        other => panic!("expected a checkout session; was {:?}", other),
+    };
+    assert_eq!(session.payment_status, Some(CheckoutSessionPaymentStatus::Paid));
+    assert_eq!(session.status, Some(CheckoutSessionStatus::Complete));
+    assert_eq!(session.amount_subtotal, Some(2198));
+    assert_eq!(session.amount_total, Some(2198));
+    assert_eq!(session.currency, Some(Currency::USD));
+    assert_eq!(session.url, None);
+
+    let details = session.customer_details.unwrap();
+    assert_eq!(details.email.as_deref(), Some("jenny.rosen@example.com"));
+    assert_eq!(details.name.as_deref(), Some("Jenny Rosen"));
+    assert_eq!(details.address.unwrap().postal_code.as_deref(), Some("94080"));
+    assert_eq!(details.tax_exempt, Some(CustomerTaxExempt::None));
+    assert_eq!(details.tax_ids[0].type_, TaxIdType::EuVat);
+    assert_eq!(details.tax_ids[0].value, "DE123456789");
+    // Types added after this list was written keep the value Stripe sent.
+    assert_eq!(details.tax_ids[1].type_, TaxIdType::Other("gb_vat".into()));
+
+    let size = &session.custom_fields[0];
+    assert_eq!(size.key, "tshirt_size");
+    assert_eq!(size.type_, CustomFieldType::Dropdown);
+    assert_eq!(size.label.custom.as_deref(), Some("T-shirt size"));
+    assert_eq!(size.dropdown.as_ref().unwrap().value.as_deref(), Some("l"));
+    let referral = &session.custom_fields[1];
+    assert!(referral.optional);
+    assert_eq!(referral.type_, CustomFieldType::Text);
+    assert_eq!(referral.text.as_ref().unwrap().value, None);
+
+    let custom_text = session.custom_text.unwrap();
+    assert_eq!(custom_text.shipping_address.unwrap().message, "Your item will ship in 5-7 days.");
+    assert!(custom_text.submit.is_none() && custom_text.terms_of_service_acceptance.is_none());
+}
+
+#[cfg(feature = "checkout")]
+#[test]
+fn deserialize_payment_link() {
+    use stripe::{CustomFieldType, Object, PaymentLink};
+
+    let example = json!({
+        "id": "plink_1MoC3ULkdIwHu7ixZjtGpVl2",
+        "object": "payment_link",
+        "active": true,
+        "allow_promotion_codes": false,
+        "currency": "usd",
+        "custom_fields": [{
+            "dropdown": {
+                "options": [
+                    { "label": "Small", "value": "s" },
+                    { "label": "Medium", "value": "m" }
+                ]
+            },
+            "key": "size",
+            "label": { "custom": "T-shirt size", "type": "custom" },
+            "optional": false,
+            "type": "dropdown"
+        }],
+        "livemode": false,
+        "metadata": {},
+        "url": "https://buy.stripe.com/test_cN25nr0iZ7bUa7meUY"
+    });
+    let link = serde_json::from_value::<PaymentLink>(example).unwrap();
+    assert_eq!(link.id(), "plink_1MoC3ULkdIwHu7ixZjtGpVl2");
+    let size = &link.custom_fields[0];
+    assert_eq!(size.type_, CustomFieldType::Dropdown);
+    assert_eq!(size.dropdown.as_ref().unwrap().options[1].value, "m");
+    assert!(link.line_items.data.is_empty());
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn deserialize_quote() {
+    use stripe::{Object, Quote, QuoteStatus};
+
+    let example = json!({
+        "id": "qt_1MoC3ULkdIwHu7ixZjtGpVl2",
+        "object": "quote",
+        "amount_subtotal": 2000,
+        "amount_total": 2000,
+        "created": 1_679_000_000,
+        "currency": "usd",
+        "customer": "cus_4QFJOjw2pOmAGJ",
+        "description": null,
+        "expires_at": 1_681_592_000,
+        "footer": null,
+        "header": null,
+        "invoice": null,
+        "livemode": false,
+        "metadata": {},
+        "number": null,
+        "status": "draft",
+        "subscription": null,
+        "subscription_data": {
+            "description": null,
+            "effective_date": 1_672_531_200,
+            "metadata": { "order_id": "6735" },
+            "trial_period_days": 14
+        }
+    });
+    let quote = serde_json::from_value::<Quote>(example).unwrap();
+    assert_eq!(quote.id(), "qt_1MoC3ULkdIwHu7ixZjtGpVl2");
+    assert_eq!(quote.status, QuoteStatus::Draft);
+    assert_eq!(quote.customer.unwrap().id(), "cus_4QFJOjw2pOmAGJ");
+    assert_eq!(quote.subscription_data.effective_date, Some(1_672_531_200));
+    assert_eq!(quote.subscription_data.trial_period_days, Some(14));
+    assert_eq!(quote.subscription_data.metadata.unwrap()["order_id"], "6735");
+}
+
+#[test]
+fn deserialize_payment_intent_next_action() {
+    use stripe::{
+        FundingInstructionsBankTransferFinancialAddressSupportedNetworks,
+        FundingInstructionsBankTransferFinancialAddressType, PaymentIntentNextAction,
+        PaymentIntentNextActionDisplayBankTransferInstructionsType, PaymentIntentNextActionType,
+    };
+
+    let example = json!({
+        "type": "display_bank_transfer_instructions",
+        "display_bank_transfer_instructions": {
+            "amount_remaining": 1000,
+            "currency": "usd",
+            "financial_addresses": [{
+                "aba": {
+                    "account_number": "000123456789",
+                    "bank_name": "Test Bank",
+                    "routing_number": "110000000"
+                },
+                "supported_networks": ["ach", "domestic_wire_us"],
+                "type": "aba"
+            }, {
+                "swift": {
+                    "account_number": "000123456789",
+                    "bank_name": "Test Bank",
+                    "swift_code": "TSTBUS33"
+                },
+                "supported_networks": ["swift", "some_future_network"],
+                "type": "swift"
+            }],
+            "hosted_instructions_url": "https://payments.stripe.com/bank_transfers/instructions/test",
+            "reference": "ABC123",
+            "type": "us_bank_transfer"
+        }
+    });
+    let action = serde_json::from_value::<PaymentIntentNextAction>(example).unwrap();
+    assert_eq!(action.type_, PaymentIntentNextActionType::DisplayBankTransferInstructions);
+    let instructions = action.display_bank_transfer_instructions.unwrap();
+    assert_eq!(
+        instructions.type_,
+        PaymentIntentNextActionDisplayBankTransferInstructionsType::UsBankTransfer
+    );
+    let addresses = instructions.financial_addresses.unwrap();
+    assert_eq!(addresses[0].type_, FundingInstructionsBankTransferFinancialAddressType::Aba);
+    assert_eq!(addresses[0].aba.as_ref().unwrap().routing_number, "110000000");
+    assert_eq!(addresses[1].type_, FundingInstructionsBankTransferFinancialAddressType::Swift);
+    assert_eq!(addresses[1].swift.as_ref().unwrap().swift_code, "TSTBUS33");
+    assert_eq!(
+        addresses[1].supported_networks,
+        Some(vec![
+            FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Swift,
+            FundingInstructionsBankTransferFinancialAddressSupportedNetworks::Other(
+                "some_future_network".into()
+            ),
+        ])
+    );
+    assert!(action.other.is_empty());
+
+    let example = json!({
+        "type": "some_future_action",
+        "some_future_action": { "url": "https://example.com" }
+    });
+    let action = serde_json::from_value::<PaymentIntentNextAction>(example).unwrap();
+    assert_eq!(action.type_, PaymentIntentNextActionType::Other);
+    assert_eq!(action.other["some_future_action"]["url"], "https://example.com");
+}
+
+#[test]
+fn serialize_intent_payment_method_options() {
+    use stripe::{
+        CreatePaymentIntent, CreateSetupIntent, CreateSetupIntentPaymentMethodOptions,
+        CreateSetupIntentPaymentMethodOptionsCard,
+        CreateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure, Currency,
+        PaymentIntentPaymentMethodOptions, PaymentIntentPaymentMethodOptionsCard,
+        PaymentIntentPaymentMethodOptionsCardRequestThreeDSecure,
+        PaymentIntentPaymentMethodOptionsUsBankAccount,
+        PaymentIntentPaymentMethodOptionsVerificationMethod,
+    };
+
+    let mut params = CreatePaymentIntent::new(1000, Currency::USD);
+    params.payment_method_options = Some(PaymentIntentPaymentMethodOptions {
+        card: Some(PaymentIntentPaymentMethodOptionsCard {
+            request_three_d_secure: Some(
+                PaymentIntentPaymentMethodOptionsCardRequestThreeDSecure::Any,
+            ),
+            ..Default::default()
+        }),
+        us_bank_account: Some(PaymentIntentPaymentMethodOptionsUsBankAccount {
+            verification_method: Some(PaymentIntentPaymentMethodOptionsVerificationMethod::Instant),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "amount=1000&currency=usd\
+         &payment_method_options[card][request_three_d_secure]=any\
+         &payment_method_options[us_bank_account][verification_method]=instant"
+    );
+
+    let mut params = CreateSetupIntent::new();
+    params.payment_method_options = Some(CreateSetupIntentPaymentMethodOptions {
+        card: Some(CreateSetupIntentPaymentMethodOptionsCard {
+            request_three_d_secure: Some(
+                CreateSetupIntentPaymentMethodOptionsCardRequestThreeDSecure::Any,
+            ),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "payment_method_options[card][request_three_d_secure]=any"
+    );
+}
+
+#[test]
+fn deserialize_intent_payment_method_options() {
+    use stripe::{
+        PaymentIntentPaymentMethodOptions,
+        PaymentIntentPaymentMethodOptionsCardRequestThreeDSecure, SetupIntentPaymentMethodOptions,
+    };
+
+    let example = json!({
+        "card": {
+            "installments": null,
+            "mandate_options": null,
+            "network": null,
+            "request_three_d_secure": "challenge_only"
+        },
+        "klarna": { "preferred_locale": null },
+        "sepa_debit": { "mandate_options": {} }
+    });
+    let options =
+        serde_json::from_value::<PaymentIntentPaymentMethodOptions>(example.clone()).unwrap();
+    let request_three_d_secure = options.card.unwrap().request_three_d_secure.unwrap();
+    assert_eq!(
+        request_three_d_secure,
+        PaymentIntentPaymentMethodOptionsCardRequestThreeDSecure::ChallengeOnly
+    );
+    assert_eq!(request_three_d_secure.to_string(), "challenge_only");
+    assert!(options.sepa_debit.unwrap().mandate_options.is_some());
+
+    let result = serde_json::from_value::<SetupIntentPaymentMethodOptions>(example);
+    assert!(result.is_ok(), "expected ok; was {:?}", result);
+}
+
+#[test]
+fn deserialize_payment_method_card_wallet() {
+    use stripe::{PaymentMethod, WalletDetailsType};
+
+    let card = json!({
+        "id": "pm_1EUmyr2x6R10KRrhlYS3l97f",
+        "object": "payment_method",
+        "billing_details": {
+            "address": null,
+            "email": null,
+            "name": "Jenny Rosen",
+            "phone": null
+        },
+        "card": {
+            "brand": "visa",
+            "checks": {
+                "address_line1_check": null,
+                "address_postal_code_check": null,
+                "cvc_check": "pass"
+            },
+            "country": "US",
+            "exp_month": 8,
+            "exp_year": 2030,
+            "fingerprint": "Xt5EWLLDS7FJjR1c",
+            "funding": "credit",
+            "generated_from": null,
+            "last4": "4242",
+            "networks": {
+                "available": ["visa"],
+                "preferred": null
+            },
+            "three_d_secure_usage": {
+                "supported": true
+            },
+            "wallet": null
+        },
+        "created": 1556596976,
+        "customer": null,
+        "livemode": false,
+        "metadata": {},
+        "type": "card"
+    });
+    let method = serde_json::from_value::<PaymentMethod>(card.clone()).unwrap();
+    let details = method.card.unwrap();
+    assert_eq!(details.networks.unwrap().available, vec!["visa".to_string()]);
+    assert!(details.three_d_secure_usage.unwrap().supported);
+    assert!(details.wallet.is_none());
+
+    let mut apple_pay = card;
+    apple_pay["card"]["wallet"] = json!({
+        "apple_pay": {},
+        "dynamic_last4": "4242",
+        "type": "apple_pay"
+    });
+    let method = serde_json::from_value::<PaymentMethod>(apple_pay).unwrap();
+    let wallet = method.card.unwrap().wallet.unwrap();
+    assert_eq!(wallet.type_, WalletDetailsType::ApplePay);
+    assert!(wallet.apple_pay.is_some());
+    assert_eq!(wallet.dynamic_last4.as_deref(), Some("4242"));
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_price_currency_options() {
+    use std::collections::HashMap;
+    use stripe::{
+        CreatePrice, CreatePriceCurrencyOptions, CreatePriceTiers, Currency, PriceTaxBehavior,
+        UpTo, UpToOther,
+    };
+
+    let mut options = HashMap::new();
+    options.insert(
+        Currency::EUR,
+        CreatePriceCurrencyOptions {
+            tax_behavior: Some(PriceTaxBehavior::Inclusive),
+            tiers: Some(vec![
+                CreatePriceTiers {
+                    flat_amount: None,
+                    flat_amount_decimal: None,
+                    unit_amount: Some(900),
+                    unit_amount_decimal: None,
+                    up_to: Some(UpTo::Max(10)),
+                },
+                CreatePriceTiers {
+                    flat_amount: None,
+                    flat_amount_decimal: None,
+                    unit_amount: Some(800),
+                    unit_amount_decimal: None,
+                    up_to: Some(UpTo::Other(UpToOther::Inf)),
+                },
+            ]),
+            ..Default::default()
+        },
+    );
+    let mut params = CreatePrice::new(Currency::USD);
+    params.currency_options = Some(options);
+    params.unit_amount = Some(1000);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "currency=usd\
+         &currency_options[eur][tax_behavior]=inclusive\
+         &currency_options[eur][tiers][0][unit_amount]=900&currency_options[eur][tiers][0][up_to]=10\
+         &currency_options[eur][tiers][1][unit_amount]=800&currency_options[eur][tiers][1][up_to]=inf\
+         &unit_amount=1000"
+    );
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn deserialize_price_currency_options() {
+    use stripe::{Currency, Price, PriceTaxBehavior};
+
+    let example = json!({
+        "id": "price_1MoBy5LkdIwHu7ixZhnattbh",
+        "object": "price",
+        "active": true,
+        "billing_scheme": "per_unit",
+        "created": 1679431181,
+        "currency": "usd",
+        "currency_options": {
+            "eur": {
+                "custom_unit_amount": null,
+                "tax_behavior": "inclusive",
+                "unit_amount": 900,
+                "unit_amount_decimal": "900"
+            },
+            "usd": {
+                "custom_unit_amount": null,
+                "tax_behavior": "exclusive",
+                "unit_amount": 1000,
+                "unit_amount_decimal": "1000"
+            }
+        },
+        "livemode": false,
+        "metadata": {},
+        "product": "prod_NZKdYqrwEYx6iK",
+        "recurring": {
+            "aggregate_usage": null,
+            "interval": "month",
+            "interval_count": 1,
+            "trial_period_days": null,
+            "usage_type": "licensed"
+        },
+        "tax_behavior": "exclusive",
+        "type": "recurring",
+        "unit_amount": 1000,
+        "unit_amount_decimal": "1000"
+    });
+    let price = serde_json::from_value::<Price>(example).unwrap();
+    let options = price.currency_options.unwrap();
+    assert_eq!(options.len(), 2);
+    assert_eq!(options[&Currency::EUR].unit_amount, Some(900));
+    assert_eq!(options[&Currency::EUR].tax_behavior, Some(PriceTaxBehavior::Inclusive));
+    assert_eq!(price.tax_behavior, Some(PriceTaxBehavior::Exclusive));
+}
+
+#[test]
+fn serialize_product_default_price_data() {
+    use stripe::{
+        CreateProduct, CreateProductDefaultPriceData, CreateProductDefaultPriceDataRecurring,
+        CreateProductDefaultPriceDataRecurringInterval, Currency, PriceTaxBehavior, ProductFeature,
+    };
+
+    let mut params = CreateProduct::new("Gold plan");
+    params.default_price_data = Some(CreateProductDefaultPriceData {
+        currency: Currency::USD,
+        recurring: Some(CreateProductDefaultPriceDataRecurring {
+            interval: CreateProductDefaultPriceDataRecurringInterval::Month,
+            interval_count: None,
+        }),
+        tax_behavior: Some(PriceTaxBehavior::Exclusive),
+        unit_amount: Some(2000),
+        unit_amount_decimal: None,
+    });
+    params.features = Some(vec![ProductFeature { name: "Unlimited seats".to_string() }]);
+    params.tax_code = Some("txcd_10000000".parse().unwrap());
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "default_price_data[currency]=usd&default_price_data[recurring][interval]=month\
+         &default_price_data[tax_behavior]=exclusive&default_price_data[unit_amount]=2000\
+         &features[0][name]=Unlimited+seats\
+         &name=Gold+plan&tax_code=txcd_10000000"
+    );
+}
+
+#[test]
+fn deserialize_product_default_price() {
+    use stripe::{Expandable, Product};
+
+    let example = json!({
+        "id": "prod_NWjs8kKbJWmuuc",
+        "object": "product",
+        "active": true,
+        "created": 1678833149,
+        "default_price": "price_1MoBy5LkdIwHu7ixZhnattbh",
+        "description": null,
+        "features": [{ "name": "Unlimited seats" }],
+        "images": [],
+        "livemode": false,
+        "metadata": {},
+        "name": "Gold Plan",
+        "package_dimensions": null,
+        "shippable": null,
+        "statement_descriptor": null,
+        "tax_code": "txcd_10000000",
+        "unit_label": null,
+        "updated": 1678833149,
+        "url": null
+    });
+    let product = serde_json::from_value::<Product>(example).unwrap();
+    match product.default_price {
+        Some(Expandable::Id(id)) => assert_eq!(id, "price_1MoBy5LkdIwHu7ixZhnattbh"),
+        other => panic!("expected a price id; was {:?}", other),
+    }
+    assert_eq!(product.features[0].name, "Unlimited seats");
+    assert_eq!(product.tax_code.unwrap().id(), "txcd_10000000");
+}
+
+#[test]
+fn serialize_verify_microdeposits() {
+    use stripe::VerifyMicrodeposits;
+
+    let params = VerifyMicrodeposits { amounts: Some([32, 45]), ..Default::default() };
+    assert_eq!(urldecode(serde_qs::to_string(&params).unwrap()), "amounts[0]=32&amounts[1]=45");
+
+    let params = VerifyMicrodeposits { descriptor_code: Some("SM11AA"), ..Default::default() };
+    assert_eq!(urldecode(serde_qs::to_string(&params).unwrap()), "descriptor_code=SM11AA");
+}
+
+#[test]
+fn deserialize_failed_payout() {
+    use stripe::{Expandable, Payout, PayoutFailureCode, PayoutStatus};
+
+    let mut example = json!({
+        "id": "po_1MoBy5LkdIwHu7ixVbVd1Qd2",
+        "object": "payout",
+        "amount": 1100,
+        "arrival_date": 1680652800,
+        "automatic": false,
+        "balance_transaction": "txn_1MoBy5LkdIwHu7ixBUbXUPab",
+        "created": 1680648691,
+        "currency": "usd",
+        "description": null,
+        "destination": "ba_1MoBy5LkdIwHu7ixExU0yGjY",
+        "failure_balance_transaction": "txn_1MoBy6LkdIwHu7ixr2bXk4ul",
+        "failure_code": "account_closed",
+        "failure_message": "The bank account has been closed.",
+        "livemode": false,
+        "metadata": {},
+        "method": "standard",
+        "source_type": "card",
+        "statement_descriptor": null,
+        "status": "failed",
+        "type": "bank_account"
+    });
+    let payout = serde_json::from_value::<Payout>(example.clone()).unwrap();
+    assert_eq!(payout.status, PayoutStatus::Failed);
+    assert_eq!(payout.failure_code, Some(PayoutFailureCode::AccountClosed));
+    match payout.failure_balance_transaction {
+        Some(Expandable::Id(id)) => assert_eq!(id, "txn_1MoBy6LkdIwHu7ixr2bXk4ul"),
+        other => panic!("expected a balance transaction id; was {:?}", other),
+    }
+
+    example["failure_code"] = json!("some_new_code");
+    let payout = serde_json::from_value::<Payout>(example).unwrap();
+    let code = payout.failure_code.unwrap();
+    assert_eq!(code, PayoutFailureCode::Other("some_new_code".to_string()));
+    assert_eq!(serde_json::to_value(&code).unwrap(), json!("some_new_code"));
+}
+
+#[test]
+fn serialize_api_version() {
+    use stripe::ApiVersion;
+
+    assert_eq!(ApiVersion::V2020_08_27.as_str(), "2020-08-27");
+    assert_eq!(serde_json::to_value(ApiVersion::V2024_06_20).unwrap(), json!("2024-06-20"));
+    let version = serde_json::from_value::<ApiVersion>(json!("2022-11-15")).unwrap();
+    assert_eq!(version, ApiVersion::V2022_11_15);
+
+    // Named releases carry their suffix on the wire.
+    assert_eq!(ApiVersion::V2024_09_30Acacia.as_str(), "2024-09-30.acacia");
+    assert_eq!(ApiVersion::V2025_03_31Basil.to_string(), "2025-03-31.basil");
+    let version = serde_json::from_value::<ApiVersion>(json!("2026-01-28.clover")).unwrap();
+    assert_eq!(version, ApiVersion::V2026_01_28Clover);
+    assert_eq!(serde_json::to_value(version).unwrap(), json!("2026-01-28.clover"));
+}
+
+#[test]
+fn serialize_create_refund() {
+    use stripe::{CreateRefund, RefundReason};
+
+    let mut params = CreateRefund::new();
+    params.charge = Some("ch_123".parse().unwrap());
+    params.reason = Some(RefundReason::RequestedByCustomer);
+    params.refund_application_fee = Some(true);
+    params.reverse_transfer = Some(true);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "charge=ch_123&reason=requested_by_customer&refund_application_fee=true&reverse_transfer=true"
+    );
+}
+
+#[test]
+fn deserialize_failed_refund() {
+    use stripe::{Expandable, Refund, RefundFailureReason, RefundStatus};
+
+    let mut example = json!({
+        "id": "re_1Nispe2eZvKYlo2Cd31jOCgZ",
+        "object": "refund",
+        "amount": 1000,
+        "balance_transaction": "txn_1Nispe2eZvKYlo2CYezqFhEx",
+        "charge": "ch_1NirD82eZvKYlo2CIvbtLWuY",
+        "created": 1692942318,
+        "currency": "usd",
+        "failure_balance_transaction": "txn_1Nispf2eZvKYlo2CNtQH1fZC",
+        "failure_reason": "expired_or_canceled_card",
+        "instructions_email": "jenny@example.com",
+        "metadata": {},
+        "next_action": {
+            "type": "display_details",
+            "display_details": {
+                "email_sent": {
+                    "email_sent_at": 1692942318,
+                    "email_sent_to": "jenny@example.com"
+                },
+                "expires_at": 1693547118
+            }
+        },
+        "payment_intent": "pi_1GszsK2eZvKYlo2CfhZyoZLp",
+        "reason": null,
+        "receipt_number": null,
+        "source_transfer_reversal": null,
+        "status": "failed",
+        "transfer_reversal": null
+    });
+    let refund = serde_json::from_value::<Refund>(example.clone()).unwrap();
+    assert_eq!(refund.status, Some(RefundStatus::Failed));
+    assert_eq!(refund.failure_reason, Some(RefundFailureReason::ExpiredOrCanceledCard));
+    match refund.failure_balance_transaction {
+        Some(Expandable::Id(id)) => assert_eq!(id, "txn_1Nispf2eZvKYlo2CNtQH1fZC"),
+        other => panic!("expected a balance transaction id; was {:?}", other),
+    }
+    assert_eq!(refund.instructions_email.as_deref(), Some("jenny@example.com"));
+    let next_action = refund.next_action.unwrap();
+    assert_eq!(next_action.type_, "display_details");
+    let details = next_action.display_details.unwrap();
+    assert_eq!(details.email_sent.email_sent_to, "jenny@example.com");
+    assert_eq!(details.expires_at, 1693547118);
+
+    example["status"] = json!("some_new_status");
+    example["failure_reason"] = json!("some_new_reason");
+    let refund = serde_json::from_value::<Refund>(example).unwrap();
+    assert_eq!(refund.status, Some(RefundStatus::Other("some_new_status".to_string())));
+    let reason = refund.failure_reason.unwrap();
+    assert_eq!(reason, RefundFailureReason::Other("some_new_reason".to_string()));
+    assert_eq!(serde_json::to_value(&reason).unwrap(), json!("some_new_reason"));
+}
+
+#[test]
+fn deserialize_balance_transaction() {
+    use stripe::{BalanceTransaction, BalanceTransactionReportingCategory, BalanceTransactionType};
+
+    let charge = json!({
+        "id": "txn_1MiN3gLkdIwHu7ixxapQrznl",
+        "object": "balance_transaction",
+        "amount": 2000,
+        "available_on": 1678492800,
+        "created": 1678229088,
+        "currency": "usd",
+        "description": "Charge for order #1234",
+        "exchange_rate": null,
+        "fee": 88,
+        "fee_details": [
+            {
+                "amount": 30,
+                "application": "ca_123",
+                "currency": "usd",
+                "description": "Application fee",
+                "type": "application_fee"
+            },
+            {
+                "amount": 58,
+                "application": null,
+                "currency": "usd",
+                "description": "Stripe processing fees",
+                "type": "stripe_fee"
+            }
+        ],
+        "net": 1912,
+        "reporting_category": "charge",
+        "source": "ch_3MiN3gLkdIwHu7ix0snN0B15",
+        "status": "pending",
+        "type": "charge"
+    });
+    let refund = json!({
+        "id": "txn_1MiN3gLkdIwHu7ixy8ZDzn7d",
+        "object": "balance_transaction",
+        "amount": -500,
+        "available_on": 1678492800,
+        "created": 1678229088,
+        "currency": "usd",
+        "description": null,
+        "exchange_rate": null,
+        "fee": 0,
+        "fee_details": [],
+        "net": -500,
+        "reporting_category": "brand_new_category",
+        "source": "re_3MiN3gLkdIwHu7ix0fS1Vn6x",
+        "status": "available",
+        "type": "brand_new_type"
+    });
+
+    let charge = serde_json::from_value::<BalanceTransaction>(charge).unwrap();
+    assert_eq!(charge.reporting_category, BalanceTransactionReportingCategory::Charge);
+    assert_eq!(charge.type_, BalanceTransactionType::Charge);
+    assert_eq!(charge.fee_details[0].application.as_deref(), Some("ca_123"));
+
+    let refund = serde_json::from_value::<BalanceTransaction>(refund).unwrap();
+    assert_eq!(
+        refund.reporting_category,
+        BalanceTransactionReportingCategory::Other("brand_new_category".to_string())
+    );
+    assert_eq!(refund.type_.as_str(), "brand_new_type");
+    assert_eq!(serde_json::to_value(&refund.type_).unwrap(), json!("brand_new_type"));
+
+    for txn in &[charge, refund] {
+        assert_eq!(txn.net, txn.amount - txn.fee);
+        assert_eq!(txn.fee, txn.fee_details.iter().map(|fee| fee.amount).sum::<i64>());
+    }
+}
+
+#[test]
+fn serialize_dispute_evidence_from_dispute() {
+    use stripe::{Dispute, UpdateDispute};
+
+    let example = json!({
+        "id": "dp_1MtJUT2eZvKYlo2CNaw2HvEv",
+        "object": "dispute",
+        "amount": 1000,
+        "balance_transactions": [],
+        "charge": "ch_1AZtxr2eZvKYlo2CJDX8whov",
+        "created": 1680651737,
+        "currency": "usd",
+        "evidence": {
+            "access_activity_log": null,
+            "billing_address": null,
+            "cancellation_policy": null,
+            "customer_communication": "file_1MtJUT2eZvKYlo2CDkHnSPa4",
+            "customer_email_address": "jenny@example.com",
+            "duplicate_charge_id": null,
+            "receipt": null,
+            "service_date": null,
+            "shipping_tracking_number": "1Z999AA10123456784",
+            "uncategorized_text": "Customer used the product for three months."
+        },
+        "evidence_details": {
+            "due_by": 1681430399,
+            "has_evidence": true,
+            "past_due": false,
+            "submission_count": 0
+        },
+        "is_charge_refundable": true,
+        "livemode": false,
+        "metadata": {},
+        "payment_intent": null,
+        "reason": "general",
+        "status": "needs_response"
+    });
+    let dispute = serde_json::from_value::<Dispute>(example).unwrap();
+    assert_eq!(dispute.evidence_details.due_by, Some(1681430399));
+    assert!(dispute.evidence_details.has_evidence);
+    assert_eq!(dispute.evidence_details.submission_count, 0);
+
+    let mut params = UpdateDispute::new();
+    params.evidence = Some(dispute.evidence);
+    params.submit = Some(false);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "evidence[customer_communication]=file_1MtJUT2eZvKYlo2CDkHnSPa4\
+         &evidence[customer_email_address]=jenny%40example.com\
+         &evidence[shipping_tracking_number]=1Z999AA10123456784\
+         &evidence[uncategorized_text]=Customer+used+the+product+for+three+months.\
+         &submit=false"
+    );
+}
+
+#[test]
+fn serialize_create_us_bank_account_payment_method() {
+    use stripe::{
+        AccountHolderType, CreatePaymentMethod, CreatePaymentMethodUsBankAccount,
+        PaymentMethodType, PaymentMethodUsBankAccountAccountType,
+    };
+
+    let mut params = CreatePaymentMethod::new();
+    params.type_ = Some(PaymentMethodType::UsBankAccount);
+    params.us_bank_account = Some(CreatePaymentMethodUsBankAccount {
+        account_holder_type: Some(AccountHolderType::Individual),
+        account_number: Some("000123456789".to_string()),
+        account_type: Some(PaymentMethodUsBankAccountAccountType::Checking),
+        routing_number: Some("110000000".to_string()),
+        ..Default::default()
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "type=us_bank_account\
+         &us_bank_account[account_holder_type]=individual\
+         &us_bank_account[account_number]=000123456789\
+         &us_bank_account[account_type]=checking\
+         &us_bank_account[routing_number]=110000000"
+    );
+}
+
+#[test]
+fn deserialize_us_bank_account_payment_method() {
+    use stripe::{PaymentMethod, PaymentMethodType, PaymentMethodUsBankAccountAccountType};
+
+    let example = json!({
+        "id": "pm_1Mxt2E2eZvKYlo2CmNBmDRgJ",
+        "object": "payment_method",
+        "billing_details": {
+            "address": null,
+            "email": null,
+            "name": "Jenny Rosen",
+            "phone": null
+        },
+        "created": 1681750214,
+        "customer": null,
+        "livemode": false,
+        "metadata": {},
+        "type": "us_bank_account",
+        "us_bank_account": {
+            "account_holder_type": "individual",
+            "account_type": "checking",
+            "bank_name": "STRIPE TEST BANK",
+            "financial_connections_account": null,
+            "fingerprint": "LstWJFsCK7P349Bg",
+            "last4": "6789",
+            "routing_number": "110000000"
+        }
+    });
+    let payment_method = serde_json::from_value::<PaymentMethod>(example).unwrap();
+    assert_eq!(payment_method.type_, PaymentMethodType::UsBankAccount);
+    let account = payment_method.us_bank_account.unwrap();
+    assert_eq!(account.account_type, Some(PaymentMethodUsBankAccountAccountType::Checking));
+    assert_eq!(account.last4.as_deref(), Some("6789"));
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_create_usage_record() {
+    use stripe::{CreateUsageRecord, UsageRecordAction};
+
+    let params = CreateUsageRecord::new(10, 1571252444);
+    assert_eq!(serde_qs::to_string(&params).unwrap(), "quantity=10&timestamp=1571252444");
+
+    let mut params = CreateUsageRecord::now(10);
+    params.action = Some(UsageRecordAction::Set);
+    assert_eq!(serde_qs::to_string(&params).unwrap(), "quantity=10&timestamp=now&action=set");
+}
+
+#[cfg(feature = "connect")]
+#[test]
+fn serialize_update_account() {
+    use std::collections::HashMap;
+    use stripe::{
+        AcceptTos, AccountSettingsParams, BusinessProfile, CapabilityRequest,
+        CardIssuingSettingsParams, RequestedCapability, UpdateAccount,
+    };
+
+    let mut capabilities = HashMap::new();
+    capabilities
+        .insert(RequestedCapability::CardPayments, CapabilityRequest { requested: Some(true) });
+
+    let mut params = UpdateAccount::new();
+    params.business_profile = Some(BusinessProfile {
+        mcc: Some("5734".to_string()),
+        name: None,
+        product_description: None,
+        support_address: None,
+        support_email: None,
+        support_phone: None,
+        support_url: None,
+        url: Some("https://example.com".to_string()),
+    });
+    params.capabilities = Some(capabilities);
+    params.settings = Some(AccountSettingsParams {
+        card_issuing: Some(CardIssuingSettingsParams {
+            tos_acceptance: Some(AcceptTos { date: Some(1_600_000_000), ..Default::default() }),
+        }),
+        ..Default::default()
+    });
+    params.tos_acceptance = Some(AcceptTos {
+        date: Some(1_600_000_000),
+        ip: Some("127.0.0.1".to_string()),
+        service_agreement: Some("recipient".to_string()),
+        user_agent: None,
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "business_profile[mcc]=5734&business_profile[url]=https%3A%2F%2Fexample.com\
+         &capabilities[card_payments][requested]=true\
+         &settings[card_issuing][tos_acceptance][date]=1600000000\
+         &tos_acceptance[date]=1600000000&tos_acceptance[ip]=127.0.0.1\
+         &tos_acceptance[service_agreement]=recipient"
+    );
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_nested_metadata() {
+    use stripe::{CreateSubscription, CreateSubscriptionItems, Metadata};
+
+    let keys = [
+        ("order_id", "order_id"),
+        ("with space", "with+space"),
+        ("ünïcödé", "%C3%BCn%C3%AFc%C3%B6d%C3%A9"),
+        ("a[b]", "a%5Bb%5D"),
+        ("x&y=z", "x%26y%3Dz"),
+    ];
+    for (key, encoded_key) in keys.iter() {
+        let mut metadata = Metadata::new();
+        metadata.insert(key.to_string(), "1".to_string());
+
+        let mut params = CreateSubscription::new("cus_123".parse().unwrap());
+        params.items = Some(vec![CreateSubscriptionItems {
+            billing_thresholds: None,
+            metadata: metadata.clone(),
+            plan: None,
+            price: Some("price_1".to_string()),
+            price_data: None,
+            quantity: None,
+            tax_rates: None,
+        }]);
+        params.metadata = Some(metadata);
+        assert_eq!(
+            serde_qs::to_string(&params).unwrap(),
+            format!(
+                "customer=cus_123&items[0][metadata][{0}]=1&items[0][price]=price_1&metadata[{0}]=1",
+                encoded_key
+            )
+        );
+    }
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_booleans() {
+    use stripe::{
+        CreateInvoice, CreateInvoiceAutomaticTax, Metadata, UpdateSubscription,
+        UpdateSubscriptionItems,
+    };
+
+    let mut params = CreateInvoice::new("cus_123".parse().unwrap());
+    params.auto_advance = Some(false);
+    params.automatic_tax = Some(CreateInvoiceAutomaticTax { enabled: true });
+    assert_eq!(
+        serde_qs::to_string(&params).unwrap(),
+        "auto_advance=false&automatic_tax[enabled]=true&customer=cus_123"
+    );
+
+    let mut params = UpdateSubscription::new();
+    params.cancel_at_period_end = Some(true);
+    params.items = Some(vec![UpdateSubscriptionItems {
+        billing_thresholds: None,
+        clear_usage: Some(false),
+        deleted: Some(true),
+        id: Some("si_123".to_string()),
+        metadata: Metadata::new(),
+        plan: None,
+        price: None,
+        price_data: None,
+        quantity: None,
+        tax_rates: None,
+    }]);
+    assert_eq!(
+        serde_qs::to_string(&params).unwrap(),
+        "cancel_at_period_end=true\
+         &items[0][clear_usage]=false&items[0][deleted]=true&items[0][id]=si_123"
+    );
+}
+
+#[test]
+fn serialize_untagged_boolean() {
+    use stripe::{CreatePaymentIntent, Currency, PaymentIntentOffSession};
+
+    let mut params = CreatePaymentIntent::new(1000, Currency::USD);
+    params.confirm = Some(true);
+    params.off_session = Some(PaymentIntentOffSession::exists(false));
+    assert_eq!(
+        serde_qs::to_string(&params).unwrap(),
+        "amount=1000&currency=usd&confirm=true&off_session=false"
+    );
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_list_subscriptions_current_period_end() {
+    use stripe::{ListSubscriptions, RangeBounds, RangeQuery, SubscriptionStatusFilter};
+
+    let now = 1_600_000_000;
+    let mut params = ListSubscriptions::new();
+    params.current_period_end = Some(RangeQuery::Bounds(RangeBounds {
+        gte: Some(now),
+        lte: Some(now + 7 * 24 * 60 * 60),
+        ..Default::default()
+    }));
+    params.status = Some(SubscriptionStatusFilter::Active);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "current_period_end[gte]=1600000000&current_period_end[lte]=1600604800&status=active"
+    );
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn deserialize_proration_line_item() {
+    use stripe::{InvoiceLineItem, InvoiceLineItemType};
+
+    let line = json!({
+        "id": "ii_1MiN3gLkdIwHu7ixBn4cHSoW",
+        "object": "line_item",
+        "amount": -1250,
+        "currency": "usd",
+        "description": "Unused time on Pro after 08 Mar 2023",
+        "discountable": false,
+        "invoice_item": "ii_1MiN3gLkdIwHu7ixBn4cHSoW",
+        "livemode": false,
+        "metadata": {},
+        "period": { "end": 1680307200, "start": 1678229088 },
+        "plan": null,
+        "price": null,
+        "proration": true,
+        "quantity": 1,
+        "subscription": "sub_1MiN3gLkdIwHu7ixG3wT8a1n",
+        "subscription_item": "si_NTKjdHWmM0wTtM",
+        "tax_amounts": [{ "amount": -103, "inclusive": false, "tax_rate": "txr_1MiN3gLkdIwHu7ix" }],
+        "tax_rates": [],
+        "type": "invoiceitem"
+    });
+    let line: InvoiceLineItem = serde_json::from_value(line).unwrap();
+    assert!(line.proration);
+    assert_eq!(line.amount, -1250);
+    assert_eq!(line.type_, InvoiceLineItemType::InvoiceItem);
+    assert_eq!(line.tax_amounts.unwrap()[0].amount, -103);
+}
+
+#[cfg(feature = "events")]
+#[test]
+fn deserialize_connect_event() {
+    use stripe::{Event, EventObject, EventType, PayoutStatus};
+
+    let example = json!({
+        "id": "evt_1MoC0YQ8h3bu2y7jLLf0nL2M",
+        "object": "event",
+        "account": "acct_1MoBxTQ8h3bu2y7j",
+        "api_version": "2022-11-15",
+        "created": 1680648842,
+        "data": {
+            "object": {
+                "id": "po_1MoBy5Q8h3bu2y7jVbVd1Qd2",
+                "object": "payout",
+                "amount": 1100,
+                "arrival_date": 1680652800,
+                "automatic": true,
+                "balance_transaction": "txn_1MoBy5Q8h3bu2y7jBUbXUPab",
+                "created": 1680648691,
+                "currency": "usd",
+                "description": "STRIPE PAYOUT",
+                "destination": "ba_1MoBy5Q8h3bu2y7jExU0yGjY",
+                "failure_balance_transaction": null,
+                "failure_code": null,
+                "failure_message": null,
+                "livemode": false,
+                "metadata": {},
+                "method": "standard",
+                "source_type": "card",
+                "statement_descriptor": null,
+                "status": "paid",
+                "type": "bank_account"
+            }
+        },
+        "livemode": false,
+        "pending_webhooks": 2,
+        "request": { "id": null, "idempotency_key": null },
+        "type": "payout.paid"
+    });
+    let event = serde_json::from_value::<Event>(example).unwrap();
+    assert_eq!(event.event_type, EventType::PayoutPaid);
+    assert_eq!(event.account.unwrap().as_str(), "acct_1MoBxTQ8h3bu2y7j");
+    assert_eq!(event.api_version.as_deref(), Some("2022-11-15"));
+    assert_eq!(event.pending_webhooks, 2);
+    let request = event.request.unwrap();
+    assert_eq!(request.id, None);
+    assert_eq!(request.idempotency_key, None);
+    match event.data.object {
+        EventObject::Payout(payout) => assert_eq!(payout.status, PayoutStatus::Paid),
+        other => panic!("expected a payout; was {:?}", other),
+    }
+}
+
+#[cfg(feature = "events")]
+#[test]
+fn deserialize_event_with_unknown_object() {
+    use stripe::{Event, EventObject};
+
+    let unknown = json!({
+        "id": "evt_123",
+        "object": "event",
+        "created": 1680648842,
+        "data": {
+            "object": { "id": "bnb_123", "object": "brand_new_beta", "amount": 100 }
+        },
+        "livemode": false,
+        "type": "account.updated"
+    });
+    let mut known = unknown.clone();
+    known["data"]["object"] = json!({
+        "id": "prod_123",
+        "object": "product",
+        "active": true,
+        "created": 1680648842,
+        "livemode": false,
+        "metadata": {},
+        "name": "T-shirt",
+        "updated": 1680648842
+    });
+
+    // An unknown object type doesn't poison a page of events.
+    let events: Vec<Event> = serde_json::from_value(json!([unknown, known])).unwrap();
+    match &events[0].data.object {
+        EventObject::Unknown { object, data } => {
+            assert_eq!(object, "brand_new_beta");
+            assert_eq!(data["amount"], 100);
+        }
+        other => panic!("expected an unknown object; was {:?}", other),
+    }
+    match &events[1].data.object {
+        EventObject::Product(product) => assert_eq!(product.name.as_deref(), Some("T-shirt")),
+        other => panic!("expected a product; was {:?}", other),
+    }
+
+    // The raw payload is written back out as it was received.
+    assert_eq!(serde_json::to_value(&events[0]).unwrap()["data"], unknown["data"]);
+
+    // A known object type which fails to deserialize is still an error.
+    let mut broken = known;
+    broken["data"]["object"]["active"] = json!("yes");
+    assert!(serde_json::from_value::<Event>(broken).is_err());
+}
+
+#[cfg(feature = "issuing")]
+#[test]
+fn deserialize_issuing_physical_bundle() {
+    use stripe::{
+        IssuingPhysicalBundle, IssuingPhysicalBundleFeatureAvailability,
+        IssuingPhysicalBundleStatus, IssuingPhysicalBundleType, ListIssuingPhysicalBundles,
+    };
+
+    let example = json!({
+        "id": "ics_NfeGLO9jrVmkUT",
+        "object": "issuing.physical_bundle",
+        "features": {
+            "card_logo": "optional",
+            "carrier_text": "optional",
+            "second_line": "unsupported"
+        },
+        "livemode": false,
+        "name": "Black",
+        "status": "active",
+        "type": "custom"
+    });
+    let bundle = serde_json::from_value::<IssuingPhysicalBundle>(example).unwrap();
+    assert_eq!(bundle.id.as_str(), "ics_NfeGLO9jrVmkUT");
+    assert_eq!(bundle.features.card_logo, IssuingPhysicalBundleFeatureAvailability::Optional);
+    assert_eq!(bundle.features.second_line, IssuingPhysicalBundleFeatureAvailability::Unsupported);
+    assert_eq!(bundle.status, IssuingPhysicalBundleStatus::Active);
+    assert_eq!(bundle.type_, IssuingPhysicalBundleType::Custom);
+
+    let mut params = ListIssuingPhysicalBundles::new();
+    params.status = Some(IssuingPhysicalBundleStatus::Active);
+    params.type_ = Some(IssuingPhysicalBundleType::Standard);
+    assert_eq!(serde_qs::to_string(&params).unwrap(), "status=active&type=standard");
+}
+
+#[test]
+fn deserialize_customer_with_legacy_sources() {
+    use stripe::{Customer, Object, PaymentSource, PaymentSourceId, SourceType};
+
+    let example = json!({
+        "id": "cus_9s6XKzkNRiz8i3",
+        "object": "customer",
+        "created": 1483565364,
+        "default_source": "btcrcv_19Y9NLGjNBy5qT5CwHQbvfF7",
+        "livemode": false,
+        "metadata": {},
+        "sources": {
+            "object": "list",
+            "data": [
+                {
+                    "id": "aliacc_19Y9NLGjNBy5qT5CldlgjXsK",
+                    "object": "alipay_account",
+                    "created": 1483565364,
+                    "customer": "cus_9s6XKzkNRiz8i3",
+                    "fingerprint": "Fk1u6GjnLHSnlQ9x",
+                    "livemode": false,
+                    "metadata": {},
+                    "payment_amount": 1000,
+                    "payment_currency": "usd",
+                    "reusable": false,
+                    "used": false,
+                    "username": "test@example.com"
+                },
+                {
+                    "id": "btcrcv_19Y9NLGjNBy5qT5CwHQbvfF7",
+                    "object": "bitcoin_receiver",
+                    "active": true,
+                    "amount": 1000,
+                    "amount_received": 1000,
+                    "bitcoin_amount": 1757908,
+                    "bitcoin_amount_received": 1757908,
+                    "bitcoin_uri": "bitcoin:test_7i9Fo4b5wXcUAuoVBFrc7nc9HDxD1?amount=0.01757908",
+                    "created": 1483565364,
+                    "currency": "usd",
+                    "customer": "cus_9s6XKzkNRiz8i3",
+                    "description": "Receiver for John Doe",
+                    "email": "test@example.com",
+                    "filled": true,
+                    "inbound_address": "test_7i9Fo4b5wXcUAuoVBFrc7nc9HDxD1",
+                    "livemode": false,
+                    "metadata": {},
+                    "payment": "py_19Y9NLGjNBy5qT5C8sOkoJSd",
+                    "refund_address": null,
+                    "uncaptured_funds": false,
+                    "used_for_payment": true
+                },
+                {
+                    "id": "src_19Y9NLGjNBy5qT5CZ1gRGxYh",
+                    "object": "source",
+                    "ach_credit_transfer": {
+                        "account_number": "test_52796e3294dc",
+                        "bank_name": "TEST BANK",
+                        "fingerprint": "ecpwEzmBOSMOqQTL",
+                        "routing_number": "110000000",
+                        "swift_code": "TSTEZ122"
+                    },
+                    "client_secret": "src_client_secret_9s6XKzkNRiz8i3",
+                    "created": 1483565364,
+                    "currency": "usd",
+                    "flow": "receiver",
+                    "livemode": false,
+                    "metadata": {},
+                    "status": "pending",
+                    "type": "ach_credit_transfer",
+                    "usage": "reusable"
+                },
+                {
+                    "id": "gbr_19Y9NLGjNBy5qT5CqYd6ZrBL",
+                    "object": "giropay_receiver",
+                    "amount": 1000,
+                    "livemode": false
+                }
+            ],
+            "has_more": false,
+            "total_count": 4,
+            "url": "/v1/customers/cus_9s6XKzkNRiz8i3/sources"
+        }
+    });
+    let customer = serde_json::from_value::<Customer>(example).unwrap();
+    let sources = &customer.sources.data;
+    assert_eq!(sources.len(), 4);
+    assert!(matches!(sources[0], PaymentSource::AlipayAccount(_)));
+    match &sources[1] {
+        PaymentSource::BitcoinReceiver(receiver) => {
+            assert_eq!(receiver.id.as_str(), "btcrcv_19Y9NLGjNBy5qT5CwHQbvfF7");
+            assert_eq!(receiver.bitcoin_amount, 1757908);
+            assert!(receiver.filled);
+        }
+        other => panic!("expected a bitcoin receiver, got {:?}", other),
+    }
+    match &sources[2] {
+        PaymentSource::Source(source) => {
+            assert_eq!(source.type_, SourceType::AchCreditTransfer);
+            let transfer = source.ach_credit_transfer.as_ref().unwrap();
+            assert_eq!(transfer.routing_number.as_deref(), Some("110000000"));
+        }
+        other => panic!("expected a source, got {:?}", other),
+    }
+    match &sources[3] {
+        PaymentSource::Other { object, data } => {
+            assert_eq!(object, "giropay_receiver");
+            assert_eq!(data["amount"], 1000);
+        }
+        other => panic!("expected an unknown source, got {:?}", other),
+    }
+    assert_eq!(sources[1].id().to_string(), "btcrcv_19Y9NLGjNBy5qT5CwHQbvfF7");
+    assert_eq!(sources[3].id(), PaymentSourceId::Other("gbr_19Y9NLGjNBy5qT5CqYd6ZrBL".into()));
+
+    // Unknown sources are written back out as they were received.
+    let customer = serde_json::to_value(&customer).unwrap();
+    assert_eq!(customer["sources"]["data"][3]["object"], "giropay_receiver");
+    assert_eq!(customer["sources"]["data"][2]["object"], "source");
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_list_promotion_codes() {
+    use stripe::{CouponId, ListPromotionCodes, PromotionCode};
+
+    let mut params = ListPromotionCodes::new();
+    params.code = Some("SUMMER20");
+    params.active = Some(true);
+    params.coupon = Some("SUMMER".parse::<CouponId>().unwrap());
+    assert_eq!(serde_qs::to_string(&params).unwrap(), "active=true&code=SUMMER20&coupon=SUMMER");
+
+    let example = json!({
+        "object": "list",
+        "data": [{
+            "id": "promo_1MiM6KLkdIwHu7ix8v0bvSyc",
+            "object": "promotion_code",
+            "active": true,
+            "code": "SUMMER20",
+            "coupon": {
+                "id": "SUMMER",
+                "object": "coupon",
+                "created": 1678040164,
+                "duration": "once",
+                "livemode": false,
+                "metadata": {},
+                "percent_off": 20.0,
+                "times_redeemed": 0,
+                "valid": true
+            },
+            "created": 1678040164,
+            "customer": null,
+            "expires_at": null,
+            "livemode": false,
+            "max_redemptions": null,
+            "metadata": {},
+            "restrictions": {
+                "first_time_transaction": false,
+                "minimum_amount": null,
+                "minimum_amount_currency": null
+            },
+            "times_redeemed": 0
+        }],
+        "has_more": false,
+        "url": "/v1/promotion_codes"
+    });
+    let list = serde_json::from_value::<stripe::List<PromotionCode>>(example).unwrap();
+    assert!(!list.has_more);
+    assert_eq!(list.data[0].code, "SUMMER20");
+    assert_eq!(list.data[0].coupon.id.as_str(), "SUMMER");
+    assert!(!list.data[0].restrictions.first_time_transaction);
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn deserialize_stacked_line_item_discounts() {
+    use stripe::{CreateInvoiceItem, CreateInvoiceItemDiscounts, CustomerId, InvoiceLineItem};
+
+    let line = json!({
+        "id": "ii_1MiN3gLkdIwHu7ixBn4cHSoW",
+        "object": "line_item",
+        "amount": 5000,
+        "currency": "usd",
+        "description": "Pro plan",
+        "discountable": true,
+        "discounts": [
+            "di_1MiN3hLkdIwHu7ixw2CRdZuJ",
+            {
+                "id": "di_1MiN3hLkdIwHu7ixqXbXvA4x",
+                "object": "discount",
+                "coupon": {
+                    "id": "SUMMER",
+                    "object": "coupon",
+                    "duration": "once",
+                    "percent_off": 20.0,
+                    "valid": true
+                },
+                "customer": "cus_9s6XKzkNRiz8i3",
+                "end": null,
+                "invoice": "in_1MiN3hLkdIwHu7ixZz9F1xR2",
+                "invoice_item": "ii_1MiN3gLkdIwHu7ixBn4cHSoW",
+                "promotion_code": "promo_1MiM6KLkdIwHu7ix8v0bvSyc",
+                "start": 1678229088,
+                "subscription": null
+            }
+        ],
+        "invoice_item": "ii_1MiN3gLkdIwHu7ixBn4cHSoW",
+        "livemode": false,
+        "metadata": {},
+        "period": { "end": 1680307200, "start": 1678229088 },
+        "proration": false,
+        "quantity": 1,
+        "type": "invoiceitem"
+    });
+    let line: InvoiceLineItem = serde_json::from_value(line).unwrap();
+    let discounts = line.discounts.unwrap();
+    assert_eq!(discounts.len(), 2);
+    assert_eq!(discounts[0].id().as_str(), "di_1MiN3hLkdIwHu7ixw2CRdZuJ");
+    let discount = discounts[1].as_object().unwrap();
+    assert_eq!(discount.coupon.id.as_str(), "SUMMER");
+    assert_eq!(
+        discount.promotion_code.as_ref().unwrap().id().as_str(),
+        "promo_1MiM6KLkdIwHu7ix8v0bvSyc"
+    );
+
+    let mut params = CreateInvoiceItem::new("cus_9s6XKzkNRiz8i3".parse::<CustomerId>().unwrap());
+    params.discounts = Some(vec![
+        CreateInvoiceItemDiscounts { coupon: Some("SUMMER".into()), ..Default::default() },
+        CreateInvoiceItemDiscounts {
+            promotion_code: Some("promo_1MiM6KLkdIwHu7ix8v0bvSyc".into()),
+            ..Default::default()
+        },
+    ]);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "customer=cus_9s6XKzkNRiz8i3&discounts[0][coupon]=SUMMER\
+         &discounts[1][promotion_code]=promo_1MiM6KLkdIwHu7ix8v0bvSyc"
+    );
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_billing_portal_session_flow_data() {
+    use stripe::{
+        BillingPortalSession, CreateBillingPortalSession, CustomerId, PortalFlowAfterCompletion,
+        PortalFlowAfterCompletionRedirect, PortalFlowAfterCompletionType, PortalFlowData,
+        PortalFlowSubscriptionCancel, PortalFlowType,
+    };
+
+    let mut flow = PortalFlowData::new(PortalFlowType::SubscriptionCancel);
+    flow.subscription_cancel =
+        Some(PortalFlowSubscriptionCancel { subscription: "sub_1MiN3gLkdIwHu7ixG3wT8a1n".into() });
+    flow.after_completion = Some(PortalFlowAfterCompletion {
+        hosted_confirmation: None,
+        redirect: Some(PortalFlowAfterCompletionRedirect {
+            return_url: "https://example.com/account".into(),
+        }),
+        type_: PortalFlowAfterCompletionType::Redirect,
+    });
+    let mut params =
+        CreateBillingPortalSession::new("cus_9s6XKzkNRiz8i3".parse::<CustomerId>().unwrap());
+    params.flow_data = Some(flow);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "customer=cus_9s6XKzkNRiz8i3\
+         &flow_data[after_completion][redirect][return_url]=https%3A%2F%2Fexample.com%2Faccount\
+         &flow_data[after_completion][type]=redirect\
+         &flow_data[subscription_cancel][subscription]=sub_1MiN3gLkdIwHu7ixG3wT8a1n\
+         &flow_data[type]=subscription_cancel"
+    );
+
+    let example = json!({
+        "id": "bps_1MiN3hLkdIwHu7ixIu0tSwv5",
+        "object": "billing_portal.session",
+        "configuration": "bpc_1MiN3hLkdIwHu7ixg4H2FfA0",
+        "created": 1678229089,
+        "customer": "cus_9s6XKzkNRiz8i3",
+        "flow": {
+            "after_completion": {
+                "hosted_confirmation": null,
+                "redirect": null,
+                "type": "portal_homepage"
+            },
+            "subscription_cancel": null,
+            "subscription_update": null,
+            "subscription_update_confirm": null,
+            "type": "payment_method_update"
+        },
+        "livemode": false,
+        "locale": null,
+        "on_behalf_of": null,
+        "return_url": "https://example.com/account",
+        "url": "https://billing.stripe.com/p/session/test_YWNjdF8xTWlN"
+    });
+    let session = serde_json::from_value::<BillingPortalSession>(example).unwrap();
+    assert_eq!(session.flow.unwrap().type_, PortalFlowType::PaymentMethodUpdate);
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_coupon_currency_options() {
+    use std::collections::HashMap;
+    use stripe::{Coupon, CouponDuration, CreateCoupon, CreateCouponCurrencyOptions, Currency};
+
+    let mut params = CreateCoupon::new(CouponDuration::Once);
+    params.amount_off = Some(500);
+    params.currency = Some(Currency::USD);
+    let mut options = HashMap::new();
+    options.insert(Currency::EUR, CreateCouponCurrencyOptions { amount_off: 450 });
+    params.currency_options = Some(options);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "amount_off=500&currency=usd&currency_options[eur][amount_off]=450&duration=once"
+    );
+
+    let example = json!({
+        "id": "EUROLAUNCH",
+        "object": "coupon",
+        "amount_off": 500,
+        "created": 1678040164,
+        "currency": "usd",
+        "currency_options": { "eur": { "amount_off": 450 } },
+        "duration": "once",
+        "livemode": false,
+        "metadata": {},
+        "valid": true
+    });
+    let coupon = serde_json::from_value::<Coupon>(example).unwrap();
+    assert_eq!(coupon.amount_off, Some(500));
+    assert_eq!(coupon.currency_options.unwrap()[&Currency::EUR].amount_off, 450);
+}
+
+#[cfg(feature = "reporting")]
+#[test]
+fn deserialize_report_types() {
+    use stripe::{List, ReportType};
+
+    let example = json!({
+        "object": "list",
+        "data": [{
+            "id": "balance.summary.1",
+            "object": "reporting.report_type",
+            "data_available_end": 1678233600,
+            "data_available_start": 1385856000,
+            "default_columns": ["category", "description", "net_amount", "currency"],
+            "livemode": false,
+            "name": "Balance summary",
+            "updated": 1678240000,
+            "version": 1
+        }],
+        "has_more": false,
+        "url": "/v1/reporting/report_types"
+    });
+    let list = serde_json::from_value::<List<ReportType>>(example).unwrap();
+    let report_type = &list.data[0];
+    assert_eq!(report_type.id.as_str(), "balance.summary.1");
+    assert_eq!(report_type.version, 1);
+    assert_eq!(report_type.default_columns.as_ref().unwrap().len(), 4);
+    assert!(report_type.namespace.is_none());
+}
+
+#[cfg(feature = "connect")]
+#[test]
+fn deserialize_external_accounts() {
+    use stripe::{Account, Expandable, ExternalAccount, List, Object, Payout};
+
+    let bank_account = json!({
+        "id": "ba_1MiN3hLkdIwHu7ixr5mBXj4H",
+        "object": "bank_account",
+        "account": "acct_1MiN3hLkdIwHu7ix",
+        "account_holder_name": "Jane Austen",
+        "account_holder_type": "individual",
+        "bank_name": "STRIPE TEST BANK",
+        "country": "US",
+        "currency": "usd",
+        "default_for_currency": true,
+        "fingerprint": "1JWtPxqbdX5Gamtz",
+        "last4": "6789",
+        "metadata": {},
+        "routing_number": "110000000",
+        "status": "new"
+    });
+    let card = json!({
+        "id": "card_1MiN3hLkdIwHu7ixkXhYbXKx",
+        "object": "card",
+        "account": "acct_1MiN3hLkdIwHu7ix",
+        "brand": "Visa",
+        "country": "US",
+        "currency": "usd",
+        "default_for_currency": false,
+        "exp_month": 8,
+        "exp_year": 2024,
+        "fingerprint": "Xt5EWLLDS7FJjR1c",
+        "funding": "debit",
+        "last4": "5556",
+        "metadata": {}
+    });
+    let example = json!({
+        "id": "acct_1MiN3hLkdIwHu7ix",
+        "object": "account",
+        "external_accounts": {
+            "object": "list",
+            "data": [bank_account, card.clone()],
+            "has_more": true,
+            "url": "/v1/accounts/acct_1MiN3hLkdIwHu7ix/external_accounts"
+        },
+        "metadata": {}
+    });
+    let account = serde_json::from_value::<Account>(example).unwrap();
+    let accounts = &account.external_accounts.data;
+    assert!(matches!(accounts[0], ExternalAccount::BankAccount(_)));
+    assert!(matches!(accounts[1], ExternalAccount::Card(_)));
+    assert_eq!(accounts[1].id().to_string(), "card_1MiN3hLkdIwHu7ixkXhYbXKx");
+    assert_eq!(accounts[1].object(), "card");
+    // The external accounts list must be able to fetch its next page.
+    let _ = List::<ExternalAccount>::next;
+
+    let payout = |destination: serde_json::Value| {
+        serde_json::from_value::<Payout>(json!({
+            "id": "po_1MiN3hLkdIwHu7ixAq6V3Q6M",
+            "object": "payout",
+            "amount": 1100,
+            "arrival_date": 1678229089,
+            "automatic": false,
+            "created": 1678229089,
+            "currency": "usd",
+            "destination": destination,
+            "livemode": false,
+            "metadata": {},
+            "method": "instant",
+            "source_type": "card",
+            "status": "paid",
+            "type": "card"
+        }))
+        .unwrap()
+    };
+    match payout(card).destination {
+        Some(Expandable::Object(destination)) => {
+            assert!(matches!(*destination, ExternalAccount::Card(_)))
+        }
+        other => panic!("expected an expanded card, got {:?}", other),
+    }
+    let destination = payout(json!("ba_1MiN3hLkdIwHu7ixr5mBXj4H")).destination.unwrap();
+    assert_eq!(destination.id().to_string(), "ba_1MiN3hLkdIwHu7ixr5mBXj4H");
+}
+
+#[test]
+fn serialize_create_funding_instructions() {
+    use stripe::{
+        BankTransferParams, BankTransferType, CreateFundingInstructions, Currency,
+        EuBankTransferParams, FundingInstructions,
+        FundingInstructionsBankTransferFinancialAddressType, FundingType,
+    };
+
+    let mut bank_transfer = BankTransferParams::new(BankTransferType::EuBankTransfer);
+    bank_transfer.eu_bank_transfer = Some(EuBankTransferParams { country: "DE".into() });
+    bank_transfer.requested_address_types =
+        Some(vec![FundingInstructionsBankTransferFinancialAddressType::Iban]);
+    let params = CreateFundingInstructions::new(bank_transfer, Currency::EUR);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "bank_transfer[eu_bank_transfer][country]=DE\
+         &bank_transfer[requested_address_types][0]=iban\
+         &bank_transfer[type]=eu_bank_transfer&currency=eur&funding_type=bank_transfer"
+    );
+
+    let example = json!({
+        "object": "funding_instructions",
+        "bank_transfer": {
+            "country": "DE",
+            "financial_addresses": [{
+                "iban": {
+                    "account_holder_name": "Stripe Technology Europe Limited",
+                    "bic": "SXPYDEHH",
+                    "country": "DE",
+                    "iban": "DE00000000000000000001"
+                },
+                "supported_networks": ["sepa"],
+                "type": "iban"
+            }],
+            "type": "eu_bank_transfer"
+        },
+        "currency": "eur",
+        "funding_type": "bank_transfer",
+        "livemode": false
+    });
+    let instructions = serde_json::from_value::<FundingInstructions>(example).unwrap();
+    assert_eq!(instructions.funding_type, FundingType::BankTransfer);
+    assert_eq!(instructions.bank_transfer.type_, BankTransferType::EuBankTransfer);
+    let address = &instructions.bank_transfer.financial_addresses[0];
+    assert_eq!(address.iban.as_ref().unwrap().iban, "DE00000000000000000001");
+
+    let example = json!({
+        "object": "funding_instructions",
+        "bank_transfer": {
+            "country": "US",
+            "financial_addresses": [{
+                "swift": {
+                    "account_number": "000123456789",
+                    "bank_name": "Test Bank",
+                    "swift_code": "TSTBUS33"
+                },
+                "supported_networks": ["swift"],
+                "type": "swift"
+            }, {
+                "some_future_address": { "number": "123" },
+                "type": "some_future_address"
+            }],
+            "type": "some_future_bank_transfer"
+        },
+        "currency": "usd",
+        "funding_type": "bank_transfer",
+        "livemode": false
+    });
+    let instructions = serde_json::from_value::<FundingInstructions>(example).unwrap();
+    assert_eq!(
+        instructions.bank_transfer.type_,
+        BankTransferType::Other("some_future_bank_transfer".into())
+    );
+    let addresses = &instructions.bank_transfer.financial_addresses;
+    assert_eq!(addresses[0].swift.as_ref().unwrap().account_number, "000123456789");
+    assert_eq!(
+        addresses[1].type_,
+        FundingInstructionsBankTransferFinancialAddressType::Other("some_future_address".into())
+    );
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_pay_invoice() {
+    use stripe::{PayInvoice, PaymentMethodId};
+
+    let mut params = PayInvoice::new();
+    params.paid_out_of_band = Some(true);
+    assert_eq!(serde_qs::to_string(&params).unwrap(), "paid_out_of_band=true");
+
+    let mut params = PayInvoice::new();
+    params.off_session = Some(false);
+    params.payment_method = Some("pm_1MiN3hLkdIwHu7ixc7bN2gUf".parse::<PaymentMethodId>().unwrap());
+    assert_eq!(
+        serde_qs::to_string(&params).unwrap(),
+        "off_session=false&payment_method=pm_1MiN3hLkdIwHu7ixc7bN2gUf"
+    );
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_list_invoices_filters() {
+    use stripe::{
+        CollectionMethod, InvoiceStatusFilter, ListInvoices, RangeBounds, RangeQuery,
+        SubscriptionId,
+    };
+
+    let mut params = ListInvoices::new();
+    params.status = Some(InvoiceStatusFilter::Open);
+    params.collection_method = Some(CollectionMethod::SendInvoice);
+    params.subscription = Some("sub_1MiN3gLkdIwHu7ixG3wT8a1n".parse::<SubscriptionId>().unwrap());
+    params.due_date =
+        Some(RangeQuery::Bounds(RangeBounds { lt: Some(1678233600), ..Default::default() }));
+    params.limit = Some(100);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "collection_method=send_invoice&due_date[lt]=1678233600&limit=100\
+         &status=open&subscription=sub_1MiN3gLkdIwHu7ixG3wT8a1n"
+    );
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn deserialize_invoice_automatic_tax() {
+    use stripe::{InvoiceAutomaticTax, InvoiceAutomaticTaxStatus};
+
+    let example = json!({ "enabled": true, "status": "requires_location_inputs" });
+    let automatic_tax = serde_json::from_value::<InvoiceAutomaticTax>(example).unwrap();
+    assert!(automatic_tax.enabled);
+    assert_eq!(automatic_tax.status, Some(InvoiceAutomaticTaxStatus::RequiresLocationInputs));
+
+    let example = json!({ "enabled": false, "status": null });
+    let automatic_tax = serde_json::from_value::<InvoiceAutomaticTax>(example).unwrap();
+    assert!(!automatic_tax.enabled);
+    assert_eq!(automatic_tax.status, None);
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn deserialize_subscription_schedule_default_settings() {
+    use stripe::{CollectionMethod, SubscriptionScheduleDefaultSettings};
+
+    // The schedule's collection method is the same enum as the invoice's and subscription's.
+    let example = json!({
+        "billing_thresholds": null,
+        "collection_method": "send_invoice",
+        "default_payment_method": null,
+        "invoice_settings": { "days_until_due": 30 }
+    });
+    let settings = serde_json::from_value::<SubscriptionScheduleDefaultSettings>(example).unwrap();
+    assert_eq!(settings.collection_method, Some(CollectionMethod::SendInvoice));
+    assert_eq!(settings.invoice_settings.unwrap().days_until_due, Some(30));
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_subscription_schedule_from_subscription() {
+    use stripe::{CreateSubscriptionSchedule, SubscriptionId};
+
+    let subscription = "sub_1MiN3gLkdIwHu7ixG3wT8a1n".parse::<SubscriptionId>().unwrap();
+    let params = CreateSubscriptionSchedule::from_subscription(subscription);
+    assert_eq!(
+        serde_qs::to_string(&params).unwrap(),
+        "from_subscription=sub_1MiN3gLkdIwHu7ixG3wT8a1n"
+    );
+}
+
+#[test]
+fn serialize_list_charges_created_between() {
+    use stripe::{ListCharges, RangeQuery};
+
+    let mut params = ListCharges::new();
+    params.created = Some(RangeQuery::between(1675209600, 1677628799));
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "created[gte]=1675209600&created[lte]=1677628799"
+    );
+}
+
+#[test]
+fn serialize_charge_fraud_details() {
+    use stripe::{FraudDetails, FraudDetailsParams, FraudDetailsReport, UpdateCharge};
+
+    let mut params = UpdateCharge::new();
+    params.fraud_details = Some(FraudDetailsParams { user_report: FraudDetailsReport::Safe });
+    assert_eq!(urldecode(serde_qs::to_string(&params).unwrap()), "fraud_details[user_report]=safe");
+
+    let both: FraudDetails =
+        serde_json::from_value(json!({ "stripe_report": "fraudulent", "user_report": "safe" }))
+            .unwrap();
+    assert_eq!(both.stripe_report, Some(FraudDetailsReport::Fraudulent));
+    assert_eq!(both.user_report, Some(FraudDetailsReport::Safe));
+
+    let none: FraudDetails = serde_json::from_value(json!({})).unwrap();
+    assert!(none.stripe_report.is_none() && none.user_report.is_none());
+}
+
+#[test]
+fn serialize_payment_intent_confirm_on_create() {
+    use stripe::{CreatePaymentIntent, Currency};
+
+    let mut params = CreatePaymentIntent::new(2000, Currency::EUR);
+    params.confirm = Some(true);
+    params.payment_method = Some("pm_1MiN3hLkdIwHu7ixc7bN2gUf".parse().unwrap());
+    params.return_url = Some("https://example.com/checkout/complete");
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "amount=2000&currency=eur&payment_method=pm_1MiN3hLkdIwHu7ixc7bN2gUf&confirm=true\
+         &return_url=https%3A%2F%2Fexample.com%2Fcheckout%2Fcomplete"
+    );
+}
+
+#[test]
+fn serialize_customer_address_and_shipping() {
+    use stripe::{Address, CreateCustomer, ShippingParams, UpdateCustomer};
+
+    let address = Address {
+        line1: Some("1 Rue de Rivoli".into()),
+        city: Some("Paris".into()),
+        postal_code: Some("75001".into()),
+        country: Some("FR".into()),
+        ..Default::default()
+    };
+    let mut params = CreateCustomer::new();
+    params.address = Some(address.clone());
+    params.shipping = Some(ShippingParams {
+        phone: Some("+33123456789".into()),
+        ..ShippingParams::new("Jenny Rosen", address)
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "address[line1]=1+Rue+de+Rivoli&address[city]=Paris&address[postal_code]=75001\
+         &address[country]=FR&shipping[address][line1]=1+Rue+de+Rivoli\
+         &shipping[address][city]=Paris&shipping[address][postal_code]=75001\
+         &shipping[address][country]=FR&shipping[name]=Jenny+Rosen&shipping[phone]=%2B33123456789"
+    );
+
+    let mut params = UpdateCustomer::new();
+    params.address = Some(Address {
+        line1: Some("354 Oyster Point Blvd".into()),
+        line2: Some("Suite 100".into()),
+        city: Some("South San Francisco".into()),
+        state: Some("CA".into()),
+        postal_code: Some("94080".into()),
+        country: Some("US".into()),
+        ..Default::default()
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "address[line1]=354+Oyster+Point+Blvd&address[line2]=Suite+100\
+         &address[city]=South+San+Francisco&address[state]=CA&address[postal_code]=94080\
+         &address[country]=US"
+    );
+}
+
+#[test]
+fn serialize_shipping_from_response() {
+    use stripe::{
+        Address, ChargeShippingParams, CreatePaymentIntent, Currency, Shipping, ShippingParams,
+        UpdateCharge, UpdateCustomer,
+    };
+
+    // Shipping read back from a payment intent, carrier and tracking number included.
+    let shipping: Shipping = serde_json::from_value(json!({
+        "address": { "line1": "1 Rue de Rivoli", "city": "Paris", "country": "FR" },
+        "carrier": "UPS",
+        "name": "Jenny Rosen",
+        "phone": "+33123456789",
+        "tracking_number": "1Z999AA10123456784"
+    }))
+    .unwrap();
+    let address = "shipping[address][line1]=1+Rue+de+Rivoli&shipping[address][city]=Paris\
+                   &shipping[address][country]=FR";
+
+    let mut params = UpdateCharge::new();
+    params.shipping = Some(ChargeShippingParams::from(shipping.clone()));
+    assert_eq!(
+        urldecode(stripe::to_form_body(&params).unwrap()),
+        format!(
+            "{}&shipping[carrier]=UPS&shipping[name]=Jenny+Rosen&shipping[phone]=%2B33123456789\
+             &shipping[tracking_number]=1Z999AA10123456784",
+            address
+        )
+    );
+
+    let mut params = CreatePaymentIntent::new(2000, Currency::EUR);
+    params.shipping = Some(ChargeShippingParams::from(shipping.clone()));
+    let body = urldecode(stripe::to_form_body(&params).unwrap());
+    assert!(body.ends_with(&format!(
+        "{}&shipping[carrier]=UPS&shipping[name]=Jenny+Rosen&shipping[phone]=%2B33123456789\
+         &shipping[tracking_number]=1Z999AA10123456784",
+        address
+    )));
+
+    // Customers reject `carrier` and `tracking_number`, so the conversion drops them.
+    let mut params = UpdateCustomer::new();
+    params.shipping = Some(ShippingParams::from(shipping));
+    assert_eq!(
+        urldecode(stripe::to_form_body(&params).unwrap()),
+        format!("{}&shipping[name]=Jenny+Rosen&shipping[phone]=%2B33123456789", address)
+    );
+
+    let address = Address { line1: Some("1 Rue de Rivoli".into()), ..Default::default() };
+    let mut params = UpdateCustomer::new();
+    params.shipping = Some(ShippingParams::new("Jenny Rosen", address));
+    assert_eq!(
+        urldecode(stripe::to_form_body(&params).unwrap()),
+        "shipping[address][line1]=1+Rue+de+Rivoli&shipping[name]=Jenny+Rosen"
+    );
+}
+
+#[test]
+fn serialize_customer_default_payment_method() {
+    use stripe::{CreateCustomer, CustomerInvoiceSettings, PaymentMethodId};
+
+    let pm: PaymentMethodId = "pm_1MiN3hLkdIwHu7ixc7bN2gUf".parse().unwrap();
+    let mut params = CreateCustomer::new();
+    params.payment_method = Some(pm.clone());
+    params.invoice_settings = Some(CustomerInvoiceSettings {
+        default_payment_method: Some(pm.to_string()),
+        ..Default::default()
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "invoice_settings[default_payment_method]=pm_1MiN3hLkdIwHu7ixc7bN2gUf\
+         &payment_method=pm_1MiN3hLkdIwHu7ixc7bN2gUf"
+    );
+}
+
+#[test]
+#[cfg(feature = "issuing")]
+fn serialize_test_create_issuing_authorization() {
+    use stripe::{
+        IssuingAuthorizationCheck, IssuingAuthorizationMethod, IssuingCardId, MerchantCategory,
+        TestCreateIssuingAuthorization, TestCreateIssuingAuthorizationMerchantData,
+        TestCreateIssuingAuthorizationVerificationData,
+    };
+
+    let card: IssuingCardId = "ic_1MvSieLkdIwHu7ixn6uuO0Xu".parse().unwrap();
+    let mut params = TestCreateIssuingAuthorization::new(card, 1000);
+    params.authorization_method = Some(IssuingAuthorizationMethod::Contactless);
+    params.is_amount_controllable = Some(true);
+    params.merchant_data = Some(TestCreateIssuingAuthorizationMerchantData {
+        category: Some(MerchantCategory::Bakeries),
+        name: Some("Corner Bakery".into()),
+        ..Default::default()
+    });
+    params.verification_data = Some(TestCreateIssuingAuthorizationVerificationData {
+        cvc_check: Some(IssuingAuthorizationCheck::Mismatch),
+        ..Default::default()
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "amount=1000&authorization_method=contactless&card=ic_1MvSieLkdIwHu7ixn6uuO0Xu\
+         &is_amount_controllable=true&merchant_data[category]=bakeries\
+         &merchant_data[name]=Corner+Bakery&verification_data[cvc_check]=mismatch"
+    );
+}
+
+#[test]
+fn deserialize_with_unknown_and_missing_fields() {
+    use stripe::{Charge, Refund};
+
+    // Keys this crate doesn't know yet, top-level and nested, are ignored; optional keys and
+    // metadata may be left out entirely, as older API versions do.
+    let example = json!({
+        "id": "ch_1MiN3gLkdIwHu7ixUiHUQ7nz",
+        "object": "charge",
+        "amount": 2000,
+        "amount_refunded": 0,
+        "billing_details": {
+            "email": "jenny.rosen@example.com",
+            "tax_id": "FR12345678901"
+        },
+        "captured": true,
+        "created": 1678037688,
+        "currency": "eur",
+        "disputed": false,
+        "livemode": false,
+        "paid": true,
+        "presentment_details": {
+            "presentment_amount": 2200,
+            "presentment_currency": "usd"
+        },
+        "refunded": false,
+        "refunds": {
+            "object": "list",
+            "data": [],
+            "has_more": false,
+            "total_count": 0,
+            "url": "/v1/charges/ch_1MiN3gLkdIwHu7ixUiHUQ7nz/refunds"
+        },
+        "status": "succeeded"
+    });
+    let charge = serde_json::from_value::<Charge>(example).unwrap();
+    assert!(charge.metadata.is_empty());
+    assert_eq!(charge.description, None);
+    assert_eq!(charge.billing_details.email.as_deref(), Some("jenny.rosen@example.com"));
+
+    let example = json!({
+        "id": "re_1Nispe2eZvKYlo2Cd31jOCgZ",
+        "object": "refund",
+        "amount": 1000,
+        "created": 1692942318,
+        "currency": "usd",
+        "destination_details": { "type": "card", "card": { "type": "refund" } }
+    });
+    let refund = serde_json::from_value::<Refund>(example).unwrap();
+    assert!(refund.metadata.is_empty());
+    assert_eq!(refund.status, None);
+
+    // Scalars that the spec requires but some API versions leave out.
+    #[cfg(feature = "billing")]
+    {
+        use stripe::{Discount, Subscription};
+
+        let example = json!({
+            "object": "discount",
+            "coupon": { "id": "SUMMER", "object": "coupon", "duration": "once", "valid": true },
+            "end": null,
+            "legacy_field": "ignored"
+        });
+        let discount = serde_json::from_value::<Discount>(example).unwrap();
+        assert_eq!(discount.id, None);
+        assert_eq!(discount.start, None);
+
+        // From 2025-03-31.basil the current period lives on the subscription items.
+        let example = json!({
+            "id": "sub_1MiN3hLkdIwHu7ixzHtqI7bV",
+            "object": "subscription",
+            "billing_cycle_anchor": 1678037688,
+            "cancel_at_period_end": false,
+            "created": 1678037688,
+            "customer": "cus_4QFJOjw2pOmAGJ",
+            "items": {
+                "object": "list",
+                "data": [],
+                "has_more": false,
+                "url": "/v1/subscription_items?subscription=sub_1MiN3hLkdIwHu7ixzHtqI7bV"
+            },
+            "livemode": false,
+            "start_date": 1678037688,
+            "status": "active"
+        });
+        let subscription = serde_json::from_value::<Subscription>(example).unwrap();
+        assert_eq!(subscription.current_period_end, None);
+        assert_eq!(subscription.current_period_start, None);
+    }
+}
+
+#[test]
+fn serialize_list_customers_by_email() {
+    use stripe::ListCustomers;
+
+    let params = ListCustomers { email: Some("jenny+shop@example.com"), ..Default::default() };
+    assert_eq!(serde_qs::to_string(&params).unwrap(), "email=jenny%2Bshop%40example.com");
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_update_invoice_auto_advance() {
+    use stripe::{CollectionMethod, UpdateInvoice};
+
+    // Turning off automatic collection keeps a draft from being finalized until it's reviewed.
+    let mut params = UpdateInvoice::new();
+    params.auto_advance = Some(false);
+    params.collection_method = Some(CollectionMethod::SendInvoice);
+    params.days_until_due = Some(30);
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "auto_advance=false&collection_method=send_invoice&days_until_due=30"
+    );
+}
+
+#[cfg(feature = "billing")]
+#[test]
+fn serialize_coupon_applies_to() {
+    use stripe::{Coupon, CouponAppliesTo, CouponDuration, CreateCoupon};
+
+    let mut params = CreateCoupon::new(CouponDuration::Forever);
+    params.percent_off = Some(25.0);
+    params.applies_to = Some(CouponAppliesTo {
+        products: vec![
+            "prod_NWjs8kKbJWmuuc".parse().unwrap(),
+            "prod_NWjs9pVmKrLpQc".parse().unwrap(),
+        ],
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "applies_to[products][0]=prod_NWjs8kKbJWmuuc&applies_to[products][1]=prod_NWjs9pVmKrLpQc\
+         &duration=forever&percent_off=25"
+    );
+
+    let example = json!({
+        "id": "Z4OV52SU",
+        "object": "coupon",
+        "applies_to": { "products": ["prod_NWjs8kKbJWmuuc"] },
+        "created": 1678037688,
+        "duration": "forever",
+        "livemode": false,
+        "metadata": {},
+        "percent_off": 25.5,
+        "valid": true
+    });
+    let coupon = serde_json::from_value::<Coupon>(example).unwrap();
+    assert_eq!(coupon.applies_to.unwrap().products[0].as_str(), "prod_NWjs8kKbJWmuuc");
+}
+
+#[test]
+fn serialize_payment_intent_automatic_payment_methods() {
+    use stripe::{
+        CreatePaymentIntent, Currency, PaymentIntentAutomaticPaymentMethods,
+        PaymentIntentAutomaticPaymentMethodsAllowRedirects,
+    };
+
+    let mut params = CreatePaymentIntent::new(2000, Currency::USD);
+    params.automatic_payment_methods = Some(PaymentIntentAutomaticPaymentMethods {
+        enabled: true,
+        allow_redirects: Some(PaymentIntentAutomaticPaymentMethodsAllowRedirects::Never),
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "amount=2000&currency=usd&automatic_payment_methods[enabled]=true\
+         &automatic_payment_methods[allow_redirects]=never"
+    );
+
+    let example = json!({ "allow_redirects": "always", "enabled": true });
+    let methods = serde_json::from_value::<PaymentIntentAutomaticPaymentMethods>(example).unwrap();
+    assert!(methods.enabled);
+    assert_eq!(
+        methods.allow_redirects,
+        Some(PaymentIntentAutomaticPaymentMethodsAllowRedirects::Always)
+    );
+}
+
+#[test]
+fn deserialize_setup_intent_verify_with_microdeposits() {
+    use stripe::{
+        PaymentIntentNextActionVerifyWithMicrodepositsMicrodepositType, SetupIntentNextAction,
+    };
+
+    let example = json!({
+        "type": "verify_with_microdeposits",
+        "verify_with_microdeposits": {
+            "arrival_date": 1678406400,
+            "hosted_verification_url": "https://payments.stripe.com/microdeposit/sacs_test_123",
+            "microdeposit_type": "descriptor_code"
+        }
+    });
+    let action = serde_json::from_value::<SetupIntentNextAction>(example).unwrap();
+    assert_eq!(action.type_, "verify_with_microdeposits");
+    let verify = action.verify_with_microdeposits.unwrap();
+    assert_eq!(verify.arrival_date, 1678406400);
+    assert_eq!(
+        verify.hosted_verification_url,
+        "https://payments.stripe.com/microdeposit/sacs_test_123"
+    );
+    assert_eq!(
+        verify.microdeposit_type,
+        Some(PaymentIntentNextActionVerifyWithMicrodepositsMicrodepositType::DescriptorCode)
+    );
+}
@@ -0,0 +1,66 @@
+//! Events whose object is of a type the library doesn't know must still be retrievable.
+
+#![cfg(feature = "events")]
+
+mod local;
+
+use stripe::{Event, EventObject};
+
+fn event() -> serde_json::Value {
+    serde_json::json!({
+        "id": "evt_123",
+        "object": "event",
+        "api_version": "2022-11-15",
+        "created": 1680648842,
+        "data": {
+            "object": { "id": "bnb_123", "object": "brand_new_beta", "amount": 100 }
+        },
+        "livemode": false,
+        "pending_webhooks": 0,
+        "type": "account.updated"
+    })
+}
+
+fn assert_unknown(event: &Event) {
+    assert_eq!(event.id, "evt_123");
+    match &event.data.object {
+        EventObject::Unknown { object, data } => {
+            assert_eq!(object, "brand_new_beta");
+            assert_eq!(data["amount"], 100);
+        }
+        other => panic!("expected an unknown object; was {:?}", other),
+    }
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn retrieve_event_with_unknown_object() {
+    let (url, requests) = local::serve(vec![local::ok(&event())]);
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let event = Event::retrieve(&client, &"evt_123".parse().unwrap(), &[]).unwrap();
+    assert_unknown(&event);
+    assert!(requests.lock().unwrap()[0].line.starts_with("GET /v1/events/evt_123"));
+}
+
+#[cfg(not(feature = "blocking"))]
+#[tokio::test]
+async fn retrieve_event_with_unknown_object() {
+    let (url, requests) = local::serve(vec![local::ok(&event())]);
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let event = Event::retrieve(&client, &"evt_123".parse().unwrap(), &[]).await.unwrap();
+    assert_unknown(&event);
+    assert!(requests.lock().unwrap()[0].line.starts_with("GET /v1/events/evt_123"));
+}
+
+#[cfg(feature = "webhook-events")]
+#[test]
+fn construct_event_with_unknown_object() {
+    use stripe::Webhook;
+
+    let payload = event().to_string();
+    let header = Webhook::generate_test_header(&payload, "whsec_test", 1680648842);
+    let event = Webhook::construct_event_at(&payload, &header, "whsec_test", 1680648842).unwrap();
+    assert_unknown(&event);
+}
@@ -0,0 +1,149 @@
+//! A linked account is refreshed, disconnected and has its owners and transactions listed.
+
+#![cfg(feature = "financial-connections")]
+
+mod local;
+
+use serde_json::json;
+use stripe::{
+    FinancialConnectionsAccountRefreshStatus, FinancialConnectionsAccountStatus,
+    FinancialConnectionsTransactionStatus, ListFinancialConnectionsAccountOwners,
+    ListFinancialConnectionsTransactions, RefreshFinancialConnectionsAccount, RefreshableFeature,
+};
+
+fn account(status: &str) -> String {
+    local::ok(&json!({
+        "id": "fca_123",
+        "object": "financial_connections.account",
+        "account_holder": { "customer": "cus_123", "type": "customer" },
+        "balance_refresh": { "last_attempted_at": 1_680_000_000, "status": "pending" },
+        "category": "cash",
+        "created": 1_679_000_000,
+        "display_name": "Checking",
+        "institution_name": "StripeBank",
+        "last4": "6789",
+        "livemode": false,
+        "ownership": "fcaowns_123",
+        "ownership_refresh": null,
+        "status": status,
+        "transaction_refresh": null
+    }))
+}
+
+fn list(url: &str, data: serde_json::Value) -> String {
+    local::ok(&json!({ "object": "list", "data": [data], "has_more": false, "url": url }))
+}
+
+fn responses() -> Vec<String> {
+    vec![
+        account("active"),
+        account("disconnected"),
+        list(
+            "/v1/financial_connections/accounts/fca_123/owners",
+            json!({
+                "id": "fcaown_123",
+                "object": "financial_connections.account_owner",
+                "email": "jenny@example.com",
+                "name": "Jenny Rosen",
+                "ownership": "fcaowns_123",
+                "phone": null,
+                "raw_address": null,
+                "refreshed_at": 1_680_000_000
+            }),
+        ),
+        list(
+            "/v1/financial_connections/transactions",
+            json!({
+                "id": "fctxn_123",
+                "object": "financial_connections.transaction",
+                "account": "fca_123",
+                "amount": -1500,
+                "currency": "usd",
+                "description": "Coffee",
+                "livemode": false,
+                "status": "posted",
+                "status_transitions": { "posted_at": 1_680_000_000, "void_at": null },
+                "transacted_at": 1_680_000_000,
+                "transaction_refresh": "fctxnref_123",
+                "updated": 1_680_000_000
+            }),
+        ),
+    ]
+}
+
+fn refresh() -> RefreshFinancialConnectionsAccount<'static> {
+    RefreshFinancialConnectionsAccount::new(vec![RefreshableFeature::Balance])
+}
+
+fn owners() -> ListFinancialConnectionsAccountOwners<'static> {
+    ListFinancialConnectionsAccountOwners::new("fcaowns_123".parse().unwrap())
+}
+
+fn transactions() -> ListFinancialConnectionsTransactions<'static> {
+    let mut params = ListFinancialConnectionsTransactions::new();
+    params.limit = Some(3);
+    params
+}
+
+fn assert_requests(requests: &[local::Request]) {
+    let lines: Vec<_> = requests.iter().map(|request| request.line.as_str()).collect();
+    assert_eq!(
+        lines,
+        [
+            "POST /v1/financial_connections/accounts/fca_123/refresh HTTP/1.1",
+            "POST /v1/financial_connections/accounts/fca_123/disconnect HTTP/1.1",
+            "GET /v1/financial_connections/accounts/fca_123/owners?ownership=fcaowns_123 HTTP/1.1",
+            "GET /v1/financial_connections/transactions?account=fca_123&limit=3 HTTP/1.1",
+        ]
+    );
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn account_lifecycle() {
+    use stripe::FinancialConnectionsAccount;
+
+    let (url, requests) = local::serve(responses());
+    let client = stripe::Client::from_url(url, "sk_test_123");
+    let id = "fca_123".parse().unwrap();
+
+    let account = FinancialConnectionsAccount::refresh(&client, &id, refresh()).unwrap();
+    let balance_refresh = account.balance_refresh.unwrap();
+    assert_eq!(balance_refresh.status, FinancialConnectionsAccountRefreshStatus::Pending);
+
+    let account = FinancialConnectionsAccount::disconnect(&client, &id).unwrap();
+    assert_eq!(account.status, FinancialConnectionsAccountStatus::Disconnected);
+
+    let owners = FinancialConnectionsAccount::list_owners(&client, &id, owners()).unwrap();
+    assert_eq!(owners.data[0].name, "Jenny Rosen");
+
+    let transactions =
+        FinancialConnectionsAccount::list_transactions(&client, &id, transactions()).unwrap();
+    assert_eq!(transactions.data[0].status, FinancialConnectionsTransactionStatus::Posted);
+    assert_requests(&requests.lock().unwrap());
+}
+
+#[cfg(not(feature = "blocking"))]
+#[tokio::test]
+async fn account_lifecycle() {
+    use stripe::FinancialConnectionsAccount;
+
+    let (url, requests) = local::serve(responses());
+    let client = stripe::Client::from_url(url, "sk_test_123");
+    let id = "fca_123".parse().unwrap();
+
+    let account = FinancialConnectionsAccount::refresh(&client, &id, refresh()).await.unwrap();
+    let balance_refresh = account.balance_refresh.unwrap();
+    assert_eq!(balance_refresh.status, FinancialConnectionsAccountRefreshStatus::Pending);
+
+    let account = FinancialConnectionsAccount::disconnect(&client, &id).await.unwrap();
+    assert_eq!(account.status, FinancialConnectionsAccountStatus::Disconnected);
+
+    let owners = FinancialConnectionsAccount::list_owners(&client, &id, owners()).await.unwrap();
+    assert_eq!(owners.data[0].name, "Jenny Rosen");
+
+    let transactions =
+        FinancialConnectionsAccount::list_transactions(&client, &id, transactions()).await.unwrap();
+    assert_eq!(transactions.data[0].status, FinancialConnectionsTransactionStatus::Posted);
+    assert_requests(&requests.lock().unwrap());
+}
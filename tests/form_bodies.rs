@@ -0,0 +1,1712 @@
+//! Snapshots of the form bodies that params structs encode to.
+//!
+//! Each test fills in a params struct (nested structs, lists, enums and ranges included) and
+//! snapshots what `stripe::to_form_body` produces, which is exactly what the client sends.
+//! When a change to a params struct alters what goes over the wire, the `.snap` file in
+//! `tests/snapshots` changes with it and shows up in review.
+//!
+//! Metadata and other maps only ever get a single key here, since their order isn't stable.
+
+use insta::assert_snapshot;
+use stripe::{to_form_body, Address, Currency, Metadata, RangeBounds, RangeQuery};
+
+fn metadata() -> Metadata {
+    let mut metadata = Metadata::new();
+    metadata.insert("order_id".to_string(), "6735".to_string());
+    metadata
+}
+
+fn address() -> Address {
+    Address {
+        line1: Some("1 Rue de Rivoli".into()),
+        line2: Some("Bâtiment B".into()),
+        city: Some("Paris".into()),
+        postal_code: Some("75001".into()),
+        country: Some("FR".into()),
+        ..Default::default()
+    }
+}
+
+/// Reads an enum from its wire value, for enums whose name is exported by several modules and
+/// so can't be imported here.
+#[allow(dead_code)]
+fn wire<T: serde::de::DeserializeOwned>(value: &str) -> T {
+    serde_json::from_value(serde_json::Value::from(value)).unwrap()
+}
+
+fn created() -> RangeQuery<stripe::Timestamp> {
+    RangeQuery::Bounds(RangeBounds {
+        gte: Some(1_672_531_200),
+        lt: Some(1_675_209_600),
+        ..Default::default()
+    })
+}
+
+#[test]
+fn balance_transaction() {
+    use stripe::ListBalanceTransactions;
+
+    let mut params = ListBalanceTransactions::new();
+    params.available_on = Some(RangeQuery::gte(1_672_531_200));
+    params.created = Some(created());
+    params.currency = Some(Currency::EUR);
+    params.expand = &["data.source"];
+    params.limit = Some(25);
+    params.payout = Some("po_1MiN3hLkdIwHu7ixc7bN2gUf".parse().unwrap());
+    params.starting_after = Some("txn_1MiN3gLkdIwHu7ixxapQrznl".parse().unwrap());
+    params.type_ = Some("charge");
+    assert_snapshot!("list_balance_transactions", to_form_body(&params).unwrap());
+}
+
+#[test]
+fn charge() {
+    use stripe::{
+        ChargeShippingParams, ChargeSourceParams, CreateCharge, FraudDetailsParams,
+        FraudDetailsReport, ListCharges, Shipping, TransferDataParams, UpdateCharge,
+    };
+
+    // Shipping details read back from Stripe can be sent again as they are.
+    let shipping: ChargeShippingParams = Shipping {
+        name: "Jenny Rosen".into(),
+        address: address(),
+        carrier: Some("UPS".into()),
+        phone: Some("+33123456789".into()),
+        tracking_number: Some("1Z999AA10123456784".into()),
+    }
+    .into();
+
+    let mut params = CreateCharge::new();
+    params.amount = Some(2000);
+    params.application_fee_amount = Some(123);
+    params.capture = Some(false);
+    params.currency = Some(Currency::EUR);
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.description = Some("Order #6735");
+    params.expand = &["balance_transaction", "customer"];
+    params.metadata = Some(metadata());
+    params.receipt_email = Some("jenny.rosen@example.com");
+    params.shipping = Some(shipping.clone());
+    params.source =
+        Some(ChargeSourceParams::Card("card_1MiN3gLkdIwHu7ixrFMZ6dmt".parse().unwrap()));
+    params.statement_descriptor_suffix = Some("6735");
+    params.transfer_data = Some(TransferDataParams {
+        amount: Some(1500),
+        destination: "acct_1032D82eZvKYlo2C".into(),
+    });
+    params.transfer_group = Some("ORDER_6735");
+    assert_snapshot!("create_charge", to_form_body(&params).unwrap());
+
+    let mut params = UpdateCharge::new();
+    params.description = Some("Order #6735 (refunded)");
+    params.fraud_details = Some(FraudDetailsParams { user_report: FraudDetailsReport::Fraudulent });
+    params.metadata = Some(metadata());
+    params.shipping = Some(shipping);
+    assert_snapshot!("update_charge", to_form_body(&params).unwrap());
+
+    let mut params = ListCharges::new();
+    params.created = Some(created());
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.limit = Some(3);
+    params.payment_intent = Some("pi_1MiN3hLkdIwHu7ixcG4dVw7d".parse().unwrap());
+    params.transfer_group = Some("ORDER_6735");
+    assert_snapshot!("list_charges", to_form_body(&params).unwrap());
+}
+
+#[test]
+fn customer() {
+    use stripe::{
+        CreateCustomer, CustomField, CustomerInvoiceSettings, CustomerTaxExemptFilter,
+        ListCustomers, PaymentSourceParams, Scheduled, ShippingParams, TaxIdData, TaxIdType,
+        UpdateCustomer,
+    };
+
+    let invoice_settings = CustomerInvoiceSettings {
+        custom_fields: Some(vec![CustomField { name: "VAT".into(), value: "FR12345".into() }]),
+        default_payment_method: Some("pm_1MiN3hLkdIwHu7ixc7bN2gUf".into()),
+        footer: Some("Thank you!".into()),
+    };
+    let shipping = ShippingParams {
+        phone: Some("+33123456789".into()),
+        ..ShippingParams::new("Jenny Rosen", address())
+    };
+
+    let mut params = CreateCustomer::new();
+    params.address = Some(address());
+    params.balance = Some(-500);
+    params.coupon = Some("25OFF".parse().unwrap());
+    params.description = Some("Jenny's account");
+    params.email = Some("jenny.rosen@example.com");
+    params.invoice_prefix = Some("JR");
+    params.invoice_settings = Some(invoice_settings.clone());
+    params.metadata = Some(metadata());
+    params.name = Some("Jenny Rosen");
+    params.next_invoice_sequence = Some(7);
+    params.payment_method = Some("pm_1MiN3hLkdIwHu7ixc7bN2gUf".parse().unwrap());
+    params.phone = Some("+33123456789");
+    params.preferred_locales = Some(vec!["fr".into(), "en".into()]);
+    params.shipping = Some(shipping.clone());
+    params.source = Some(PaymentSourceParams::Token("tok_visa".parse().unwrap()));
+    params.tax_exempt = Some(CustomerTaxExemptFilter::Reverse);
+    params.tax_id_data =
+        Some(vec![TaxIdData { type_: TaxIdType::EuVat, value: "FR12345678901".into() }]);
+    assert_snapshot!("create_customer", to_form_body(&params).unwrap());
+
+    let mut params = UpdateCustomer::new();
+    params.address = Some(address());
+    params.default_source = Some("card_1MiN3gLkdIwHu7ixrFMZ6dmt".parse().unwrap());
+    params.email = Some("jenny@example.com");
+    params.invoice_settings = Some(invoice_settings);
+    params.metadata = Some(metadata());
+    params.shipping = Some(shipping);
+    params.tax_exempt = Some(CustomerTaxExemptFilter::None);
+    params.trial_end = Some(Scheduled::now());
+    assert_snapshot!("update_customer", to_form_body(&params).unwrap());
+
+    let mut params = ListCustomers::new();
+    params.created = Some(created());
+    params.email = Some("jenny.rosen@example.com");
+    params.ending_before = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.expand = &["data.default_source"];
+    params.limit = Some(10);
+    assert_snapshot!("list_customers", to_form_body(&params).unwrap());
+}
+
+#[test]
+fn dispute() {
+    use stripe::{DisputeEvidence, Expandable, ListDisputes, UpdateDispute};
+
+    let mut params = UpdateDispute::new();
+    params.evidence = Some(DisputeEvidence {
+        access_activity_log: None,
+        billing_address: Some("1 Rue de Rivoli, Paris".into()),
+        cancellation_policy: None,
+        cancellation_policy_disclosure: None,
+        cancellation_rebuttal: None,
+        customer_communication: Some(Expandable::Id(
+            "file_1MiN3gLkdIwHu7ixqHUB5AFd".parse().unwrap(),
+        )),
+        customer_email_address: Some("jenny.rosen@example.com".into()),
+        customer_name: Some("Jenny Rosen".into()),
+        customer_purchase_ip: Some("203.0.113.7".into()),
+        customer_signature: None,
+        duplicate_charge_documentation: None,
+        duplicate_charge_explanation: None,
+        duplicate_charge_id: None,
+        product_description: Some("Annual plan".into()),
+        receipt: None,
+        refund_policy: None,
+        refund_policy_disclosure: None,
+        refund_refusal_explanation: None,
+        service_date: None,
+        service_documentation: None,
+        shipping_address: None,
+        shipping_carrier: Some("UPS".into()),
+        shipping_date: Some("2023-01-02".into()),
+        shipping_documentation: None,
+        shipping_tracking_number: Some("1Z999AA10123456784".into()),
+        uncategorized_file: None,
+        uncategorized_text: None,
+    });
+    params.metadata = Some(metadata());
+    params.submit = Some(true);
+    assert_snapshot!("update_dispute", to_form_body(&params).unwrap());
+
+    let mut params = ListDisputes::new();
+    params.charge = Some("ch_1MiN3gLkdIwHu7ixUiHUQ7nz".parse().unwrap());
+    params.created = Some(created());
+    params.limit = Some(5);
+    assert_snapshot!("list_disputes", to_form_body(&params).unwrap());
+}
+
+#[test]
+fn file() {
+    use stripe::{
+        CreateFileLink, FilePurpose, ListFileLinks, ListFiles, Scheduled, UpdateFileLink,
+    };
+
+    let mut params = ListFiles::new();
+    params.created = Some(created());
+    params.limit = Some(5);
+    params.purpose = Some(FilePurpose::DisputeEvidence);
+    assert_snapshot!("list_files", to_form_body(&params).unwrap());
+
+    let mut params = CreateFileLink::new("file_1MiN3gLkdIwHu7ixqHUB5AFd".parse().unwrap());
+    params.expires_at = Some(1_675_209_600);
+    params.metadata = Some(metadata());
+    assert_snapshot!("create_file_link", to_form_body(&params).unwrap());
+
+    let mut params = UpdateFileLink::new();
+    params.expires_at = Some(Scheduled::now());
+    params.metadata = Some(metadata());
+    assert_snapshot!("update_file_link", to_form_body(&params).unwrap());
+
+    let mut params = ListFileLinks::new();
+    params.created = Some(created());
+    params.expired = Some(false);
+    params.file = Some("file_1MiN3gLkdIwHu7ixqHUB5AFd".parse().unwrap());
+    assert_snapshot!("list_file_links", to_form_body(&params).unwrap());
+}
+
+#[test]
+fn funding_instructions() {
+    use stripe::{
+        BankTransferParams, BankTransferType, CreateFundingInstructions, EuBankTransferParams,
+        FundingInstructionsBankTransferFinancialAddressType,
+    };
+
+    let mut bank_transfer = BankTransferParams::new(BankTransferType::EuBankTransfer);
+    bank_transfer.eu_bank_transfer = Some(EuBankTransferParams { country: "FR".into() });
+    bank_transfer.requested_address_types =
+        Some(vec![FundingInstructionsBankTransferFinancialAddressType::Iban]);
+    let mut params = CreateFundingInstructions::new(bank_transfer, Currency::EUR);
+    params.expand = &["bank_transfer"];
+    assert_snapshot!("create_funding_instructions", to_form_body(&params).unwrap());
+}
+
+#[test]
+fn payment_intent() {
+    use stripe::{
+        ChargeShippingParams, CreatePaymentIntent, ListPaymentIntents, OffSessionOther,
+        PaymentIntentCaptureMethod, PaymentIntentConfirmationMethod, PaymentIntentMethodType,
+        PaymentIntentOffSession, PaymentIntentPaymentMethodOptions,
+        PaymentIntentPaymentMethodOptionsCard, PaymentIntentSetupFutureUsage,
+        PaymentIntentUpdateParams, TransferDataParams, UpdatePaymentIntentTransferData,
+    };
+
+    let mut params = CreatePaymentIntent::new(2000, Currency::EUR);
+    params.payment_method_types =
+        vec![PaymentIntentMethodType::Card, PaymentIntentMethodType::SepaDebit];
+    params.payment_method = Some("pm_1MiN3hLkdIwHu7ixc7bN2gUf".parse().unwrap());
+    params.confirmation_method = Some(PaymentIntentConfirmationMethod::Manual);
+    params.application_fee_amount = Some(123);
+    params.capture_method = Some(PaymentIntentCaptureMethod::Manual);
+    params.confirm = Some(true);
+    params.customer = Some("cus_4QFJOjw2pOmAGJ");
+    params.description = Some("Order #6735");
+    params.expand = &["latest_charge"];
+    params.metadata = Some(metadata());
+    params.off_session = Some(PaymentIntentOffSession::Other(OffSessionOther::OneOff));
+    params.on_behalf_of = Some("acct_1032D82eZvKYlo2C");
+    params.payment_method_options = Some(PaymentIntentPaymentMethodOptions {
+        card: Some(PaymentIntentPaymentMethodOptionsCard {
+            network: Some("cartes_bancaires".into()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    params.receipt_email = Some("jenny.rosen@example.com");
+    params.return_url = Some("https://example.com/return");
+    params.setup_future_usage = Some(PaymentIntentSetupFutureUsage::OffSession);
+    params.shipping = Some(ChargeShippingParams::new("Jenny Rosen", address()));
+    params.statement_descriptor = Some("EXAMPLE SHOP");
+    params.transfer_data = Some(TransferDataParams {
+        amount: Some(1_877),
+        destination: "acct_1032D82eZvKYlo2C".into(),
+    });
+    params.transfer_group = Some("ORDER_6735");
+    assert_snapshot!("create_payment_intent", to_form_body(&params).unwrap());
+
+    // The destination of a transfer can't be changed once the PaymentIntent is created.
+    let mut params = PaymentIntentUpdateParams::default();
+    params.amount = Some(2500);
+    params.application_fee_amount = Some(150);
+    params.transfer_data = Some(UpdatePaymentIntentTransferData { amount: Some(2_350) });
+    assert_snapshot!("update_payment_intent", to_form_body(&params).unwrap());
+
+    let starting_after = "pi_1MiN3hLkdIwHu7ixcG4dVw7d".parse().unwrap();
+    let mut params = ListPaymentIntents::new();
+    params.created = Some(created());
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.limit = Some(20);
+    params.starting_after = Some(&starting_after);
+    assert_snapshot!("list_payment_intents", to_form_body(&params).unwrap());
+}
+
+#[test]
+fn payment_method() {
+    use stripe::{
+        BillingDetails, CreatePaymentMethod, CreatePaymentMethodDomain,
+        CreatePaymentMethodSepaDebit, ListPaymentMethodDomains, ListPaymentMethods,
+        PaymentMethodType, PaymentMethodTypeFilter, UpdatePaymentMethod, UpdatePaymentMethodDomain,
+    };
+
+    let billing_details = BillingDetails {
+        address: Some(address()),
+        email: Some("jenny.rosen@example.com".into()),
+        name: Some("Jenny Rosen".into()),
+        phone: None,
+    };
+
+    let mut params = CreatePaymentMethod::new();
+    params.billing_details = Some(billing_details.clone());
+    params.metadata = Some(metadata());
+    params.sepa_debit =
+        Some(CreatePaymentMethodSepaDebit { iban: "FR1420041010050500013M02606".into() });
+    params.type_ = Some(PaymentMethodType::SepaDebit);
+    assert_snapshot!("create_payment_method", to_form_body(&params).unwrap());
+
+    let mut params = UpdatePaymentMethod::new();
+    params.billing_details = Some(billing_details);
+    params.metadata = Some(metadata());
+    assert_snapshot!("update_payment_method", to_form_body(&params).unwrap());
+
+    let mut params = ListPaymentMethods::new(
+        "cus_4QFJOjw2pOmAGJ".parse().unwrap(),
+        PaymentMethodTypeFilter::SepaDebit,
+    );
+    params.limit = Some(3);
+    assert_snapshot!("list_payment_methods", to_form_body(&params).unwrap());
+
+    let mut params = CreatePaymentMethodDomain::new("shop.example.com");
+    params.enabled = Some(true);
+    assert_snapshot!("create_payment_method_domain", to_form_body(&params).unwrap());
+
+    let mut params = UpdatePaymentMethodDomain::new();
+    params.enabled = Some(false);
+    assert_snapshot!("update_payment_method_domain", to_form_body(&params).unwrap());
+
+    let mut params = ListPaymentMethodDomains::new();
+    params.domain_name = Some("shop.example.com");
+    params.enabled = Some(true);
+    assert_snapshot!("list_payment_method_domains", to_form_body(&params).unwrap());
+}
+
+#[test]
+fn payout() {
+    use stripe::{CreatePayout, ListPayouts, PayoutMethod, PayoutSourceType, UpdatePayout};
+
+    let mut params = CreatePayout::new(5000, Currency::EUR);
+    params.description = Some("Weekly payout");
+    params.destination = Some("ba_1MiN3gLkdIwHu7ixQ8B3mBrH".parse().unwrap());
+    params.metadata = Some(metadata());
+    params.method = Some(PayoutMethod::Instant);
+    params.source_type = Some(PayoutSourceType::Card);
+    params.statement_descriptor = Some("EXAMPLE PAYOUT");
+    assert_snapshot!("create_payout", to_form_body(&params).unwrap());
+
+    let mut params = UpdatePayout::new();
+    params.metadata = Some(metadata());
+    assert_snapshot!("update_payout", to_form_body(&params).unwrap());
+
+    let mut params = ListPayouts::new();
+    params.arrival_date = Some(RangeQuery::lt(1_675_209_600));
+    params.created = Some(created());
+    params.status = Some("paid");
+    assert_snapshot!("list_payouts", to_form_body(&params).unwrap());
+}
+
+#[test]
+fn product() {
+    use stripe::{
+        CreateProduct, CreateProductDefaultPriceData, CreateProductDefaultPriceDataRecurring,
+        CreateProductDefaultPriceDataRecurringInterval, ListProducts, ProductType, UpdateProduct,
+    };
+
+    let mut params = CreateProduct::new("Gold plan");
+    params.active = Some(true);
+    params.default_price_data = Some(CreateProductDefaultPriceData {
+        currency: Currency::EUR,
+        recurring: Some(CreateProductDefaultPriceDataRecurring {
+            interval: CreateProductDefaultPriceDataRecurringInterval::Month,
+            interval_count: Some(3),
+        }),
+        tax_behavior: None,
+        unit_amount: Some(1500),
+        unit_amount_decimal: None,
+    });
+    params.description = Some("Our best plan");
+    params.id = Some("gold");
+    params.images = Some(vec!["https://example.com/gold.png".into()]);
+    params.metadata = Some(metadata());
+    params.shippable = Some(false);
+    params.statement_descriptor = Some("GOLD PLAN");
+    params.tax_code = Some("txcd_10000000".parse().unwrap());
+    params.type_ = Some(ProductType::Service);
+    params.unit_label = Some("seat");
+    params.url = Some("https://example.com/gold");
+    assert_snapshot!("create_product", to_form_body(&params).unwrap());
+
+    let mut params = UpdateProduct::new();
+    params.default_price = Some("price_1MiN3gLkdIwHu7ixQaR8Pm1a".parse().unwrap());
+    params.images = Some(vec!["https://example.com/gold.png".into()]);
+    params.metadata = Some(metadata());
+    params.name = Some("Gold plan (2023)");
+    assert_snapshot!("update_product", to_form_body(&params).unwrap());
+
+    let mut params = ListProducts::new();
+    params.active = Some(true);
+    params.created = Some(created());
+    params.ids = Some(vec!["gold".into(), "silver".into()]);
+    params.type_ = Some(ProductType::Service);
+    assert_snapshot!("list_products", to_form_body(&params).unwrap());
+}
+
+#[test]
+fn refund() {
+    use stripe::{CreateRefund, ListRefunds, RefundReason, UpdateRefund};
+
+    let mut params = CreateRefund::new();
+    params.amount = Some(500);
+    params.charge = Some("ch_1MiN3gLkdIwHu7ixUiHUQ7nz".parse().unwrap());
+    params.metadata = Some(metadata());
+    params.reason = Some(RefundReason::RequestedByCustomer);
+    params.refund_application_fee = Some(true);
+    params.reverse_transfer = Some(true);
+    assert_snapshot!("create_refund", to_form_body(&params).unwrap());
+
+    let mut params = UpdateRefund::new();
+    params.metadata = Some(metadata());
+    assert_snapshot!("update_refund", to_form_body(&params).unwrap());
+
+    let mut params = ListRefunds::new();
+    params.created = Some(created());
+    params.payment_intent = Some("pi_1MiN3hLkdIwHu7ixcG4dVw7d".parse().unwrap());
+    assert_snapshot!("list_refunds", to_form_body(&params).unwrap());
+}
+
+#[test]
+fn setup_intent() {
+    use stripe::{
+        CreateSetupIntent, CreateSetupIntentMandateData,
+        CreateSetupIntentMandateDataCustomerAcceptance,
+        CreateSetupIntentMandateDataCustomerAcceptanceOnline,
+        CreateSetupIntentMandateDataCustomerAcceptanceType, CreateSetupIntentPaymentMethodOptions,
+        CreateSetupIntentPaymentMethodOptionsCard, CreateSetupIntentSingleUse, ListSetupIntents,
+        UpdateSetupIntent, UpdateSetupIntentPaymentMethodOptions,
+        UpdateSetupIntentPaymentMethodOptionsCard,
+    };
+
+    let mut params = CreateSetupIntent::new();
+    params.confirm = Some(true);
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.mandate_data = Some(CreateSetupIntentMandateData {
+        customer_acceptance: CreateSetupIntentMandateDataCustomerAcceptance {
+            accepted_at: Some(1_672_531_200),
+            offline: None,
+            online: Some(CreateSetupIntentMandateDataCustomerAcceptanceOnline {
+                ip_address: "203.0.113.7".into(),
+                user_agent: "Mozilla/5.0".into(),
+            }),
+            type_: CreateSetupIntentMandateDataCustomerAcceptanceType::Online,
+        },
+    });
+    params.metadata = Some(metadata());
+    params.payment_method = Some("pm_1MiN3hLkdIwHu7ixc7bN2gUf".parse().unwrap());
+    params.payment_method_options = Some(CreateSetupIntentPaymentMethodOptions {
+        card: Some(CreateSetupIntentPaymentMethodOptionsCard {
+            mandate_options: None,
+            network: Some("visa".into()),
+            request_three_d_secure: None,
+        }),
+        ..Default::default()
+    });
+    params.payment_method_types = Some(vec!["card".into(), "sepa_debit".into()]);
+    params.return_url = Some("https://example.com/return");
+    params.single_use = Some(CreateSetupIntentSingleUse { amount: 2000, currency: Currency::EUR });
+    assert_snapshot!("create_setup_intent", to_form_body(&params).unwrap());
+
+    let mut params = UpdateSetupIntent::new();
+    params.description = Some("Card for Gold plan");
+    params.metadata = Some(metadata());
+    params.payment_method_options = Some(UpdateSetupIntentPaymentMethodOptions {
+        card: Some(UpdateSetupIntentPaymentMethodOptionsCard {
+            mandate_options: None,
+            network: Some("cartes_bancaires".into()),
+            request_three_d_secure: None,
+        }),
+        ..Default::default()
+    });
+    assert_snapshot!("update_setup_intent", to_form_body(&params).unwrap());
+
+    let mut params = ListSetupIntents::new();
+    params.created = Some(created());
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.payment_method = Some("pm_1MiN3hLkdIwHu7ixc7bN2gUf".parse().unwrap());
+    assert_snapshot!("list_setup_intents", to_form_body(&params).unwrap());
+}
+
+#[test]
+fn source() {
+    use stripe::{
+        BillingDetails, CreateSource, CreateSourceRedirect, CreateSourceSourceOrder,
+        CreateSourceSourceOrderItems, CreateSourceSourceOrderItemsType, SourceFlow, UpdateSource,
+    };
+
+    let owner = BillingDetails {
+        address: Some(address()),
+        email: Some("jenny.rosen@example.com".into()),
+        name: Some("Jenny Rosen".into()),
+        phone: None,
+    };
+
+    let mut params = CreateSource::new();
+    params.amount = Some(2000);
+    params.currency = Some(Currency::EUR);
+    params.flow = Some(SourceFlow::Redirect);
+    params.metadata = Some(metadata());
+    params.owner = Some(owner.clone());
+    params.redirect =
+        Some(CreateSourceRedirect { return_url: "https://example.com/return".into() });
+    params.source_order = Some(CreateSourceSourceOrder {
+        items: Some(vec![CreateSourceSourceOrderItems {
+            amount: Some(2000),
+            currency: Some(Currency::EUR),
+            description: Some("Gold plan".into()),
+            parent: None,
+            quantity: Some(1),
+            type_: Some(CreateSourceSourceOrderItemsType::Sku),
+        }]),
+        shipping: None,
+    });
+    params.statement_descriptor = Some("EXAMPLE SHOP");
+    params.type_ = Some("ideal");
+    assert_snapshot!("create_source", to_form_body(&params).unwrap());
+
+    let mut params = UpdateSource::new();
+    params.amount = Some(2500);
+    params.metadata = Some(metadata());
+    params.owner = Some(owner);
+    assert_snapshot!("update_source", to_form_body(&params).unwrap());
+}
+
+#[test]
+fn tax_calculation() {
+    use stripe::{
+        CreateTaxCalculation, CreateTaxCalculationCustomerDetails,
+        CreateTaxCalculationCustomerDetailsAddress,
+        CreateTaxCalculationCustomerDetailsAddressSource, CreateTaxCalculationLineItems,
+        TaxCalculationLineItemTaxBehavior,
+    };
+
+    let line_items = vec![CreateTaxCalculationLineItems {
+        amount: 1499,
+        quantity: Some(1),
+        reference: Some("L1".into()),
+        tax_behavior: Some(TaxCalculationLineItemTaxBehavior::Exclusive),
+        tax_code: Some("txcd_99999999".into()),
+        ..Default::default()
+    }];
+    let mut params = CreateTaxCalculation::new(Currency::USD, line_items);
+    params.customer_details = Some(CreateTaxCalculationCustomerDetails {
+        address: Some(CreateTaxCalculationCustomerDetailsAddress {
+            country: "US".into(),
+            postal_code: Some("10001".into()),
+            state: Some("NY".into()),
+            ..Default::default()
+        }),
+        address_source: Some(CreateTaxCalculationCustomerDetailsAddressSource::Shipping),
+        ip_address: None,
+    });
+    params.expand = &["line_items"];
+    assert_snapshot!("create_tax_calculation", to_form_body(&params).unwrap());
+}
+
+#[test]
+fn tax_code() {
+    use stripe::ListTaxCodes;
+
+    let mut params = ListTaxCodes::new();
+    params.limit = Some(50);
+    params.starting_after = Some("txcd_10000000".parse().unwrap());
+    assert_snapshot!("list_tax_codes", to_form_body(&params).unwrap());
+}
+
+#[test]
+fn token() {
+    use stripe::{CreateToken, CreateTokenPii};
+
+    let mut params = CreateToken::new();
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.pii = Some(CreateTokenPii { id_number: Some("000000000".into()) });
+    assert_snapshot!("create_token", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "checkout")]
+fn checkout_session() {
+    use stripe::{
+        CheckoutSessionCustomText, CheckoutSessionMode, CreateCheckoutSession,
+        CreateCheckoutSessionLineItems, CustomTextPosition,
+    };
+
+    let mut params = CreateCheckoutSession::new("https://example.com/success");
+    params.cancel_url = Some("https://example.com/cancel");
+    params.custom_text = Some(CheckoutSessionCustomText {
+        shipping_address: Some(CustomTextPosition {
+            message: "Your item will ship in 5-7 days.".into(),
+        }),
+        submit: None,
+        terms_of_service_acceptance: Some(CustomTextPosition {
+            message: "By purchasing you agree to our terms.".into(),
+        }),
+    });
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.line_items = Some(vec![CreateCheckoutSessionLineItems {
+        price: "price_1MiN3hLkdIwHu7ixQvJ2x1Yd".parse().unwrap(),
+        quantity: 1,
+    }]);
+    params.mode = Some(CheckoutSessionMode::Payment);
+    assert_snapshot!("create_checkout_session", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "checkout")]
+fn payment_link() {
+    use stripe::{
+        CreatePaymentLink, CreatePaymentLinkCustomFields, CreatePaymentLinkCustomFieldsDropdown,
+        CreatePaymentLinkCustomFieldsDropdownOptions, CreatePaymentLinkCustomFieldsLabel,
+        CreatePaymentLinkLineItems, CustomFieldLabelType, CustomFieldType,
+    };
+
+    let label = |custom: &str| CreatePaymentLinkCustomFieldsLabel {
+        custom: custom.into(),
+        type_: CustomFieldLabelType::Custom,
+    };
+    let option = |label: &str, value: &str| CreatePaymentLinkCustomFieldsDropdownOptions {
+        label: label.into(),
+        value: value.into(),
+    };
+    let mut params = CreatePaymentLink::new(vec![CreatePaymentLinkLineItems {
+        price: "price_1MiN3hLkdIwHu7ixQvJ2x1Yd".parse().unwrap(),
+        quantity: 2,
+    }]);
+    params.allow_promotion_codes = Some(true);
+    params.custom_fields = Some(vec![
+        CreatePaymentLinkCustomFields {
+            dropdown: Some(CreatePaymentLinkCustomFieldsDropdown {
+                options: vec![option("Small", "s"), option("Medium", "m")],
+            }),
+            key: "size".into(),
+            label: label("T-shirt size"),
+            optional: None,
+            type_: CustomFieldType::Dropdown,
+        },
+        CreatePaymentLinkCustomFields {
+            dropdown: None,
+            key: "referral".into(),
+            label: label("Referral code"),
+            optional: Some(true),
+            type_: CustomFieldType::Text,
+        },
+    ]);
+    params.metadata = Some(metadata());
+    assert_snapshot!("create_payment_link", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "billing")]
+fn billing_portal_session() {
+    use stripe::{
+        CreateBillingPortalSession, PortalFlowAfterCompletion, PortalFlowAfterCompletionRedirect,
+        PortalFlowAfterCompletionType, PortalFlowData, PortalFlowSubscriptionCancel,
+        PortalFlowType,
+    };
+
+    let mut flow_data = PortalFlowData::new(PortalFlowType::SubscriptionCancel);
+    flow_data.after_completion = Some(PortalFlowAfterCompletion {
+        hosted_confirmation: None,
+        redirect: Some(PortalFlowAfterCompletionRedirect {
+            return_url: "https://example.com/account".into(),
+        }),
+        type_: PortalFlowAfterCompletionType::Redirect,
+    });
+    flow_data.subscription_cancel =
+        Some(PortalFlowSubscriptionCancel { subscription: "sub_1MiN3hLkdIwHu7ixzHtqI7bV".into() });
+    let mut params = CreateBillingPortalSession::new("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.flow_data = Some(flow_data);
+    params.locale = Some("fr");
+    params.return_url = Some("https://example.com/account");
+    assert_snapshot!("create_billing_portal_session", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "billing")]
+fn coupon() {
+    use std::collections::HashMap;
+    use stripe::{
+        CouponDuration, CreateCoupon, CreateCouponCurrencyOptions, ListCoupons, UpdateCoupon,
+        UpdateCouponCurrencyOptions,
+    };
+
+    let mut params = CreateCoupon::new(CouponDuration::Repeating);
+    params.amount_off = Some(500);
+    params.currency = Some(Currency::EUR);
+    params.currency_options =
+        Some(HashMap::from([(Currency::USD, CreateCouponCurrencyOptions { amount_off: 550 })]));
+    params.duration_in_months = Some(3);
+    params.id = Some("5OFF");
+    params.max_redemptions = Some(100);
+    params.metadata = Some(metadata());
+    params.name = Some("5€ off");
+    params.redeem_by = Some(1_675_209_600);
+    assert_snapshot!("create_coupon", to_form_body(&params).unwrap());
+
+    let mut params = UpdateCoupon::new();
+    params.currency_options =
+        Some(HashMap::from([(Currency::USD, UpdateCouponCurrencyOptions { amount_off: 600 })]));
+    params.metadata = Some(metadata());
+    params.name = Some("5€ off (2023)");
+    assert_snapshot!("update_coupon", to_form_body(&params).unwrap());
+
+    let mut params = ListCoupons::new();
+    params.created = Some(created());
+    params.limit = Some(10);
+    assert_snapshot!("list_coupons", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "billing")]
+fn invoice() {
+    use stripe::{
+        CollectionMethod, CreateInvoice, CreateInvoiceAutomaticTax, CreateInvoiceDiscounts,
+        CreateInvoicePaymentSettings, CreateInvoiceRendering, CreateInvoiceRenderingPdf,
+        CustomField, FromInvoice, FromInvoiceAction, InvoicePendingInvoiceItemsBehavior,
+        InvoiceRenderingAmountTaxDisplay, InvoiceRenderingPdfPageSize, InvoiceStatusFilter,
+        ListInvoices, SubscriptionPaymentMethodType, UpdateInvoice, UpdateInvoiceAutomaticTax,
+        UpdateInvoiceDiscounts,
+    };
+
+    let mut params = CreateInvoice::new("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.auto_advance = Some(false);
+    params.automatic_tax = Some(CreateInvoiceAutomaticTax { enabled: true });
+    params.collection_method = Some(CollectionMethod::SendInvoice);
+    params.currency = Some(Currency::EUR);
+    params.custom_fields =
+        Some(vec![CustomField { name: "PO number".into(), value: "PO-6735".into() }]);
+    params.days_until_due = Some(30);
+    params.default_tax_rates = Some(vec!["txr_1MiN3gLkdIwHu7ixL5M5aMyQ".into()]);
+    params.description = Some("Consulting, January");
+    params.discounts = Some(vec![
+        CreateInvoiceDiscounts { coupon: Some("5OFF".into()), ..Default::default() },
+        CreateInvoiceDiscounts {
+            promotion_code: Some("promo_1MiN3gLkdIwHu7ixWRLPFkmP".into()),
+            ..Default::default()
+        },
+    ]);
+    params.footer = Some("Thank you for your business");
+    params.metadata = Some(metadata());
+    params.on_behalf_of = Some("acct_1032D82eZvKYlo2C");
+    params.payment_settings = Some(CreateInvoicePaymentSettings {
+        payment_method_types: Some(vec![
+            SubscriptionPaymentMethodType::Card,
+            SubscriptionPaymentMethodType::SepaDebit,
+        ]),
+        ..Default::default()
+    });
+    params.pending_invoice_items_behavior = Some(InvoicePendingInvoiceItemsBehavior::Exclude);
+    params.rendering = Some(CreateInvoiceRendering {
+        amount_tax_display: Some(InvoiceRenderingAmountTaxDisplay::ExcludeTax),
+        pdf: Some(CreateInvoiceRenderingPdf { page_size: Some(InvoiceRenderingPdfPageSize::A4) }),
+    });
+    params.subscription = Some("sub_1MiN3hLkdIwHu7ixzHtqI7bV".parse().unwrap());
+    assert_snapshot!("create_invoice", to_form_body(&params).unwrap());
+
+    // A revision starts out as a draft that isn't finalized until it has been reviewed.
+    let mut params = CreateInvoice::new("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.auto_advance = Some(false);
+    params.from_invoice = Some(FromInvoice {
+        action: FromInvoiceAction::Revision,
+        invoice: "in_1MiN3hLkdIwHu7ixWRLPFkmP".parse().unwrap(),
+    });
+    assert_snapshot!("create_invoice_revision", to_form_body(&params).unwrap());
+
+    let mut params = UpdateInvoice::new();
+    params.automatic_tax = Some(UpdateInvoiceAutomaticTax { enabled: false });
+    params.discounts = Some(vec![UpdateInvoiceDiscounts {
+        discount: Some("di_1MiN3gLkdIwHu7ixhDqdXH8b".into()),
+        ..Default::default()
+    }]);
+    params.due_date = Some(1_675_209_600);
+    params.metadata = Some(metadata());
+    params.statement_descriptor = Some("CONSULTING JAN");
+    assert_snapshot!("update_invoice", to_form_body(&params).unwrap());
+
+    let mut params = ListInvoices::new();
+    params.collection_method = Some(CollectionMethod::ChargeAutomatically);
+    params.created = Some(created());
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.due_date = Some(RangeQuery::lte(1_675_209_600));
+    params.status = Some(InvoiceStatusFilter::Open);
+    params.subscription = Some("sub_1MiN3hLkdIwHu7ixzHtqI7bV".parse().unwrap());
+    assert_snapshot!("list_invoices", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "billing")]
+fn invoice_item() {
+    use stripe::{
+        CreateInvoiceItem, CreateInvoiceItemDiscounts, CreateInvoiceLineItem, ListInvoiceItems,
+        Period, UpdateInvoiceItem, UpdateInvoiceItemDiscounts,
+    };
+
+    let period = Period { start: 1_672_531_200, end: 1_675_209_600 };
+
+    let mut params = CreateInvoiceItem::new("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.currency = Some(Currency::EUR);
+    params.description = Some("Consulting hours");
+    params.discountable = Some(true);
+    params.discounts = Some(vec![CreateInvoiceItemDiscounts {
+        coupon: Some("5OFF".into()),
+        ..Default::default()
+    }]);
+    params.invoice = Some("in_1MiN3gLkdIwHu7ixU2YRO9wq".parse().unwrap());
+    params.metadata = Some(metadata());
+    params.period = Some(period.clone());
+    params.quantity = Some(12);
+    params.tax_rates = Some(vec!["txr_1MiN3gLkdIwHu7ixL5M5aMyQ".into()]);
+    params.unit_amount_decimal = Some("1250.5");
+    assert_snapshot!("create_invoice_item", to_form_body(&params).unwrap());
+
+    let mut params = UpdateInvoiceItem::new();
+    params.description = Some("Consulting hours (revised)");
+    params.discounts = Some(vec![UpdateInvoiceItemDiscounts {
+        promotion_code: Some("promo_1MiN3gLkdIwHu7ixWRLPFkmP".into()),
+        ..Default::default()
+    }]);
+    params.period = Some(period);
+    params.quantity = Some(10);
+    params.unit_amount = Some(12_500);
+    assert_snapshot!("update_invoice_item", to_form_body(&params).unwrap());
+
+    let mut params = ListInvoiceItems::new();
+    params.created = Some(created());
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.pending = Some(true);
+    assert_snapshot!("list_invoice_items", to_form_body(&params).unwrap());
+
+    let mut params = CreateInvoiceLineItem::new();
+    params.amount = Some(1000);
+    params.currency = Some(Currency::EUR);
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.description = Some("Setup fee");
+    params.discountable = Some(false);
+    params.invoice = Some("in_1MiN3gLkdIwHu7ixU2YRO9wq".parse().unwrap());
+    assert_snapshot!("create_invoice_line_item", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "billing")]
+fn plan() {
+    use stripe::{
+        CreatePlan, CreatePlanTiers, CreatePlanTransformUsage, CreatePlanTransformUsageRound,
+        IdOrCreate, ListPlans, PlanAggregateUsage, PlanBillingScheme, PlanInterval, PlanTiersMode,
+        PlanUsageType, UpTo, UpToOther, UpdatePlan,
+    };
+
+    let mut params = CreatePlan::new(Currency::EUR, PlanInterval::Month);
+    params.active = Some(true);
+    params.aggregate_usage = Some(PlanAggregateUsage::Sum);
+    params.billing_scheme = Some(PlanBillingScheme::Tiered);
+    params.id = Some("api-calls");
+    params.interval_count = Some(1);
+    params.metadata = Some(metadata());
+    params.nickname = Some("API calls");
+    params.product = Some(IdOrCreate::Id("prod_NTLHY2kmqUIQyp"));
+    params.tiers = Some(vec![
+        CreatePlanTiers {
+            flat_amount: Some(0),
+            flat_amount_decimal: None,
+            unit_amount: Some(5),
+            unit_amount_decimal: None,
+            up_to: Some(UpTo::max(1000)),
+        },
+        CreatePlanTiers {
+            flat_amount: None,
+            flat_amount_decimal: None,
+            unit_amount: None,
+            unit_amount_decimal: Some("2.5".into()),
+            up_to: Some(UpTo::Other(UpToOther::Inf)),
+        },
+    ]);
+    params.tiers_mode = Some(PlanTiersMode::Graduated);
+    params.transform_usage =
+        Some(CreatePlanTransformUsage { divide_by: 100, round: CreatePlanTransformUsageRound::Up });
+    params.usage_type = Some(PlanUsageType::Metered);
+    assert_snapshot!("create_plan", to_form_body(&params).unwrap());
+
+    let mut params = UpdatePlan::new();
+    params.active = Some(false);
+    params.metadata = Some(metadata());
+    params.nickname = Some("API calls (legacy)");
+    params.trial_period_days = Some(14);
+    assert_snapshot!("update_plan", to_form_body(&params).unwrap());
+
+    let mut params = ListPlans::new();
+    params.active = Some(true);
+    params.created = Some(created());
+    params.product = Some(IdOrCreate::Id("prod_NTLHY2kmqUIQyp"));
+    assert_snapshot!("list_plans", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "billing")]
+fn price() {
+    use std::collections::HashMap;
+    use stripe::{
+        CreatePrice, CreatePriceCurrencyOptions, CreatePriceProductData, CreatePriceRecurring,
+        CreatePriceRecurringInterval, CreatePriceRecurringUsageType, CreatePriceTransformQuantity,
+        CreatePriceTransformQuantityRound, ListPrices, ListPricesRecurring,
+        ListPricesRecurringInterval, PriceTaxBehavior, PriceType, UpdatePrice,
+        UpdatePriceRecurring,
+    };
+
+    let currency_options = HashMap::from([(
+        Currency::USD,
+        CreatePriceCurrencyOptions {
+            tax_behavior: Some(PriceTaxBehavior::Exclusive),
+            tiers: None,
+            unit_amount: Some(1600),
+            unit_amount_decimal: None,
+        },
+    )]);
+
+    let mut params = CreatePrice::new(Currency::EUR);
+    params.active = Some(true);
+    params.currency_options = Some(currency_options.clone());
+    params.lookup_key = Some("gold_monthly");
+    params.metadata = Some(metadata());
+    params.nickname = Some("Gold monthly");
+    params.product_data = Some(CreatePriceProductData {
+        active: Some(true),
+        id: Some("gold".into()),
+        metadata: metadata(),
+        name: "Gold plan".into(),
+        statement_descriptor: Some("GOLD PLAN".into()),
+        unit_label: Some("seat".into()),
+    });
+    params.recurring = Some(CreatePriceRecurring {
+        aggregate_usage: None,
+        interval: CreatePriceRecurringInterval::Month,
+        interval_count: Some(1),
+        trial_period_days: Some(14),
+        usage_type: Some(CreatePriceRecurringUsageType::Licensed),
+    });
+    params.tax_behavior = Some(PriceTaxBehavior::Inclusive);
+    params.transfer_lookup_key = Some(true);
+    params.transform_quantity = Some(CreatePriceTransformQuantity {
+        divide_by: 10,
+        round: CreatePriceTransformQuantityRound::Up,
+    });
+    params.unit_amount = Some(1500);
+    assert_snapshot!("create_price", to_form_body(&params).unwrap());
+
+    let mut params = UpdatePrice::new();
+    params.active = Some(false);
+    params.currency_options = Some(currency_options);
+    params.lookup_key = Some("gold_monthly_2023");
+    params.metadata = Some(metadata());
+    params.recurring = Some(UpdatePriceRecurring { trial_period_days: Some(7) });
+    params.tax_behavior = Some(PriceTaxBehavior::Exclusive);
+    assert_snapshot!("update_price", to_form_body(&params).unwrap());
+
+    let mut params = ListPrices::new();
+    params.active = Some(true);
+    params.created = Some(created());
+    params.currency = Some(Currency::EUR);
+    params.lookup_keys = Some(vec!["gold_monthly".into(), "gold_yearly".into()]);
+    params.product = Some(stripe::IdOrCreate::Id("prod_NTLHY2kmqUIQyp"));
+    params.recurring = Some(ListPricesRecurring {
+        interval: Some(ListPricesRecurringInterval::Year),
+        usage_type: None,
+    });
+    params.type_ = Some(PriceType::Recurring);
+    assert_snapshot!("list_prices", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "billing")]
+fn promotion_code() {
+    use stripe::ListPromotionCodes;
+
+    let mut params = ListPromotionCodes::new();
+    params.active = Some(true);
+    params.code = Some("SPRING23");
+    params.coupon = Some("5OFF".parse().unwrap());
+    params.created = Some(created());
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    assert_snapshot!("list_promotion_codes", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "billing")]
+fn quote() {
+    use stripe::{
+        CreateQuote, ListQuotes, QuoteEffectiveDate, QuoteLineItemParams, QuoteStatus,
+        QuoteSubscriptionDataParams, UpdateQuote,
+    };
+
+    let mut params = CreateQuote::new();
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.expires_at = Some(1_675_209_600);
+    params.header = Some("Annual plan");
+    params.line_items = Some(vec![QuoteLineItemParams {
+        price: Some("price_1MiN3gLkdIwHu7ixQaR8Pm1a".parse().unwrap()),
+        quantity: Some(2),
+    }]);
+    params.subscription_data = Some(QuoteSubscriptionDataParams {
+        description: Some("Annual plan".into()),
+        effective_date: Some(QuoteEffectiveDate::at(1_672_531_200)),
+        metadata: Some(metadata()),
+        trial_period_days: Some(14),
+    });
+    assert_snapshot!("create_quote", to_form_body(&params).unwrap());
+
+    let mut params = UpdateQuote::new();
+    params.footer = Some("Thank you");
+    params.subscription_data = Some(QuoteSubscriptionDataParams {
+        effective_date: Some(QuoteEffectiveDate::current_period_end()),
+        ..Default::default()
+    });
+    assert_snapshot!("update_quote", to_form_body(&params).unwrap());
+
+    let mut params = ListQuotes::new();
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.status = Some(QuoteStatus::Open);
+    assert_snapshot!("list_quotes", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "billing")]
+fn subscription() {
+    use stripe::{
+        CollectionMethod, CreateSubscription, CreateSubscriptionItems,
+        CreateSubscriptionItemsBillingThresholds, CreateSubscriptionPendingInvoiceItemInterval,
+        ListSubscriptions, PlanInterval, Scheduled, SubscriptionBillingCycleAnchor,
+        SubscriptionBillingThresholds, SubscriptionPaymentMethodType,
+        SubscriptionPaymentSettingsParams, SubscriptionSaveDefaultPaymentMethod,
+        SubscriptionStatusFilter, SubscriptionTrialSettings, SubscriptionTrialSettingsEndBehavior,
+        SubscriptionTrialSettingsEndBehaviorMissingPaymentMethod, UpdateSubscription,
+        UpdateSubscriptionItems, UpdateSubscriptionPauseCollection,
+        UpdateSubscriptionPauseCollectionBehavior, UpdateSubscriptionPendingInvoiceItemInterval,
+    };
+
+    let trial_settings = SubscriptionTrialSettings {
+        end_behavior: SubscriptionTrialSettingsEndBehavior {
+            missing_payment_method: SubscriptionTrialSettingsEndBehaviorMissingPaymentMethod::Pause,
+        },
+    };
+
+    let mut params = CreateSubscription::new("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.billing_thresholds = Some(SubscriptionBillingThresholds {
+        amount_gte: Some(10_000),
+        reset_billing_cycle_anchor: Some(true),
+    });
+    params.cancel_at_period_end = Some(false);
+    params.collection_method = Some(CollectionMethod::ChargeAutomatically);
+    params.coupon = Some("5OFF".parse().unwrap());
+    params.default_payment_method = Some("pm_1MiN3hLkdIwHu7ixc7bN2gUf");
+    params.default_tax_rates =
+        Some(vec!["txr_1MiN3gLkdIwHu7ixL5M5aMyQ".into(), "txr_1MiN3hLkdIwHu7ixD9p4jK2a".into()]);
+    params.expand = &["latest_invoice.payment_intent"];
+    params.items = Some(vec![
+        CreateSubscriptionItems {
+            billing_thresholds: None,
+            metadata: metadata(),
+            plan: None,
+            price: Some("price_1MiN3gLkdIwHu7ixQaR8Pm1a".into()),
+            price_data: None,
+            quantity: Some(3),
+            tax_rates: None,
+        },
+        CreateSubscriptionItems {
+            billing_thresholds: Some(CreateSubscriptionItemsBillingThresholds { usage_gte: 5000 }),
+            metadata: Default::default(),
+            plan: None,
+            price: Some("price_1MiN3hLkdIwHu7ixkAaR3Jzd".into()),
+            price_data: None,
+            quantity: None,
+            tax_rates: Some(vec!["txr_1MiN3gLkdIwHu7ixL5M5aMyQ".into()]),
+        },
+    ]);
+    params.metadata = Some(metadata());
+    params.payment_behavior = Some(wire("default_incomplete"));
+    params.payment_settings = Some(SubscriptionPaymentSettingsParams {
+        payment_method_options: None,
+        payment_method_types: Some(vec![
+            SubscriptionPaymentMethodType::Card,
+            SubscriptionPaymentMethodType::SepaDebit,
+        ]),
+        save_default_payment_method: Some(SubscriptionSaveDefaultPaymentMethod::OnSubscription),
+    });
+    params.pending_invoice_item_interval = Some(CreateSubscriptionPendingInvoiceItemInterval {
+        interval: PlanInterval::Week,
+        interval_count: Some(2),
+    });
+    params.proration_behavior = Some(wire("create_prorations"));
+    params.trial_end = Some(Scheduled::at(1_675_209_600));
+    params.trial_settings = Some(trial_settings.clone());
+    assert_snapshot!("create_subscription", to_form_body(&params).unwrap());
+
+    let mut params = UpdateSubscription::new();
+    params.billing_cycle_anchor = Some(SubscriptionBillingCycleAnchor::Now);
+    params.cancel_at = Some(1_704_067_200);
+    params.items = Some(vec![
+        UpdateSubscriptionItems {
+            billing_thresholds: None,
+            clear_usage: None,
+            deleted: None,
+            id: Some("si_NTLHfIyRFzrgvF".into()),
+            metadata: Default::default(),
+            plan: None,
+            price: None,
+            price_data: None,
+            quantity: Some(5),
+            tax_rates: None,
+        },
+        UpdateSubscriptionItems {
+            billing_thresholds: None,
+            clear_usage: Some(true),
+            deleted: Some(true),
+            id: Some("si_NTLHUx0aYyR8M0".into()),
+            metadata: Default::default(),
+            plan: None,
+            price: None,
+            price_data: None,
+            quantity: None,
+            tax_rates: None,
+        },
+    ]);
+    params.metadata = Some(metadata());
+    params.pause_collection = Some(UpdateSubscriptionPauseCollection {
+        behavior: UpdateSubscriptionPauseCollectionBehavior::KeepAsDraft,
+        resumes_at: Some(1_677_628_800),
+    });
+    params.pending_invoice_item_interval = Some(UpdateSubscriptionPendingInvoiceItemInterval {
+        interval: PlanInterval::Month,
+        interval_count: Some(1),
+    });
+    params.proration_behavior = Some(wire("always_invoice"));
+    params.proration_date = Some(1_672_531_200);
+    params.trial_end = Some(Scheduled::now());
+    params.trial_settings = Some(trial_settings);
+    assert_snapshot!("update_subscription", to_form_body(&params).unwrap());
+
+    let mut params = ListSubscriptions::new();
+    params.collection_method = Some(CollectionMethod::SendInvoice);
+    params.created = Some(created());
+    params.current_period_end = Some(RangeQuery::gt(1_672_531_200));
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.price = Some("price_1MiN3gLkdIwHu7ixQaR8Pm1a".parse().unwrap());
+    params.status = Some(SubscriptionStatusFilter::All);
+    assert_snapshot!("list_subscriptions", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "billing")]
+fn subscription_item() {
+    use stripe::{
+        CreateSubscriptionItem, ListSubscriptionItems, ListUsageRecordSummaries,
+        SubscriptionItemBillingThresholds, UpdateSubscriptionItem,
+    };
+
+    let mut params = CreateSubscriptionItem::new("sub_1MiN3hLkdIwHu7ixzHtqI7bV".parse().unwrap());
+    params.billing_thresholds = Some(SubscriptionItemBillingThresholds { usage_gte: 5000 });
+    params.metadata = Some(metadata());
+    params.payment_behavior = Some(wire("error_if_incomplete"));
+    params.price = Some("price_1MiN3gLkdIwHu7ixQaR8Pm1a".parse().unwrap());
+    params.proration_behavior = Some(wire("none"));
+    params.quantity = Some(2);
+    params.tax_rates = Some(vec!["txr_1MiN3gLkdIwHu7ixL5M5aMyQ".into()]);
+    assert_snapshot!("create_subscription_item", to_form_body(&params).unwrap());
+
+    let mut params = UpdateSubscriptionItem::new();
+    params.metadata = Some(metadata());
+    params.off_session = Some(true);
+    params.proration_date = Some(1_672_531_200);
+    params.quantity = Some(4);
+    assert_snapshot!("update_subscription_item", to_form_body(&params).unwrap());
+
+    let mut params = ListSubscriptionItems::new("sub_1MiN3hLkdIwHu7ixzHtqI7bV".parse().unwrap());
+    params.limit = Some(20);
+    assert_snapshot!("list_subscription_items", to_form_body(&params).unwrap());
+
+    let mut params = ListUsageRecordSummaries::new();
+    params.limit = Some(12);
+    assert_snapshot!("list_usage_record_summaries", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "billing")]
+fn subscription_schedule() {
+    use stripe::{
+        CollectionMethod, CreateSubscriptionSchedule, CreateSubscriptionSchedulePhases,
+        ListSubscriptionSchedules, Scheduled, SubscriptionScheduleDefaultSettingsParams,
+        SubscriptionScheduleEndBehavior, SubscriptionScheduleInvoiceSettings,
+        SubscriptionSchedulePhasesPlansParams, UpdateSubscriptionSchedule,
+        UpdateSubscriptionSchedulePhases,
+    };
+
+    let default_settings = SubscriptionScheduleDefaultSettingsParams {
+        billing_thresholds: None,
+        collection_method: Some(CollectionMethod::SendInvoice),
+        default_payment_method: None,
+        invoice_settings: Some(SubscriptionScheduleInvoiceSettings { days_until_due: Some(30) }),
+    };
+    let plans = vec![SubscriptionSchedulePhasesPlansParams {
+        billing_thresholds: None,
+        plan: None,
+        price: Some("price_1MiN3gLkdIwHu7ixQaR8Pm1a".into()),
+        price_data: None,
+        quantity: Some(2),
+        tax_rates: None,
+    }];
+
+    let mut params = CreateSubscriptionSchedule::new();
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.default_settings = Some(default_settings.clone());
+    params.end_behavior = Some(SubscriptionScheduleEndBehavior::Release);
+    params.metadata = Some(metadata());
+    params.phases = Some(vec![CreateSubscriptionSchedulePhases {
+        add_invoice_items: None,
+        application_fee_percent: None,
+        billing_thresholds: None,
+        collection_method: None,
+        coupon: Some("5OFF".into()),
+        default_payment_method: None,
+        default_tax_rates: Some(vec!["txr_1MiN3gLkdIwHu7ixL5M5aMyQ".into()]),
+        end_date: None,
+        invoice_settings: None,
+        iterations: Some(12),
+        plans: plans.clone(),
+        proration_behavior: None,
+        tax_percent: None,
+        trial: Some(false),
+        trial_end: None,
+    }]);
+    params.start_date = Some(Scheduled::at(1_672_531_200));
+    assert_snapshot!("create_subscription_schedule", to_form_body(&params).unwrap());
+
+    let mut params = UpdateSubscriptionSchedule::new();
+    params.default_settings = Some(default_settings);
+    params.end_behavior = Some(SubscriptionScheduleEndBehavior::Cancel);
+    params.phases = Some(vec![UpdateSubscriptionSchedulePhases {
+        add_invoice_items: None,
+        application_fee_percent: None,
+        billing_thresholds: None,
+        collection_method: None,
+        coupon: None,
+        default_payment_method: Some("pm_1MiN3hLkdIwHu7ixc7bN2gUf".into()),
+        default_tax_rates: None,
+        end_date: Some(Scheduled::at(1_704_067_200)),
+        invoice_settings: None,
+        iterations: None,
+        plans,
+        proration_behavior: Some(wire("none")),
+        start_date: Some(Scheduled::now()),
+        tax_percent: None,
+        trial: None,
+        trial_end: None,
+    }]);
+    params.proration_behavior = Some(wire("create_prorations"));
+    assert_snapshot!("update_subscription_schedule", to_form_body(&params).unwrap());
+
+    let mut params = ListSubscriptionSchedules::new();
+    params.canceled_at = Some(RangeQuery::gte(1_672_531_200));
+    params.created = Some(created());
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.scheduled = Some(true);
+    assert_snapshot!("list_subscription_schedules", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "billing")]
+fn tax_rate() {
+    use stripe::{CreateTaxRate, ListTaxRates, UpdateTaxRate};
+
+    let mut params = CreateTaxRate::new("VAT", 20.0);
+    params.active = Some(true);
+    params.description = Some("French VAT");
+    params.inclusive = true;
+    params.jurisdiction = Some("FR");
+    params.metadata = Some(metadata());
+    assert_snapshot!("create_tax_rate", to_form_body(&params).unwrap());
+
+    let mut params = UpdateTaxRate::new();
+    params.active = Some(false);
+    params.display_name = Some("TVA");
+    params.metadata = Some(metadata());
+    assert_snapshot!("update_tax_rate", to_form_body(&params).unwrap());
+
+    let mut params = ListTaxRates::new();
+    params.active = Some(true);
+    params.created = Some(created());
+    params.inclusive = Some(true);
+    assert_snapshot!("list_tax_rates", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "connect")]
+fn account() {
+    use std::collections::HashMap;
+    use stripe::{
+        AcceptTos, AccountSettingsParams, AccountType, BusinessProfile, BusinessType,
+        CapabilityRequest, CreateAccount, DelayDays, ListAccounts, PayoutSettingsParams,
+        RequestedCapability, TransferScheduleInterval, TransferScheduleParams, UpdateAccount,
+    };
+
+    let business_profile = BusinessProfile {
+        mcc: Some("5734".into()),
+        name: Some("Example Shop".into()),
+        product_description: Some("Computer software".into()),
+        support_address: Some(address()),
+        support_email: Some("support@example.com".into()),
+        support_phone: None,
+        support_url: None,
+        url: Some("https://example.com".into()),
+    };
+    let settings = AccountSettingsParams {
+        payouts: Some(PayoutSettingsParams {
+            debit_negative_balances: Some(true),
+            schedule: Some(TransferScheduleParams {
+                delay_days: Some(DelayDays::days(7)),
+                interval: Some(TransferScheduleInterval::Monthly),
+                monthly_anchor: Some(15),
+                weekly_anchor: None,
+            }),
+            statement_descriptor: Some("EXAMPLE SHOP".into()),
+        }),
+        ..Default::default()
+    };
+
+    let mut params = CreateAccount::new();
+    params.business_profile = Some(business_profile.clone());
+    params.business_type = Some(BusinessType::Company);
+    params.capabilities = Some(HashMap::from([(
+        RequestedCapability::CardPayments,
+        CapabilityRequest { requested: Some(true) },
+    )]));
+    params.country = Some("FR");
+    params.default_currency = Some(Currency::EUR);
+    params.email = Some("owner@example.com");
+    params.metadata = Some(metadata());
+    params.settings = Some(settings.clone());
+    params.tos_acceptance = Some(AcceptTos {
+        date: Some(1_672_531_200),
+        ip: Some("203.0.113.7".into()),
+        service_agreement: None,
+        user_agent: None,
+    });
+    params.type_ = Some(AccountType::Custom);
+    assert_snapshot!("create_account", to_form_body(&params).unwrap());
+
+    let mut params = UpdateAccount::new();
+    params.business_profile = Some(business_profile);
+    params.external_account = Some("btok_1MiN3gLkdIwHu7ixBGxo4Ezv");
+    params.metadata = Some(metadata());
+    params.settings = Some(settings);
+    assert_snapshot!("update_account", to_form_body(&params).unwrap());
+
+    let mut params = ListAccounts::new();
+    params.created = Some(created());
+    params.limit = Some(10);
+    assert_snapshot!("list_accounts", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "connect")]
+fn application_fee() {
+    use stripe::ListApplicationFees;
+
+    let mut params = ListApplicationFees::new();
+    params.charge = Some("ch_1MiN3gLkdIwHu7ixUiHUQ7nz".parse().unwrap());
+    params.created = Some(created());
+    assert_snapshot!("list_application_fees", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "connect")]
+fn recipient() {
+    use stripe::{CreateRecipient, ListRecipients, RecipientType, UpdateRecipient};
+
+    let mut params = CreateRecipient::new("Jenny Rosen", RecipientType::Individual);
+    params.description = Some("Freelance designer");
+    params.email = Some("jenny.rosen@example.com");
+    params.metadata = Some(metadata());
+    params.tax_id = Some("000000000");
+    assert_snapshot!("create_recipient", to_form_body(&params).unwrap());
+
+    let mut params = UpdateRecipient::new();
+    params.default_card = Some("card_1MiN3gLkdIwHu7ixrFMZ6dmt");
+    params.email = Some("jenny@example.com");
+    params.metadata = Some(metadata());
+    assert_snapshot!("update_recipient", to_form_body(&params).unwrap());
+
+    let mut params = ListRecipients::new();
+    params.created = Some(created());
+    params.type_ = Some(RecipientType::Corporation);
+    params.verified = Some(true);
+    assert_snapshot!("list_recipients", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "connect")]
+fn topup() {
+    use stripe::{ListTopups, TopupStatusFilter, UpdateTopup};
+
+    let mut params = UpdateTopup::new();
+    params.description = Some("Monthly top-up");
+    params.metadata = Some(metadata());
+    assert_snapshot!("update_topup", to_form_body(&params).unwrap());
+
+    let mut params = ListTopups::new();
+    params.amount = Some(RangeQuery::gte(10_000));
+    params.created = Some(created());
+    params.status = Some(TopupStatusFilter::Succeeded);
+    assert_snapshot!("list_topups", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "connect")]
+fn transfer() {
+    use stripe::{CreateTransfer, ListTransfers, TransferSourceType, UpdateTransfer};
+
+    let mut params = CreateTransfer::new(Currency::EUR);
+    params.amount = Some(1500);
+    params.description = Some("Seller payout for order #6735");
+    params.metadata = Some(metadata());
+    params.source_transaction = Some("ch_1MiN3gLkdIwHu7ixUiHUQ7nz".parse().unwrap());
+    params.source_type = Some(TransferSourceType::Card);
+    params.transfer_group = Some("ORDER_6735");
+    assert_snapshot!("create_transfer", to_form_body(&params).unwrap());
+
+    let mut params = UpdateTransfer::new();
+    params.description = Some("Seller payout for order #6735 (adjusted)");
+    params.metadata = Some(metadata());
+    assert_snapshot!("update_transfer", to_form_body(&params).unwrap());
+
+    let mut params = ListTransfers::new();
+    params.created = Some(created());
+    params.transfer_group = Some("ORDER_6735");
+    assert_snapshot!("list_transfers", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "financial-connections")]
+fn financial_connections_account() {
+    use stripe::{
+        ListFinancialConnectionsAccountOwners, ListFinancialConnectionsTransactions,
+        ListFinancialConnectionsTransactionsTransactionRefresh, RefreshFinancialConnectionsAccount,
+        RefreshableFeature,
+    };
+
+    let params = RefreshFinancialConnectionsAccount::new(vec![
+        RefreshableFeature::Balance,
+        RefreshableFeature::Transactions,
+    ]);
+    assert_snapshot!("refresh_financial_connections_account", to_form_body(&params).unwrap());
+
+    let mut params = ListFinancialConnectionsAccountOwners::new("fcaowns_123".parse().unwrap());
+    params.limit = Some(10);
+    assert_snapshot!("list_financial_connections_account_owners", to_form_body(&params).unwrap());
+
+    let mut params = ListFinancialConnectionsTransactions::new();
+    params.transacted_at = Some(created());
+    params.transaction_refresh = Some(ListFinancialConnectionsTransactionsTransactionRefresh {
+        after: "fctxnref_123".into(),
+    });
+    assert_snapshot!("list_financial_connections_transactions", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "fraud")]
+fn review() {
+    use stripe::ListReviews;
+
+    let mut params = ListReviews::new();
+    params.created = Some(created());
+    params.limit = Some(10);
+    assert_snapshot!("list_reviews", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "issuing")]
+fn issuing() {
+    use stripe::{
+        ApproveIssuingAuthorization, IssuingAuthorizationCheck, IssuingAuthorizationMethod,
+        IssuingAuthorizationWalletProvider, IssuingPhysicalBundleStatus, IssuingPhysicalBundleType,
+        ListIssuingPhysicalBundles, MerchantCategory, TestCaptureIssuingAuthorization,
+        TestCreateIssuingAuthorization, TestCreateIssuingAuthorizationAmountDetails,
+        TestCreateIssuingAuthorizationMerchantData, TestCreateIssuingAuthorizationNetworkData,
+        TestCreateIssuingAuthorizationVerificationData,
+    };
+
+    let mut params =
+        TestCreateIssuingAuthorization::new("ic_1MvSieLkdIwHu7ixn6uuO0Xu".parse().unwrap(), 1000);
+    params.amount_details = Some(TestCreateIssuingAuthorizationAmountDetails {
+        atm_fee: None,
+        cashback_amount: Some(200),
+    });
+    params.authorization_method = Some(IssuingAuthorizationMethod::Chip);
+    params.currency = Some(Currency::EUR);
+    params.is_amount_controllable = Some(true);
+    params.merchant_data = Some(TestCreateIssuingAuthorizationMerchantData {
+        category: Some(MerchantCategory::Bakeries),
+        city: Some("Paris".into()),
+        country: Some("FR".into()),
+        name: Some("Corner Bakery".into()),
+        network_id: Some("1234567890".into()),
+        postal_code: Some("75001".into()),
+        state: None,
+    });
+    params.network_data = Some(TestCreateIssuingAuthorizationNetworkData {
+        acquiring_institution_id: Some("123456".into()),
+    });
+    params.verification_data = Some(TestCreateIssuingAuthorizationVerificationData {
+        address_line1_check: Some(IssuingAuthorizationCheck::Match),
+        address_postal_code_check: Some(IssuingAuthorizationCheck::Match),
+        cvc_check: Some(IssuingAuthorizationCheck::Mismatch),
+        expiry_check: Some(IssuingAuthorizationCheck::NotProvided),
+    });
+    params.wallet = Some(IssuingAuthorizationWalletProvider::ApplePay);
+    assert_snapshot!("test_create_issuing_authorization", to_form_body(&params).unwrap());
+
+    let mut params = ApproveIssuingAuthorization::new();
+    params.amount = Some(800);
+    params.metadata = Some(metadata());
+    assert_snapshot!("approve_issuing_authorization", to_form_body(&params).unwrap());
+
+    let mut params = TestCaptureIssuingAuthorization::new();
+    params.capture_amount = Some(800);
+    params.close_authorization = Some(false);
+    assert_snapshot!("test_capture_issuing_authorization", to_form_body(&params).unwrap());
+
+    let mut params = ListIssuingPhysicalBundles::new();
+    params.status = Some(IssuingPhysicalBundleStatus::Active);
+    params.type_ = Some(IssuingPhysicalBundleType::Custom);
+    assert_snapshot!("list_issuing_physical_bundles", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "orders")]
+fn order() {
+    use stripe::{
+        CreateOrder, CreateSku, IdOrCreate, Inventory, ListOrderReturns, ListOrders,
+        ListOrdersStatusTransitions, ListSkus, OrderItemParams, OrderItemParamsType, OrderStatus,
+        OrderStatusFilter, PackageDimensions, ShippingParams, UpdateOrder, UpdateSku,
+    };
+
+    let shipping = ShippingParams {
+        phone: Some("+33123456789".into()),
+        ..ShippingParams::new("Jenny Rosen", address())
+    };
+
+    let mut params = CreateOrder::new(Currency::EUR);
+    params.coupon = Some("5OFF".parse().unwrap());
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.email = Some("jenny.rosen@example.com");
+    params.items = Some(vec![OrderItemParams {
+        amount: None,
+        currency: None,
+        description: None,
+        parent: Some("sku_NTLH1ZBLSzsQX8".into()),
+        quantity: Some(2),
+        type_: Some(OrderItemParamsType::Sku),
+    }]);
+    params.metadata = Some(metadata());
+    params.shipping = Some(shipping.clone());
+    assert_snapshot!("create_order", to_form_body(&params).unwrap());
+
+    let mut params = UpdateOrder::new();
+    params.metadata = Some(metadata());
+    params.selected_shipping_method = Some("express");
+    params.shipping = Some(shipping);
+    params.status = Some(OrderStatus::Fulfilled);
+    assert_snapshot!("update_order", to_form_body(&params).unwrap());
+
+    let mut params = ListOrders::new();
+    params.created = Some(created());
+    params.customer = Some("cus_4QFJOjw2pOmAGJ".parse().unwrap());
+    params.status = Some(OrderStatusFilter::Paid);
+    params.status_transitions = Some(ListOrdersStatusTransitions {
+        canceled: None,
+        fulfilled: None,
+        paid: Some(RangeQuery::gte(1_672_531_200)),
+        returned: None,
+    });
+    params.upstream_ids = Some(vec!["upstream_6735".into()]);
+    assert_snapshot!("list_orders", to_form_body(&params).unwrap());
+
+    let mut params = ListOrderReturns::new();
+    params.created = Some(created());
+    params.order = Some("or_1MiN3gLkdIwHu7ixsrUYPq7N".parse().unwrap());
+    assert_snapshot!("list_order_returns", to_form_body(&params).unwrap());
+
+    let package_dimensions =
+        PackageDimensions { height: 10.0, length: 20.0, weight: 0.5, width: 15.0 };
+    let mut params = CreateSku::new(
+        Currency::EUR,
+        Some(Inventory { quantity: Some(50), type_: "finite".into(), value: None }),
+        1500,
+        IdOrCreate::Id("prod_NTLHY2kmqUIQyp"),
+    );
+    params.active = Some(true);
+    params.attributes = Some(metadata());
+    params.id = Some("sku_gold_tshirt");
+    params.image = Some("https://example.com/tshirt.png");
+    params.package_dimensions = Some(package_dimensions);
+    assert_snapshot!("create_sku", to_form_body(&params).unwrap());
+
+    let mut params = UpdateSku::new();
+    params.inventory =
+        Some(Inventory { quantity: None, type_: "bucket".into(), value: Some("limited".into()) });
+    params.metadata = Some(metadata());
+    params.price = Some(1800);
+    assert_snapshot!("update_sku", to_form_body(&params).unwrap());
+
+    let mut params = ListSkus::new();
+    params.active = Some(true);
+    params.in_stock = Some(true);
+    params.product = Some(IdOrCreate::Id("prod_NTLHY2kmqUIQyp"));
+    assert_snapshot!("list_skus", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "terminal")]
+fn terminal_reader() {
+    use stripe::{
+        ProcessPaymentIntentConfig, ProcessPaymentIntentParams, ProcessSetupIntentConfig,
+        ProcessSetupIntentParams,
+    };
+
+    let mut params =
+        ProcessPaymentIntentParams::new("pi_1MiN3gLkdIwHu7ixQaR8Pm1a".parse().unwrap());
+    params.process_config = Some(ProcessPaymentIntentConfig {
+        enable_customer_cancellation: None,
+        skip_tipping: Some(true),
+    });
+    assert_snapshot!("process_payment_intent", to_form_body(&params).unwrap());
+
+    let mut params =
+        ProcessSetupIntentParams::new("seti_1MiN3gLkdIwHu7ixQaR8Pm1a".parse().unwrap(), true);
+    params.process_config =
+        Some(ProcessSetupIntentConfig { enable_customer_cancellation: Some(true) });
+    assert_snapshot!("process_setup_intent", to_form_body(&params).unwrap());
+}
+
+#[test]
+#[cfg(feature = "webhook-endpoints")]
+fn webhook_endpoint() {
+    use stripe::{
+        ApiVersion, CreateWebhookEndpoint, EventFilter, ListWebhookEndpoints, UpdateWebhookEndpoint,
+    };
+
+    let mut params = CreateWebhookEndpoint::new(
+        vec![EventFilter::ChargeSucceeded, EventFilter::CustomerSubscriptionDeleted],
+        "https://example.com/webhooks",
+    );
+    params.api_version = Some(ApiVersion::V2019_03_14);
+    params.connect = Some(true);
+    params.description = Some("Production endpoint");
+    params.metadata = Some(metadata());
+    assert_snapshot!("create_webhook_endpoint", to_form_body(&params).unwrap());
+
+    let mut params = UpdateWebhookEndpoint::new();
+    params.disabled = Some(false);
+    params.enabled_events = Some(vec![EventFilter::All]);
+    params.url = Some("https://example.com/stripe/webhooks");
+    assert_snapshot!("update_webhook_endpoint", to_form_body(&params).unwrap());
+
+    let mut params = ListWebhookEndpoints::new();
+    params.limit = Some(5);
+    assert_snapshot!("list_webhook_endpoints", to_form_body(&params).unwrap());
+}
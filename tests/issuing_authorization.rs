@@ -0,0 +1,178 @@
+//! A test mode authorization is created, then approved and captured, or declined.
+
+#![cfg(feature = "issuing")]
+
+mod local;
+
+use serde_json::json;
+use stripe::{
+    ApproveIssuingAuthorization, DeclineIssuingAuthorization, IssuingAuthorization,
+    IssuingAuthorizationStatus, TestCaptureIssuingAuthorization, TestCreateIssuingAuthorization,
+};
+
+fn authorization(approved: bool, status: &str) -> String {
+    local::ok(&json!({
+        "id": "iauth_123",
+        "object": "issuing.authorization",
+        "amount": if approved { 1000 } else { 0 },
+        "approved": approved,
+        "authorization_method": "online",
+        "balance_transactions": [],
+        "card": {
+            "id": "ic_123",
+            "object": "issuing.card",
+            "brand": "Visa",
+            "cardholder": {
+                "id": "ich_123",
+                "object": "issuing.cardholder",
+                "billing": {
+                    "address": {
+                        "city": "San Francisco",
+                        "country": "US",
+                        "line1": "1234 Main Street",
+                        "postal_code": "94111",
+                        "state": "CA"
+                    }
+                },
+                "created": 1_680_000_000,
+                "livemode": false,
+                "metadata": {},
+                "name": "Jenny Rosen",
+                "requirements": { "disabled_reason": null, "past_due": [] },
+                "status": "active",
+                "type": "individual"
+            },
+            "created": 1_680_000_000,
+            "currency": "usd",
+            "exp_month": 8,
+            "exp_year": 2027,
+            "last4": "4242",
+            "livemode": false,
+            "metadata": {},
+            "spending_controls": {},
+            "status": "active",
+            "type": "virtual"
+        },
+        "created": 1_680_000_000,
+        "currency": "usd",
+        "livemode": false,
+        "merchant_amount": 1000,
+        "merchant_currency": "usd",
+        "merchant_data": { "category": "bakeries", "name": "Corner Bakery", "network_id": "1234" },
+        "metadata": {},
+        "pending_request": null,
+        "request_history": [],
+        "status": status,
+        "transactions": [],
+        "verification_data": {
+            "address_line1_check": "not_provided",
+            "address_postal_code_check": "not_provided",
+            "cvc_check": "match",
+            "expiry_check": "match"
+        }
+    }))
+}
+
+fn create() -> TestCreateIssuingAuthorization<'static> {
+    let mut params = TestCreateIssuingAuthorization::new("ic_123".parse().unwrap(), 1000);
+    params.is_amount_controllable = Some(true);
+    params
+}
+
+fn approve() -> ApproveIssuingAuthorization<'static> {
+    let mut params = ApproveIssuingAuthorization::new();
+    params.amount = Some(1000);
+    params
+}
+
+fn assert_requests(requests: &[local::Request], action: &str) {
+    let lines: Vec<_> = requests.iter().map(|request| request.line.as_str()).collect();
+    let mut expected = vec![
+        "POST /v1/test_helpers/issuing/authorizations HTTP/1.1".to_string(),
+        format!("POST /v1/issuing/authorizations/iauth_123/{} HTTP/1.1", action),
+    ];
+    if action == "approve" {
+        expected
+            .push("POST /v1/test_helpers/issuing/authorizations/iauth_123/capture HTTP/1.1".into());
+    }
+    assert_eq!(lines, expected);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn approve_and_capture() {
+    let (url, requests) = local::serve(vec![
+        authorization(false, "pending"),
+        authorization(true, "pending"),
+        authorization(true, "closed"),
+    ]);
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let authorization = IssuingAuthorization::test_create(&client, create()).unwrap();
+    assert_eq!(authorization.status, IssuingAuthorizationStatus::Pending);
+
+    let authorization =
+        IssuingAuthorization::approve(&client, &authorization.id, approve()).unwrap();
+    assert!(authorization.approved);
+
+    let params = TestCaptureIssuingAuthorization::new();
+    let authorization =
+        IssuingAuthorization::test_capture(&client, &authorization.id, params).unwrap();
+    assert_eq!(authorization.status, IssuingAuthorizationStatus::Closed);
+    assert_requests(&requests.lock().unwrap(), "approve");
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn decline() {
+    let (url, requests) =
+        local::serve(vec![authorization(false, "pending"), authorization(false, "closed")]);
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let authorization = IssuingAuthorization::test_create(&client, create()).unwrap();
+    let params = DeclineIssuingAuthorization::new();
+    let authorization = IssuingAuthorization::decline(&client, &authorization.id, params).unwrap();
+    assert!(!authorization.approved);
+    assert_eq!(authorization.status, IssuingAuthorizationStatus::Closed);
+    assert_requests(&requests.lock().unwrap(), "decline");
+}
+
+#[cfg(not(feature = "blocking"))]
+#[tokio::test]
+async fn approve_and_capture() {
+    let (url, requests) = local::serve(vec![
+        authorization(false, "pending"),
+        authorization(true, "pending"),
+        authorization(true, "closed"),
+    ]);
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let authorization = IssuingAuthorization::test_create(&client, create()).await.unwrap();
+    assert_eq!(authorization.status, IssuingAuthorizationStatus::Pending);
+
+    let authorization =
+        IssuingAuthorization::approve(&client, &authorization.id, approve()).await.unwrap();
+    assert!(authorization.approved);
+
+    let params = TestCaptureIssuingAuthorization::new();
+    let authorization =
+        IssuingAuthorization::test_capture(&client, &authorization.id, params).await.unwrap();
+    assert_eq!(authorization.status, IssuingAuthorizationStatus::Closed);
+    assert_requests(&requests.lock().unwrap(), "approve");
+}
+
+#[cfg(not(feature = "blocking"))]
+#[tokio::test]
+async fn decline() {
+    let (url, requests) =
+        local::serve(vec![authorization(false, "pending"), authorization(false, "closed")]);
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let authorization = IssuingAuthorization::test_create(&client, create()).await.unwrap();
+    let params = DeclineIssuingAuthorization::new();
+    let authorization =
+        IssuingAuthorization::decline(&client, &authorization.id, params).await.unwrap();
+    assert!(!authorization.approved);
+    assert_eq!(authorization.status, IssuingAuthorizationStatus::Closed);
+    assert_requests(&requests.lock().unwrap(), "decline");
+}
@@ -0,0 +1,57 @@
+//! A local HTTP server serving canned responses, for tests which need to observe requests.
+
+// Not every test binary uses every helper.
+#![allow(dead_code)]
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Instant;
+
+/// A request received by the server.
+#[derive(Clone, Debug)]
+pub struct Request {
+    /// When the request arrived.
+    pub at: Instant,
+    /// The request line, e.g. `GET /v1/events/evt_123 HTTP/1.1`.
+    pub line: String,
+}
+
+/// Serves one canned response per connection, recording each request as it arrives.
+pub fn serve(responses: Vec<String>) -> (String, Arc<Mutex<Vec<Request>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = requests.clone();
+    thread::spawn(move || {
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            let request = String::from_utf8_lossy(&request);
+            let line = request.lines().next().unwrap_or_default().to_string();
+            recorded.lock().unwrap().push(Request { at: Instant::now(), line });
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+    (url, requests)
+}
+
+pub fn response(status: &str, headers: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n{}\r\n{}",
+        status,
+        body.len(),
+        headers,
+        body
+    )
+}
+
+pub fn ok(body: &serde_json::Value) -> String {
+    response("200 OK", "", &body.to_string())
+}
@@ -0,0 +1,164 @@
+//! Pages following the first one of a list must be fetched with the same filters, and for
+//! nested lists from the same path.
+
+mod local;
+
+use serde_json::json;
+use stripe::{ListPayouts, Payout};
+
+fn payout(id: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "object": "payout",
+        "amount": 1100,
+        "arrival_date": 1680652800,
+        "automatic": true,
+        "created": 1680648842,
+        "currency": "usd",
+        "livemode": false,
+        "method": "standard",
+        "source_type": "card",
+        "status": "paid",
+        "type": "bank_account"
+    })
+}
+
+fn page(ids: &[&str], has_more: bool) -> String {
+    local::ok(&json!({
+        "object": "list",
+        "data": ids.iter().map(|id| payout(id)).collect::<Vec<_>>(),
+        "has_more": has_more,
+        "url": "/v1/payouts"
+    }))
+}
+
+fn params() -> ListPayouts<'static> {
+    let mut params = ListPayouts::new();
+    params.limit = Some(2);
+    params.status = Some("paid");
+    params
+}
+
+fn assert_filtered(requests: &[local::Request]) {
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].line, "GET /v1/payouts?limit=2&status=paid HTTP/1.1");
+    assert_eq!(
+        requests[1].line,
+        "GET /v1/payouts?limit=2&status=paid&starting_after=po_2 HTTP/1.1"
+    );
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn get_all_keeps_filters() {
+    let (url, requests) = local::serve(vec![page(&["po_1", "po_2"], true), page(&["po_3"], false)]);
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let payouts = Payout::list(&client, params()).unwrap().get_all(&client).unwrap();
+    let ids: Vec<_> = payouts.iter().map(|payout| payout.id.as_str()).collect();
+    assert_eq!(ids, ["po_1", "po_2", "po_3"]);
+    assert_filtered(&requests.lock().unwrap());
+}
+
+#[cfg(not(feature = "blocking"))]
+#[tokio::test]
+async fn get_all_keeps_filters() {
+    use futures_util::TryStreamExt;
+
+    let (url, requests) = local::serve(vec![page(&["po_1", "po_2"], true), page(&["po_3"], false)]);
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let payouts: Vec<_> = Payout::list(&client, params())
+        .await
+        .unwrap()
+        .get_all(&client)
+        .try_collect()
+        .await
+        .unwrap();
+    let ids: Vec<_> = payouts.iter().map(|payout| payout.id.as_str()).collect();
+    assert_eq!(ids, ["po_1", "po_2", "po_3"]);
+    assert_filtered(&requests.lock().unwrap());
+}
+
+#[cfg(feature = "billing")]
+mod nested {
+    use super::local;
+    use serde_json::json;
+    use stripe::{ListUsageRecordSummaries, SubscriptionItemId, UsageRecordSummary};
+
+    const PATH: &str = "/v1/subscription_items/si_123/usage_record_summaries";
+
+    fn page(ids: &[&str], has_more: bool) -> String {
+        let data: Vec<_> = ids
+            .iter()
+            .map(|id| {
+                json!({
+                    "id": id,
+                    "object": "usage_record_summary",
+                    "invoice": null,
+                    "livemode": false,
+                    "period": { "start": null, "end": null },
+                    "subscription_item": "si_123",
+                    "total_usage": 10
+                })
+            })
+            .collect();
+        local::ok(&json!({ "object": "list", "data": data, "has_more": has_more, "url": PATH }))
+    }
+
+    fn pages() -> Vec<String> {
+        vec![
+            page(&["sis_1", "sis_2"], true),
+            page(&["sis_3", "sis_4"], true),
+            page(&["sis_5"], false),
+        ]
+    }
+
+    fn id() -> SubscriptionItemId {
+        "si_123".parse().unwrap()
+    }
+
+    fn params() -> ListUsageRecordSummaries<'static> {
+        let mut params = ListUsageRecordSummaries::new();
+        params.limit = Some(2);
+        params
+    }
+
+    fn assert_each_once(summaries: &[UsageRecordSummary], requests: &[local::Request]) {
+        let ids: Vec<_> = summaries.iter().map(|summary| summary.id.as_str()).collect();
+        assert_eq!(ids, ["sis_1", "sis_2", "sis_3", "sis_4", "sis_5"]);
+        let lines: Vec<_> = requests.iter().map(|request| request.line.clone()).collect();
+        assert_eq!(
+            lines,
+            [
+                format!("GET {}?limit=2 HTTP/1.1", PATH),
+                format!("GET {}?limit=2&starting_after=sis_2 HTTP/1.1", PATH),
+                format!("GET {}?limit=2&starting_after=sis_4 HTTP/1.1", PATH),
+            ]
+        );
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn get_all_fetches_each_item_once() {
+        let (url, requests) = local::serve(pages());
+        let client = stripe::Client::from_url(url, "sk_test_123");
+
+        let list = UsageRecordSummary::list(&client, &id(), params()).unwrap();
+        let summaries = list.get_all(&client).unwrap();
+        assert_each_once(&summaries, &requests.lock().unwrap());
+    }
+
+    #[cfg(not(feature = "blocking"))]
+    #[tokio::test]
+    async fn get_all_fetches_each_item_once() {
+        use futures_util::TryStreamExt;
+
+        let (url, requests) = local::serve(pages());
+        let client = stripe::Client::from_url(url, "sk_test_123");
+
+        let list = UsageRecordSummary::list(&client, &id(), params()).await.unwrap();
+        let summaries: Vec<_> = list.get_all(&client).try_collect().await.unwrap();
+        assert_each_once(&summaries, &requests.lock().unwrap());
+    }
+}
@@ -44,3 +44,18 @@ fn is_subscription_expandable() {
         assert!(subscription.customer.is_object());
     });
 }
+
+#[test]
+fn subscription_cancel_at_period_end() {
+    mock::with_client(|client| {
+        let id = "sub_123".parse().unwrap();
+        let mut params = stripe::CancelSubscription::new();
+        params.at_period_end = Some(true);
+        let result = stripe::Subscription::cancel(client, &id, params);
+        let subscription = match result {
+            Err(err) => panic!("{}", err),
+            Ok(ok) => ok,
+        };
+        assert_eq!(subscription.id, "sub_123");
+    });
+}
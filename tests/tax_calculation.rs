@@ -0,0 +1,92 @@
+//! Line items of a tax calculation are listed from its own sub-resource.
+
+mod local;
+
+use serde_json::json;
+use stripe::{
+    ListTaxCalculationLineItems, TaxCalculation, TaxCalculationLineItem,
+    TaxCalculationLineItemTaxBehavior, TaxJurisdictionLevel,
+};
+
+fn line_items() -> String {
+    local::ok(&json!({
+        "object": "list",
+        "data": [{
+            "id": "tax_li_1",
+            "object": "tax.calculation_line_item",
+            "amount": 1499,
+            "amount_tax": 133,
+            "livemode": false,
+            "product": null,
+            "quantity": 1,
+            "reference": "L1",
+            "tax_behavior": "exclusive",
+            "tax_breakdown": [{
+                "amount": 133,
+                "jurisdiction": {
+                    "country": "US",
+                    "display_name": "New York",
+                    "level": "state",
+                    "state": "NY"
+                },
+                "sourcing": "destination",
+                "tax_rate_details": {
+                    "display_name": "Sales Tax",
+                    "percentage_decimal": "8.875",
+                    "tax_type": "sales_tax"
+                },
+                "taxability_reason": "standard_rated",
+                "taxable_amount": 1499
+            }],
+            "tax_code": "txcd_99999999",
+            "unit_cost": 1499
+        }],
+        "has_more": false,
+        "url": "/v1/tax/calculations/taxcalc_123/line_items"
+    }))
+}
+
+fn params() -> ListTaxCalculationLineItems<'static> {
+    let mut params = ListTaxCalculationLineItems::new();
+    params.limit = Some(5);
+    params
+}
+
+fn assert_line_items(items: &[TaxCalculationLineItem], requests: &[local::Request]) {
+    assert_eq!(items.len(), 1);
+    let item = &items[0];
+    assert_eq!(item.amount_tax, 133);
+    assert_eq!(item.tax_behavior, TaxCalculationLineItemTaxBehavior::Exclusive);
+    assert_eq!(item.tax_breakdown[0].jurisdiction.level, TaxJurisdictionLevel::State);
+    assert_eq!(
+        item.tax_breakdown[0].tax_rate_details.as_ref().unwrap().percentage_decimal,
+        "8.875"
+    );
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].line,
+        "GET /v1/tax/calculations/taxcalc_123/line_items?limit=5 HTTP/1.1"
+    );
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn list_line_items() {
+    let (url, requests) = local::serve(vec![line_items()]);
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let id = "taxcalc_123".parse().unwrap();
+    let items = TaxCalculation::list_line_items(&client, &id, params()).unwrap();
+    assert_line_items(&items.data, &requests.lock().unwrap());
+}
+
+#[cfg(not(feature = "blocking"))]
+#[tokio::test]
+async fn list_line_items() {
+    let (url, requests) = local::serve(vec![line_items()]);
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let id = "taxcalc_123".parse().unwrap();
+    let items = TaxCalculation::list_line_items(&client, &id, params()).await.unwrap();
+    assert_line_items(&items.data, &requests.lock().unwrap());
+}
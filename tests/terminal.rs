@@ -0,0 +1,71 @@
+//! A reader collects a payment method for a setup intent through its own action endpoint.
+
+#![cfg(feature = "terminal")]
+
+mod local;
+
+use serde_json::json;
+use stripe::{
+    Expandable, ProcessSetupIntentParams, TerminalReader, TerminalReaderActionStatus,
+    TerminalReaderActionType,
+};
+
+fn reader() -> String {
+    local::ok(&json!({
+        "id": "tmr_123",
+        "object": "terminal.reader",
+        "action": {
+            "failure_code": null,
+            "failure_message": null,
+            "process_setup_intent": {
+                "setup_intent": "seti_123"
+            },
+            "status": "in_progress",
+            "type": "process_setup_intent"
+        },
+        "device_type": "bbpos_wisepos_e",
+        "ip_address": "192.168.2.2",
+        "label": "Front desk",
+        "livemode": false,
+        "location": "tml_123",
+        "metadata": {},
+        "serial_number": "123-456-789",
+        "status": "online"
+    }))
+}
+
+fn params() -> ProcessSetupIntentParams<'static> {
+    ProcessSetupIntentParams::new("seti_123".parse().unwrap(), true)
+}
+
+fn assert_processing(reader: &TerminalReader, requests: &[local::Request]) {
+    let action = reader.action.as_ref().unwrap();
+    assert_eq!(action.type_, TerminalReaderActionType::ProcessSetupIntent);
+    assert_eq!(action.status, TerminalReaderActionStatus::InProgress);
+    let setup_intent = &action.process_setup_intent.as_ref().unwrap().setup_intent;
+    assert!(matches!(setup_intent, Expandable::Id(id) if id.as_str() == "seti_123"));
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].line, "POST /v1/terminal/readers/tmr_123/process_setup_intent HTTP/1.1");
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn process_setup_intent() {
+    let (url, requests) = local::serve(vec![reader()]);
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let id = "tmr_123".parse().unwrap();
+    let reader = TerminalReader::process_setup_intent(&client, &id, params()).unwrap();
+    assert_processing(&reader, &requests.lock().unwrap());
+}
+
+#[cfg(not(feature = "blocking"))]
+#[tokio::test]
+async fn process_setup_intent() {
+    let (url, requests) = local::serve(vec![reader()]);
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let id = "tmr_123".parse().unwrap();
+    let reader = TerminalReader::process_setup_intent(&client, &id, params()).await.unwrap();
+    assert_processing(&reader, &requests.lock().unwrap());
+}
@@ -0,0 +1,68 @@
+//! `List::get_all_throttled` must space out page requests and wait out rate limiting.
+
+use std::time::{Duration, Instant};
+
+mod local;
+
+use local::response;
+
+fn page(id: &str, has_more: bool) -> String {
+    serde_json::json!({
+        "object": "list",
+        "data": [{ "id": id }],
+        "has_more": has_more,
+        "url": "/v1/customers/cus_123/sources"
+    })
+    .to_string()
+}
+
+fn first_page() -> stripe::List<stripe::Card> {
+    serde_json::from_str(&page("card_1", true)).unwrap()
+}
+
+/// The second page is rate limited once, then served after `Retry-After`.
+fn rate_limited_pages() -> Vec<String> {
+    let rate_limited = r#"{"error": {"type": "rate_limit_error", "message": "Too many requests"}}"#;
+    vec![
+        response("429 Too Many Requests", "retry-after: 1\r\n", rate_limited),
+        response("200 OK", "", &page("card_2", true)),
+        response("200 OK", "", &page("card_3", false)),
+    ]
+}
+
+fn assert_paced(started: Instant, requests: &[local::Request]) {
+    assert_eq!(requests.len(), 3);
+    let arrivals: Vec<_> = requests.iter().map(|request| request.at).collect();
+    // 10 requests per second, with some slack for the time a request spends in flight.
+    assert!(arrivals[0] - started >= Duration::from_millis(100));
+    assert!(arrivals[1] - arrivals[0] >= Duration::from_secs(1));
+    assert!(arrivals[2] - arrivals[1] >= Duration::from_millis(80));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn get_all_throttled() {
+    let (url, requests) = local::serve(rate_limited_pages());
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let started = Instant::now();
+    let cards = first_page().get_all_throttled(&client, 10).unwrap();
+    let ids: Vec<_> = cards.iter().map(|card| card.id.as_str()).collect();
+    assert_eq!(ids, ["card_1", "card_2", "card_3"]);
+    assert_paced(started, &requests.lock().unwrap());
+}
+
+#[cfg(not(feature = "blocking"))]
+#[tokio::test]
+async fn get_all_throttled() {
+    use futures_util::TryStreamExt;
+
+    let (url, requests) = local::serve(rate_limited_pages());
+    let client = stripe::Client::from_url(url, "sk_test_123");
+
+    let started = Instant::now();
+    let cards: Vec<_> = first_page().get_all_throttled(&client, 10).try_collect().await.unwrap();
+    let ids: Vec<_> = cards.iter().map(|card| card.id.as_str()).collect();
+    assert_eq!(ids, ["card_1", "card_2", "card_3"]);
+    assert_paced(started, &requests.lock().unwrap());
+}